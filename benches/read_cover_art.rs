@@ -0,0 +1,57 @@
+#![allow(missing_docs)]
+
+use lofty::picture::PictureType;
+use lofty::prelude::*;
+
+use gungraun::{library_benchmark, library_benchmark_group, main};
+
+use std::hint::black_box;
+
+macro_rules! test_read_cover_art {
+	([$(($NAME:ident, $path:expr)),+ $(,)?]) => {
+		$(
+			paste::paste! {
+				#[library_benchmark]
+				fn [<$NAME:lower _read_cover_art>]() {
+					const PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../benches/", $path);
+
+					black_box(lofty::read_cover_art(PATH).unwrap().unwrap());
+				}
+
+				#[library_benchmark]
+				fn [<$NAME:lower _full_parse>]() {
+					const PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../benches/", $path);
+
+					let tagged_file = lofty::read_from_path(PATH).unwrap();
+					black_box(
+						tagged_file
+							.primary_tag()
+							.unwrap()
+							.get_picture_type(PictureType::CoverFront)
+							.unwrap()
+							.clone()
+					);
+				}
+			}
+		)+
+	}
+}
+
+test_read_cover_art!([
+	(FLAC, "./assets/01 TempleOS Hymn Risen (Remix).flac"),
+	(MP3, "./assets/01 TempleOS Hymn Risen (Remix).mp3"),
+	(MP4, "./assets/01 TempleOS Hymn Risen (Remix).m4a"),
+	(OPUS, "./assets/01 TempleOS Hymn Risen (Remix).opus"),
+	(VORBIS, "./assets/01 TempleOS Hymn Risen (Remix).ogg"),
+]);
+
+library_benchmark_group!(
+	name = cover_art_reading;
+	benchmarks =
+		flac_read_cover_art, flac_full_parse,
+		mp3_read_cover_art, mp3_full_parse,
+		mp4_read_cover_art, mp4_full_parse,
+		opus_read_cover_art, opus_full_parse,
+		vorbis_read_cover_art, vorbis_full_parse
+);
+main!(library_benchmark_groups = cover_art_reading);
@@ -6,6 +6,7 @@ mod header;
 mod properties;
 mod read;
 
+use crate::file::tag_space::TagSpace;
 use crate::id3::v1::tag::Id3v1Tag;
 use crate::id3::v2::tag::Id3v2Tag;
 
@@ -25,4 +26,5 @@ pub struct AacFile {
 	#[lofty(tag_type = "Id3v1")]
 	pub(crate) id3v1_tag: Option<Id3v1Tag>,
 	pub(crate) properties: AACProperties,
+	pub(crate) tag_space: Option<TagSpace>,
 }
@@ -2,11 +2,13 @@ use super::AacFile;
 use super::header::{ADTSHeader, HEADER_MASK};
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
+use crate::file::tag_space::TagSpace;
 use crate::id3::v2::header::Id3v2Header;
 use crate::id3::v2::read::parse_id3v2;
 use crate::id3::{ID3FindResults, find_id3v1};
 use crate::macros::{decode_err, err, parse_mode_choice};
 use crate::mpeg::header::{HeaderCmpResult, cmp_header, search_for_frame_sync};
+use crate::tag::TagType;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -41,11 +43,19 @@ where
 			// [I, D, 3, ver_major, ver_minor, flags, size (4 bytes)]
 			[b'I', b'D', b'3', ..] => {
 				// Seek back to read the tag in full
-				reader.seek(SeekFrom::Current(-4))?;
+				let tag_start = reader.seek(SeekFrom::Current(-4))?;
 
 				let header = Id3v2Header::parse(reader)?;
 				let skip_footer = header.flags.footer;
 
+				// Only a tag at the very start of the file can be written over in place
+				if tag_start == 0 && parse_options.read_tags {
+					file.tag_space = Some(TagSpace {
+						tag_type: TagType::Id3v2,
+						size: u64::from(header.full_tag_size()),
+					});
+				}
+
 				let Some(new_stream_len) = stream_len.checked_sub(u64::from(header.size)) else {
 					err!(SizeMismatch);
 				};
@@ -65,6 +75,8 @@ where
 						continue;
 					}
 					file.id3v2_tag = Some(id3v2);
+				} else {
+					reader.seek(SeekFrom::Current(i64::from(header.content_size())))?;
 				}
 
 				// Skip over the footer
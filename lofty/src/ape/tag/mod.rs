@@ -14,6 +14,7 @@ use crate::tag::{
 };
 use crate::util::flag_item;
 use crate::util::io::{FileLike, Truncate};
+use crate::util::text::parse_number;
 
 use std::borrow::Cow;
 use std::io::Write;
@@ -205,7 +206,7 @@ impl ApeTag {
 			..
 		}) = self.get(key)
 		{
-			let mut split = text.split('/').flat_map(str::parse::<u32>);
+			let mut split = text.split('/').flat_map(parse_number::<u32>);
 			return (split.next(), split.next());
 		}
 
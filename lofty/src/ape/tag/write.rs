@@ -6,6 +6,7 @@ use crate::config::{ParseOptions, WriteOptions};
 use crate::error::{LoftyError, Result};
 use crate::id3::{FindId3v2Config, find_id3v1, find_id3v2, find_lyrics3v2};
 use crate::macros::{decode_err, err};
+use crate::picture::{Picture, PictureType};
 use crate::probe::Probe;
 use crate::tag::item::ItemValueRef;
 use crate::util::io::{FileLike, Truncate};
@@ -154,7 +155,9 @@ where
 	R: Iterator<Item = ApeItemRef<'b>>,
 {
 	let items = &mut tag.items;
-	let mut peek = items.peekable();
+	let mut peek = items
+		.filter(|item| is_item_allowed(item, write_options))
+		.peekable();
 
 	// Unnecessary to write anything if there's no metadata
 	if peek.peek().is_none() {
@@ -257,3 +260,26 @@ where
 
 	Ok(tag_write)
 }
+
+// Pictures are stored as binary items, which need to be parsed to check their MIME type
+fn is_item_allowed(item: &ApeItemRef<'_>, write_options: WriteOptions) -> bool {
+	if write_options.allowed_picture_mimes.is_none() {
+		return true;
+	}
+
+	let ItemValueRef::Binary(value) = &item.value else {
+		return true;
+	};
+
+	if matches!(
+		PictureType::from_ape_key(item.key),
+		PictureType::Undefined(_)
+	) {
+		return true;
+	}
+
+	match Picture::from_ape_bytes(item.key, value) {
+		Ok(picture) => write_options.is_picture_allowed(&picture),
+		Err(_) => true,
+	}
+}
@@ -6,6 +6,6 @@ mod write_options;
 
 pub use global_options::{GlobalOptions, apply_global_options};
 pub use parse_options::{ParseOptions, ParsingMode};
-pub use write_options::WriteOptions;
+pub use write_options::{WriteOptions, WriteStrategy};
 
 pub(crate) use global_options::global_options;
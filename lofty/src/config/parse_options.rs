@@ -8,6 +8,8 @@ pub struct ParseOptions {
 	pub(crate) max_junk_bytes: usize,
 	pub(crate) read_cover_art: bool,
 	pub(crate) implicit_conversions: bool,
+	pub(crate) max_comment_count: u32,
+	pub(crate) max_last_page_search: u64,
 }
 
 impl Default for ParseOptions {
@@ -23,6 +25,8 @@ impl Default for ParseOptions {
 	///     max_junk_bytes: 1024,
 	///     read_cover_art: true,
 	///     implicit_conversions: true,
+	///     max_comment_count: 65536,
+	///     max_last_page_search: 1048576,
 	/// }
 	/// ```
 	fn default() -> Self {
@@ -37,6 +41,12 @@ impl ParseOptions {
 	/// Default number of junk bytes to read
 	pub const DEFAULT_MAX_JUNK_BYTES: usize = 1024;
 
+	/// Default maximum number of comment fields
+	pub const DEFAULT_MAX_COMMENT_COUNT: u32 = 65536;
+
+	/// Default number of bytes to search for the last OGG page
+	pub const DEFAULT_MAX_LAST_PAGE_SEARCH: u64 = 1024 * 1024;
+
 	/// Creates a new `ParseOptions`, alias for `Default` implementation
 	///
 	/// See also: [`ParseOptions::default`]
@@ -57,6 +67,8 @@ impl ParseOptions {
 			max_junk_bytes: Self::DEFAULT_MAX_JUNK_BYTES,
 			read_cover_art: true,
 			implicit_conversions: true,
+			max_comment_count: Self::DEFAULT_MAX_COMMENT_COUNT,
+			max_last_page_search: Self::DEFAULT_MAX_LAST_PAGE_SEARCH,
 		}
 	}
 
@@ -162,6 +174,47 @@ impl ParseOptions {
 		self.implicit_conversions = implicit_conversions;
 		*self
 	}
+
+	/// The maximum number of fields allowed in a Vorbis comment block
+	///
+	/// Each field has very little overhead, so a small tag can declare an enormous number of them.
+	/// If a tag declares more fields than this, it will error with [`ErrorKind::TooMuchData`],
+	/// regardless of the [`ParsingMode`].
+	///
+	/// [`ErrorKind::TooMuchData`]: crate::error::ErrorKind::TooMuchData
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	///
+	/// // My files never have more than a handful of fields
+	/// let parsing_options = ParseOptions::new().max_comment_count(256);
+	/// ```
+	pub fn max_comment_count(&mut self, max_comment_count: u32) -> Self {
+		self.max_comment_count = max_comment_count;
+		*self
+	}
+
+	/// The maximum number of bytes to search for the last page of an OGG stream
+	///
+	/// The last page holds the final granule position, which is needed to calculate the duration.
+	/// It is searched for backward from the end of the file, so this only needs to cover the last page
+	/// and anything appended after it. If no page is found, every page header will be read from the
+	/// start of the stream instead, which is much slower for large files.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	///
+	/// // My files never have anything after the last page
+	/// let parsing_options = ParseOptions::new().max_last_page_search(64 * 1024);
+	/// ```
+	pub fn max_last_page_search(&mut self, max_last_page_search: u64) -> Self {
+		self.max_last_page_search = max_last_page_search;
+		*self
+	}
 }
 
 /// The parsing strictness mode
@@ -1,3 +1,6 @@
+use crate::picture::{MimeType, Picture};
+use crate::util::text::TextEncoding;
+
 /// Options to control how Lofty writes to a file
 ///
 /// This acts as a dumping ground for all sorts of format-specific settings. As such, this is best
@@ -10,6 +13,9 @@ pub struct WriteOptions {
 	pub(crate) respect_read_only: bool,
 	pub(crate) uppercase_id3v2_chunk: bool,
 	pub(crate) use_id3v23: bool,
+	pub(crate) id3v2_text_encoding: Option<TextEncoding>,
+	pub(crate) write_strategy: WriteStrategy,
+	pub(crate) allowed_picture_mimes: Option<&'static [MimeType]>,
 }
 
 impl WriteOptions {
@@ -34,6 +40,9 @@ impl WriteOptions {
 			respect_read_only: true,
 			uppercase_id3v2_chunk: true,
 			use_id3v23: false,
+			id3v2_text_encoding: None,
+			write_strategy: WriteStrategy::InPlace,
+			allowed_picture_mimes: None,
 		}
 	}
 
@@ -177,6 +186,119 @@ impl WriteOptions {
 		self.use_id3v23 = use_id3v23;
 		*self
 	}
+
+	/// The text encoding to use for ID3v2 frames
+	///
+	/// By default (`None`), every frame is written with its own encoding. Otherwise, all frames that
+	/// store text will be written with this encoding instead.
+	///
+	/// If the preferred encoding is [`TextEncoding::Latin1`], any frame with text that can't be represented
+	/// in Latin-1 will fall back to [`TextEncoding::UTF8`] (or [`TextEncoding::UTF16`] when writing ID3v2.3).
+	///
+	/// NOTE: ID3v2.3 only supports [`TextEncoding::Latin1`] and [`TextEncoding::UTF16`], any other
+	///       encoding will be substituted with [`TextEncoding::UTF16`].
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::TextEncoding;
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // My old car stereo can only read Latin-1, so use it wherever possible
+	/// let options = WriteOptions::new().id3v2_text_encoding(Some(TextEncoding::Latin1));
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn id3v2_text_encoding(mut self, id3v2_text_encoding: Option<TextEncoding>) -> Self {
+		self.id3v2_text_encoding = id3v2_text_encoding;
+		self
+	}
+
+	/// How to write to a file when saving to a path
+	///
+	/// This only applies to methods that take a path, such as [`TagExt::save_to_path`](crate::tag::TagExt::save_to_path)
+	/// and [`AudioFile::save_to_path`](crate::file::AudioFile::save_to_path). See [`WriteStrategy`] for the available options.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::{WriteOptions, WriteStrategy};
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // I never want to end up with a half-written file
+	/// let options = WriteOptions::new().write_strategy(WriteStrategy::AtomicRename);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn write_strategy(mut self, write_strategy: WriteStrategy) -> Self {
+		self.write_strategy = write_strategy;
+		self
+	}
+
+	/// Only write pictures with one of these MIME types
+	///
+	/// Any other pictures will be dropped when writing, including pictures without a MIME type.
+	/// Setting this to `None` (the default) writes all pictures.
+	///
+	/// NOTE: Pictures are not re-encoded, only filtered out.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::picture::MimeType;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // I only want JPEG and PNG covers in my files
+	/// let options =
+	/// 	WriteOptions::new().allowed_picture_mimes(Some(&[MimeType::Jpeg, MimeType::Png]));
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn allowed_picture_mimes(
+		mut self,
+		allowed_picture_mimes: Option<&'static [MimeType]>,
+	) -> Self {
+		self.allowed_picture_mimes = allowed_picture_mimes;
+		self
+	}
+
+	pub(crate) fn is_picture_allowed(&self, picture: &Picture) -> bool {
+		let Some(allowed_picture_mimes) = self.allowed_picture_mimes else {
+			return true;
+		};
+
+		let allowed = picture
+			.mime_type()
+			.is_some_and(|mime_type| allowed_picture_mimes.contains(mime_type));
+		if !allowed {
+			log::debug!(
+				"Dropping picture with disallowed MIME type: {:?}",
+				picture.mime_type()
+			);
+		}
+
+		allowed
+	}
 }
 
 impl Default for WriteOptions {
@@ -191,9 +313,39 @@ impl Default for WriteOptions {
 	///     respect_read_only: true,
 	///     uppercase_id3v2_chunk: true,
 	///     use_id3v23: false,
+	///     id3v2_text_encoding: None,
+	///     write_strategy: WriteStrategy::InPlace,
+	///     allowed_picture_mimes: None,
 	/// }
 	/// ```
 	fn default() -> Self {
 		Self::new()
 	}
 }
+
+/// The strategy used when writing to a path
+///
+/// See [`WriteOptions::write_strategy`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum WriteStrategy {
+	/// Modify the file directly
+	///
+	/// This requires no additional disk space beyond what the new tags need, but an error
+	/// partway through writing (e.g. a full disk) can leave the file partially written.
+	#[default]
+	InPlace,
+	/// Write to a temporary copy of the file, then rename it over the original
+	///
+	/// The original file is never modified, it is either fully replaced or left untouched.
+	///
+	/// NOTES:
+	///
+	/// * This requires enough free space for a full copy of the file
+	/// * The temporary file is created in the same directory as the original, as renames are
+	///   only atomic within a single file system. As such, the directory must be writable.
+	///   See [`AudioFile::save_to_path_with_temp_dir`](crate::file::AudioFile::save_to_path_with_temp_dir)
+	///   and [`TagExt::save_to_path_with_temp_dir`](crate::tag::TagExt::save_to_path_with_temp_dir) to change this.
+	/// * The original file's permissions will be copied, as will its ownership where possible (Unix only)
+	AtomicRename,
+}
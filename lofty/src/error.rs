@@ -26,6 +26,11 @@ pub enum ErrorKind {
 	// File data related errors
 	/// Attempting to read/write an abnormally large amount of data
 	TooMuchData,
+	/// Arises when the audio stream is encrypted (e.g. DRM protected)
+	///
+	/// This is only raised with [`ParsingMode::Strict`](crate::config::ParsingMode::Strict), otherwise
+	/// the file is read as normal, without any audio properties.
+	EncryptedStream,
 	/// Expected the data to be a different size than provided
 	///
 	/// This occurs when the size of an item is written as one value, but that size is either too
@@ -561,6 +566,7 @@ impl Display for LoftyError {
 				f,
 				"Attempted to read/write an abnormally large amount of data"
 			),
+			ErrorKind::EncryptedStream => write!(f, "Reading: The audio stream is encrypted"),
 			ErrorKind::SizeMismatch => write!(
 				f,
 				"Encountered an invalid item size, either too big or too small to be valid"
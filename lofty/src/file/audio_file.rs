@@ -4,7 +4,6 @@ use crate::error::{LoftyError, Result};
 use crate::tag::TagType;
 
 use crate::util::io::{FileLike, Length, Truncate};
-use std::fs::OpenOptions;
 use std::io::{Read, Seek};
 use std::path::Path;
 
@@ -49,10 +48,54 @@ pub trait AudioFile: Into<TaggedFile> {
 	/// # Ok(()) }
 	/// ```
 	fn save_to_path(&self, path: impl AsRef<Path>, write_options: WriteOptions) -> Result<()> {
-		self.save_to(
-			&mut OpenOptions::new().read(true).write(true).open(path)?,
-			write_options,
-		)
+		crate::util::save::save_to_path(path.as_ref(), write_options, |file| {
+			self.save_to(file, write_options)
+		})
+	}
+
+	/// Attempts to write all tags to a path, going through a temporary file in `temp_dir`
+	///
+	/// This is the same as [`AudioFile::save_to_path`] with [`WriteStrategy::AtomicRename`](crate::config::WriteStrategy::AtomicRename),
+	/// regardless of [`WriteOptions::write_strategy`], except that the temporary file is created in
+	/// `temp_dir` rather than alongside the original. This is useful when the original's directory is
+	/// read-only, or its disk is full.
+	///
+	/// NOTE: The temporary file can only be renamed over the original if both are on the same file
+	///       system. Otherwise, this will error, leaving the original untouched and the finished
+	///       temporary file in `temp_dir`.
+	///
+	/// # Errors
+	///
+	/// * `path` does not exist
+	/// * A file can't be created in `temp_dir`
+	/// * The temporary file can't be renamed over `path`
+	/// * See [`AudioFile::save_to`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::file::{AudioFile, TaggedFileExt};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let mut tagged_file = lofty::read_from_path(path)?;
+	///
+	/// // Edit the tags
+	///
+	/// // My album directories are read-only, but the disk has a writable scratch directory
+	/// tagged_file.save_to_path_with_temp_dir(path, "/mnt/music/.scratch", WriteOptions::default())?;
+	/// # Ok(()) }
+	/// ```
+	fn save_to_path_with_temp_dir(
+		&self,
+		path: impl AsRef<Path>,
+		temp_dir: impl AsRef<Path>,
+		write_options: WriteOptions,
+	) -> Result<()> {
+		crate::util::save::save_to_path_with_temp_dir(path.as_ref(), temp_dir.as_ref(), |file| {
+			self.save_to(file, write_options)
+		})
 	}
 
 	/// Attempts to write all tags to a file
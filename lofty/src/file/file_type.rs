@@ -159,6 +159,58 @@ impl FileType {
 		)
 	}
 
+	/// Returns every [`TagType`] this `FileType` can hold
+	///
+	/// This includes tags that are only readable ([`TagSupport::ReadOnly`]), and matches the tags
+	/// accepted by [`TaggedFileExt::insert_tag`](crate::file::TaggedFileExt::insert_tag). For all
+	/// non-custom file types, the [primary tag type](FileType::primary_tag_type) is listed first.
+	///
+	/// | [`FileType`]                | [`TagType`]s                   |
+	/// |-----------------------------|--------------------------------|
+	/// | `Aac`                       | `Id3v2`, `Id3v1`               |
+	/// | `Aiff`                      | `Id3v2`, `AiffText`            |
+	/// | `Ape`, `Mpc`                | `Ape`, `Id3v1`, `Id3v2`        |
+	/// | `Flac`                      | `VorbisComments`, `Id3v2`      |
+	/// | `Mpeg`                      | `Id3v2`, `Id3v1`, `Ape`        |
+	/// | `Mp4`                       | `Mp4Ilst`                      |
+	/// | `Opus`, `Vorbis`, `Speex`   | `VorbisComments`               |
+	/// | `Wav`                       | `Id3v2`, `RiffInfo`            |
+	/// | `WavPack`                   | `Ape`, `Id3v1`                 |
+	///
+	/// # Panics
+	///
+	/// If an unregistered `FileType` ([`FileType::Custom`]) is encountered. See [`register_custom_resolver`](crate::resolve::register_custom_resolver).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::file::FileType;
+	/// use lofty::tag::TagType;
+	///
+	/// assert_eq!(
+	/// 	FileType::Vorbis.supported_tag_types(),
+	/// 	&[TagType::VorbisComments]
+	/// );
+	/// assert!(!FileType::Vorbis.supported_tag_types().contains(&TagType::Ape));
+	/// ```
+	pub fn supported_tag_types(&self) -> &'static [TagType] {
+		match self {
+			FileType::Aac => &[TagType::Id3v2, TagType::Id3v1],
+			FileType::Aiff => &[TagType::Id3v2, TagType::AiffText],
+			FileType::Ape | FileType::Mpc => &[TagType::Ape, TagType::Id3v1, TagType::Id3v2],
+			FileType::Flac => &[TagType::VorbisComments, TagType::Id3v2],
+			FileType::Mpeg => &[TagType::Id3v2, TagType::Id3v1, TagType::Ape],
+			FileType::Mp4 => &[TagType::Mp4Ilst],
+			FileType::Opus | FileType::Vorbis | FileType::Speex => &[TagType::VorbisComments],
+			FileType::Wav => &[TagType::Id3v2, TagType::RiffInfo],
+			FileType::WavPack => &[TagType::Ape, TagType::Id3v1],
+			FileType::Custom(c) => {
+				let resolver = crate::resolve::lookup_resolver(c);
+				resolver.supported_tag_types()
+			},
+		}
+	}
+
 	/// Attempts to extract a [`FileType`] from an extension
 	///
 	/// # Examples
@@ -353,7 +405,8 @@ impl FileType {
 				None
 			},
 			102 if buf.starts_with(b"fLaC") => Some(Self::Flac),
-			82 if buf.len() >= 12 && &buf[..4] == b"RIFF" => {
+			// RF64 and BW64 are WAV files with 64-bit sizes
+			66 | 82 if buf.len() >= 12 && matches!(&buf[..4], b"RIFF" | b"RF64" | b"BW64") => {
 				if &buf[8..12] == b"WAVE" {
 					return Some(Self::Wav);
 				}
@@ -380,3 +433,63 @@ pub(crate) enum FileTypeGuessResult {
 	/// The stream starts with potential junk data
 	MaybePrecededByJunk,
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::file::FileType;
+	use crate::tag::TagType;
+
+	const FILE_TYPES: [FileType; 12] = [
+		FileType::Aac,
+		FileType::Aiff,
+		FileType::Ape,
+		FileType::Flac,
+		FileType::Mpeg,
+		FileType::Mp4,
+		FileType::Mpc,
+		FileType::Opus,
+		FileType::Vorbis,
+		FileType::Speex,
+		FileType::Wav,
+		FileType::WavPack,
+	];
+
+	#[test_log::test]
+	fn supported_tag_types() {
+		let expected: [&[TagType]; 12] = [
+			&[TagType::Id3v2, TagType::Id3v1],
+			&[TagType::Id3v2, TagType::AiffText],
+			&[TagType::Ape, TagType::Id3v1, TagType::Id3v2],
+			&[TagType::VorbisComments, TagType::Id3v2],
+			&[TagType::Id3v2, TagType::Id3v1, TagType::Ape],
+			&[TagType::Mp4Ilst],
+			&[TagType::Ape, TagType::Id3v1, TagType::Id3v2],
+			&[TagType::VorbisComments],
+			&[TagType::VorbisComments],
+			&[TagType::VorbisComments],
+			&[TagType::Id3v2, TagType::RiffInfo],
+			&[TagType::Ape, TagType::Id3v1],
+		];
+
+		for (file_type, expected) in FILE_TYPES.into_iter().zip(expected) {
+			assert_eq!(file_type.supported_tag_types(), expected, "{file_type:?}");
+			assert_eq!(
+				file_type.supported_tag_types()[0],
+				file_type.primary_tag_type()
+			);
+		}
+	}
+
+	#[test_log::test]
+	fn supported_tag_types_match_tag_support() {
+		for file_type in FILE_TYPES {
+			for tag_type in TagType::ALL {
+				assert_eq!(
+					file_type.supported_tag_types().contains(&tag_type),
+					file_type.tag_support(tag_type).is_readable(),
+					"{file_type:?}: {tag_type:?}"
+				);
+			}
+		}
+	}
+}
@@ -2,10 +2,13 @@
 
 mod audio_file;
 mod file_type;
+mod roundtrip;
+pub(crate) mod tag_space;
 mod tagged_file;
 
 pub use audio_file::AudioFile;
 pub use file_type::{EXTENSIONS, FileType};
+pub use roundtrip::{RoundtripDifference, verify_roundtrip};
 pub use tagged_file::{BoundTaggedFile, TaggedFile, TaggedFileExt};
 
 pub(crate) use file_type::FileTypeGuessResult;
@@ -0,0 +1,163 @@
+use super::audio_file::AudioFile;
+use super::file_type::FileType;
+use super::tagged_file::{TaggedFile, TaggedFileExt};
+use crate::config::{ParseOptions, WriteOptions};
+use crate::error::Result;
+use crate::probe::Probe;
+use crate::tag::{Tag, TagType};
+
+use std::io::{Cursor, Seek};
+use std::path::Path;
+
+use ogg_pager::Page;
+
+/// A difference found by [`verify_roundtrip`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RoundtripDifference {
+	/// A tag of this type was present in the original file, but not the re-read one
+	TagRemoved(TagType),
+	/// A tag of this type was present in the re-read file, but not the original one
+	TagAdded(TagType),
+	/// The items or pictures of the tag of this type differ
+	TagContents(TagType),
+	/// The audio properties differ
+	Properties,
+	/// The audio pages differ
+	///
+	/// This is only checked for OGG formats ([`FileType::Opus`], [`FileType::Vorbis`], and [`FileType::Speex`]).
+	AudioData,
+}
+
+/// Read a file, write it back unchanged, and compare the result against the original
+///
+/// The file at `path` is **not** modified, the write happens in memory.
+///
+/// The tags and properties of the re-read file are compared against those of the original. Items
+/// and pictures are compared regardless of their order, since some formats do not preserve it.
+///
+/// For OGG formats, the audio pages are also compared. Their contents, granule positions and flags
+/// must be identical, though sequence numbers and checksums are allowed to change, as those
+/// depend on the size of the metadata.
+///
+/// An empty list means the file survived the round trip.
+///
+/// # Errors
+///
+/// * `path` does not exist
+/// * The file could not be read or written, see [`AudioFile::read_from`] and [`AudioFile::save_to`]
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::config::{ParseOptions, WriteOptions};
+/// use lofty::file::verify_roundtrip;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// let differences = verify_roundtrip(path, ParseOptions::new(), WriteOptions::default())?;
+/// assert!(differences.is_empty());
+/// # Ok(()) }
+/// ```
+pub fn verify_roundtrip<P>(
+	path: P,
+	parse_options: ParseOptions,
+	write_options: WriteOptions,
+) -> Result<Vec<RoundtripDifference>>
+where
+	P: AsRef<Path>,
+{
+	let original_bytes = std::fs::read(path)?;
+
+	let original = Probe::new(Cursor::new(original_bytes.as_slice()))
+		.options(parse_options)
+		.guess_file_type()?
+		.read()?;
+
+	let mut written = Cursor::new(original_bytes.clone());
+	original.save_to(&mut written, write_options)?;
+	written.rewind()?;
+
+	let re_read = Probe::with_file_type(&mut written, original.file_type())
+		.options(parse_options)
+		.read()?;
+
+	let mut differences = Vec::new();
+	compare_tags(&original, &re_read, &mut differences);
+
+	if original.properties() != re_read.properties() {
+		differences.push(RoundtripDifference::Properties);
+	}
+
+	if matches!(
+		original.file_type(),
+		FileType::Opus | FileType::Vorbis | FileType::Speex
+	) && ogg_audio_pages(&original_bytes) != ogg_audio_pages(written.get_ref())
+	{
+		differences.push(RoundtripDifference::AudioData);
+	}
+
+	Ok(differences)
+}
+
+fn compare_tags(
+	original: &TaggedFile,
+	re_read: &TaggedFile,
+	differences: &mut Vec<RoundtripDifference>,
+) {
+	for tag in original.tags() {
+		let tag_type = tag.tag_type();
+		match re_read.tag(tag_type) {
+			Some(re_read_tag) if !tag_contents_eq(tag, re_read_tag) => {
+				differences.push(RoundtripDifference::TagContents(tag_type));
+			},
+			None => differences.push(RoundtripDifference::TagRemoved(tag_type)),
+			_ => {},
+		}
+	}
+
+	for tag in re_read.tags() {
+		if original.tag(tag.tag_type()).is_none() {
+			differences.push(RoundtripDifference::TagAdded(tag.tag_type()));
+		}
+	}
+}
+
+pub(super) fn tag_contents_eq(a: &Tag, b: &Tag) -> bool {
+	let a_items = a.items().collect::<Vec<_>>();
+	let b_items = b.items().collect::<Vec<_>>();
+
+	same_elements(&a_items, &b_items) && same_elements(a.pictures(), b.pictures())
+}
+
+// Order-insensitive comparison, accounting for duplicates
+fn same_elements<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+	let count = |list: &[T], target: &T| list.iter().filter(|item| *item == target).count();
+
+	a.len() == b.len() && a.iter().all(|item| count(a, item) == count(b, item))
+}
+
+// The header pages are always written with a granule position of 0, so anything else is audio.
+//
+// The granule position, flags, and content are returned for each page. A stream that fails to
+// parse partway through will simply have fewer pages, which will then fail the comparison.
+fn ogg_audio_pages(bytes: &[u8]) -> Vec<(u64, u8, Vec<u8>)> {
+	let mut reader = Cursor::new(bytes);
+
+	let mut pages = Vec::new();
+	while (reader.position() as usize) < bytes.len() {
+		let Ok(page) = Page::read(&mut reader) else {
+			break;
+		};
+
+		let abgp = page.header().abgp;
+		if abgp == 0 {
+			continue;
+		}
+
+		let flags = page.header().header_type_flag();
+		pages.push((abgp, flags, page.take_content()));
+	}
+
+	pages
+}
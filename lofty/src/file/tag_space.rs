@@ -0,0 +1,137 @@
+use super::file_type::FileType;
+use crate::config::{ParseOptions, WriteOptions};
+use crate::error::Result;
+use crate::id3::v2::Id3v2Tag;
+use crate::macros::err;
+use crate::probe::Probe;
+use crate::tag::{Tag, TagExt, TagType};
+
+use std::io::{Read, Seek};
+
+/// The space taken up by a tag preceding the audio data, which can be reused when writing
+///
+/// This includes any padding. For tags that retain parts of the original, such as the vendor string
+/// of Vorbis comments, those parts are excluded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct TagSpace {
+	pub(crate) tag_type: TagType,
+	pub(crate) size: u64,
+}
+
+/// Reads the [`TagSpace`] of the file at the current position of `reader` again, after its tags
+/// were written
+pub(crate) fn reread_tag_space<R>(reader: &mut R, file_type: FileType) -> Option<TagSpace>
+where
+	R: Read + Seek,
+{
+	if !matches!(
+		file_type,
+		FileType::Aac
+			| FileType::Flac
+			| FileType::Mpeg
+			| FileType::Opus
+			| FileType::Speex
+			| FileType::Vorbis
+	) {
+		return None;
+	}
+
+	let parse_options = ParseOptions::new()
+		.read_properties(false)
+		.read_cover_art(false);
+	match Probe::with_file_type(reader, file_type)
+		.options(parse_options)
+		.read()
+	{
+		Ok(tagged_file) => tagged_file.tag_space,
+		Err(e) => {
+			log::debug!("Unable to determine the space available for tags: {e}");
+			None
+		},
+	}
+}
+
+/// Whether writing `tags` would need to move the audio data
+pub(crate) fn requires_rewrite<'a>(
+	file_type: FileType,
+	tags: impl IntoIterator<Item = &'a Tag>,
+	tag_space: Option<TagSpace>,
+) -> bool {
+	for tag in tags {
+		let tag_type = tag.tag_type();
+		if is_stored_after_audio(file_type, tag_type) {
+			continue;
+		}
+
+		let space = tag_space
+			.filter(|space| space.tag_type == tag_type)
+			.map(|space| space.size);
+
+		let size = match required_space(file_type, tag) {
+			Ok(Some(size)) => size,
+			// Nothing is written, which only moves the audio if an existing tag is removed
+			Ok(None) if space.is_none() => continue,
+			Ok(None) => return true,
+			Err(e) => {
+				log::debug!("Unable to determine the size of the {tag_type:?} tag: {e}");
+				return true;
+			},
+		};
+
+		let Some(space) = space else {
+			return true;
+		};
+
+		let fits = match file_type {
+			// Any space left over has to be filled with a single PADDING block, which has a 4 byte
+			// header and a 24-bit size
+			FileType::Flac => size == space || (size + 4 <= space && space - size - 4 <= 0xFF_FFFF),
+			_ => size <= space,
+		};
+
+		if !fits {
+			return true;
+		}
+	}
+
+	false
+}
+
+// Tags that are written after the audio data can always be written without moving it
+fn is_stored_after_audio(file_type: FileType, tag_type: TagType) -> bool {
+	match tag_type {
+		TagType::Id3v1 => true,
+		TagType::Ape => matches!(
+			file_type,
+			FileType::Ape | FileType::Mpc | FileType::Mpeg | FileType::WavPack
+		),
+		_ => false,
+	}
+}
+
+// The size of `tag` once written, without any padding, or `None` if nothing is written
+//
+// Returns an error if the layout of the tag in `file_type` isn't known.
+fn required_space(file_type: FileType, tag: &Tag) -> Result<Option<u64>> {
+	match (file_type, tag.tag_type()) {
+		(FileType::Aac | FileType::Mpeg, TagType::Id3v2) => {
+			// Empty tags are removed
+			if tag.is_empty() {
+				return Ok(None);
+			}
+
+			let mut id3v2 = Vec::new();
+			Id3v2Tag::from(tag.clone())
+				.dump_to(&mut id3v2, WriteOptions::new().preferred_padding(0))?;
+			Ok(Some(id3v2.len() as u64))
+		},
+		(FileType::Flac, TagType::VorbisComments) => {
+			crate::flac::write::comment_blocks_size(tag).map(Some)
+		},
+		// The comment header is required, so empty tags are still written
+		(FileType::Opus | FileType::Speex | FileType::Vorbis, TagType::VorbisComments) => {
+			crate::ogg::write::comment_header_size(tag, file_type).map(Some)
+		},
+		_ => err!(UnsupportedTag),
+	}
+}
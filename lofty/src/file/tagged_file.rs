@@ -1,12 +1,15 @@
 use super::audio_file::AudioFile;
 use super::file_type::FileType;
+use super::tag_space::TagSpace;
 use crate::config::{ParseOptions, WriteOptions};
 use crate::error::{LoftyError, Result};
+use crate::id3::v1::Id3v1Tag;
 use crate::properties::FileProperties;
 use crate::tag::{Tag, TagExt, TagSupport, TagType};
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::io::{Read, Seek};
+use std::path::Path;
 
 /// Provides a common interface between [`TaggedFile`] and [`BoundTaggedFile`]
 pub trait TaggedFileExt {
@@ -244,7 +247,7 @@ pub trait TaggedFileExt {
 	/// Inserts a [`Tag`]
 	///
 	/// NOTE: This will do nothing if the [`FileType`] does not support the [`TagType`].
-	/// See [`FileType::tag_support()`]
+	/// See [`FileType::supported_tag_types()`]
 	///
 	/// If a tag is replaced, it will be returned
 	///
@@ -271,6 +274,47 @@ pub trait TaggedFileExt {
 	/// ```
 	fn insert_tag(&mut self, tag: Tag) -> Option<Tag>;
 
+	/// Gets a mutable reference to a specific [`TagType`], inserting an empty [`Tag`] if it doesn't exist
+	///
+	/// This will return `None` if the [`FileType`] does not support the [`TagType`].
+	/// See [`FileType::tag_support()`]
+	///
+	/// NOTE: An empty tag will be stripped when saving. Some formats can't represent one (for example,
+	///       an `ID3v2` tag must contain at least one frame), so at least one item or picture needs
+	///       to be added for the tag to be written.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::file::{AudioFile, TaggedFileExt};
+	/// use lofty::tag::{Accessor, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path_to_mp3 = "tests/files/assets/minimal/full_test.mp3";
+	/// // Read an MP3 file without an ID3v2 tag
+	/// let mut tagged_file = lofty::read_from_path(path_to_mp3)?;
+	/// # let _ = tagged_file.remove(TagType::Id3v2); // sneaky
+	///
+	/// assert!(!tagged_file.contains_tag_type(TagType::Id3v2));
+	///
+	/// if let Some(id3v2) = tagged_file.get_or_insert_tag(TagType::Id3v2) {
+	/// 	id3v2.set_title(String::from("Foo title"));
+	/// }
+	///
+	/// assert!(tagged_file.contains_tag_type(TagType::Id3v2));
+	///
+	/// // MP3 files can't contain Vorbis Comments
+	/// assert!(tagged_file.get_or_insert_tag(TagType::VorbisComments).is_none());
+	/// # Ok(()) }
+	/// ```
+	fn get_or_insert_tag(&mut self, tag_type: TagType) -> Option<&mut Tag> {
+		if self.tag(tag_type).is_none() {
+			let _ = self.insert_tag(Tag::new(tag_type));
+		}
+
+		self.tag_mut(tag_type)
+	}
+
 	/// Removes a specific [`TagType`] and returns it
 	///
 	/// # Examples
@@ -294,6 +338,69 @@ pub trait TaggedFileExt {
 	/// ```
 	fn remove(&mut self, tag_type: TagType) -> Option<Tag>;
 
+	/// Converts the tag of type `from` into a tag of type `to`
+	///
+	/// The fields are mapped through the generic [`Tag`], so any items that have no equivalent in
+	/// the target format are dropped. If `remove_source` is set, the original tag will be removed
+	/// from the file. An existing tag of type `to` will be replaced.
+	///
+	/// This will return `None` if the [`FileType`] does not support `to`, or the file has no tag of
+	/// type `from`. In either case, the file is left untouched.
+	///
+	/// NOTE: Conversions to `ID3v1` are lossy:
+	///
+	/// * The title, artist, and album are truncated to 30 bytes, and the comment to 28 bytes,
+	///   without splitting a character
+	/// * Genres that aren't in [`GENRES`](crate::id3::v1::GENRES) are dropped
+	/// * Track numbers that don't fit in a byte are dropped
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::file::{AudioFile, TaggedFileExt};
+	/// use lofty::tag::TagType;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path_to_mp3 = "tests/files/assets/minimal/full_test.mp3";
+	/// // Read an MP3 file with only an ID3v1 tag
+	/// let mut tagged_file = lofty::read_from_path(path_to_mp3)?;
+	/// # let _ = tagged_file.remove(TagType::Id3v2); // sneaky
+	///
+	/// // Upgrade it to ID3v2
+	/// let id3v2 = tagged_file.convert_tag(TagType::Id3v1, TagType::Id3v2, true);
+	/// assert!(id3v2.is_some());
+	///
+	/// assert!(tagged_file.contains_tag_type(TagType::Id3v2));
+	/// assert!(!tagged_file.contains_tag_type(TagType::Id3v1));
+	/// # Ok(()) }
+	/// ```
+	fn convert_tag(&mut self, from: TagType, to: TagType, remove_source: bool) -> Option<&mut Tag> {
+		if !self.file_type().supported_tag_types().contains(&to) {
+			log::warn!(
+				"Unable to convert to {to:?}, not supported by {:?}",
+				self.file_type()
+			);
+			return None;
+		}
+
+		let mut tag = if remove_source {
+			self.remove(from)?
+		} else {
+			self.tag(from)?.clone()
+		};
+
+		tag.re_map(to);
+
+		if to == TagType::Id3v1 {
+			let mut id3v1 = Id3v1Tag::from(tag);
+			id3v1.truncate_fields();
+			tag = id3v1.into();
+		}
+
+		let _ = self.insert_tag(tag);
+		self.tag_mut(to)
+	}
+
 	/// Removes all tags from the file
 	///
 	/// # Examples
@@ -323,6 +430,8 @@ pub struct TaggedFile {
 	pub(crate) properties: FileProperties,
 	/// A collection of the file's tags
 	pub(crate) tags: Vec<Tag>,
+	/// The space taken up by the file's tags, if known
+	pub(crate) tag_space: Option<TagSpace>,
 }
 
 impl TaggedFile {
@@ -334,6 +443,7 @@ impl TaggedFile {
 			ty,
 			properties,
 			tags,
+			tag_space: None,
 		}
 	}
 
@@ -366,9 +476,129 @@ impl TaggedFile {
 	pub fn change_file_type(&mut self, file_type: FileType) {
 		self.ty = file_type;
 		self.properties = FileProperties::default();
+		self.tag_space = None;
 		self.tags
 			.retain(|t| self.ty.tag_support(t.tag_type()).is_readable());
 	}
+
+	/// Attempts to write all tags to a path, only if they differ from what is currently on disk
+	///
+	/// The tags currently in the file are read and compared against those in `self`. If every
+	/// writable tag is identical (ignoring item order), the file is left untouched. Otherwise, this
+	/// is the same as [`AudioFile::save_to_path`].
+	///
+	/// Returns `true` if the file was written.
+	///
+	/// # Errors
+	///
+	/// * `path` does not exist
+	/// * `path` is not writable
+	/// * The tags currently in the file could not be read
+	/// * See [`AudioFile::save_to`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::file::TaggedFileExt;
+	/// use lofty::tag::Accessor;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let mut tagged_file = lofty::read_from_path(path)?;
+	///
+	/// // Nothing has changed, so nothing is written
+	/// assert!(!tagged_file.save_if_changed(path, WriteOptions::default())?);
+	///
+	/// if let Some(tag) = tagged_file.primary_tag_mut() {
+	/// 	tag.set_title(String::from("Foo title"));
+	/// }
+	///
+	/// assert!(tagged_file.save_if_changed(path, WriteOptions::default())?);
+	/// # Ok(()) }
+	/// ```
+	pub fn save_if_changed(
+		&self,
+		path: impl AsRef<Path>,
+		write_options: WriteOptions,
+	) -> Result<bool> {
+		let path = path.as_ref();
+
+		let on_disk = crate::probe::Probe::open(path)?
+			.set_file_type(self.ty)
+			.options(ParseOptions::new().read_properties(false))
+			.read()?;
+
+		let changed = self
+			.tags
+			.iter()
+			.filter(|tag| self.tag_support(tag.tag_type()).is_writable())
+			.any(|tag| match on_disk.tag(tag.tag_type()) {
+				Some(existing) => !super::roundtrip::tag_contents_eq(tag, existing),
+				// Writing an empty tag removes it, which is a no-op if it doesn't exist
+				None => !tag.is_empty(),
+			});
+
+		if !changed {
+			log::debug!("Tags are unchanged, skipping write");
+			return Ok(false);
+		}
+
+		self.save_to_path(path, write_options)?;
+		Ok(true)
+	}
+
+	/// Whether writing the tags would require the audio data to be moved
+	///
+	/// This compares the size of each tag against the space its original counterpart took up in the
+	/// file, including any padding. If every tag fits, saving writes them over the original space
+	/// without shifting the audio data.
+	///
+	/// The space is only known for the formats that store their tags ahead of the audio data in a
+	/// reusable way:
+	///
+	/// * ID3v2 in AAC and MPEG files, including its padding
+	/// * Vorbis comments in FLAC files, including the PICTURE and PADDING blocks
+	/// * Vorbis comments in Opus, Speex, and Vorbis files, including the comment header padding. The
+	///   identification header has to be on a page of its own.
+	///
+	/// Tags stored after the audio data, such as ID3v1, never require a rewrite. Any other tag is
+	/// assumed to require one, as is any tag in a `TaggedFile` that wasn't read with [`Probe`].
+	/// Tags that are not writable in the file's format are ignored, as they are skipped when saving.
+	///
+	/// NOTE: This is only a prediction. The size of the new tags is calculated with the default
+	/// [`WriteOptions`], without any padding.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::file::TaggedFileExt;
+	/// use lofty::tag::Accessor;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let mut tagged_file = lofty::read_from_path(path)?;
+	///
+	/// if let Some(tag) = tagged_file.primary_tag_mut() {
+	/// 	tag.set_title(String::from("Foo title"));
+	/// }
+	///
+	/// if tagged_file.write_requires_rewrite() {
+	/// 	println!("The audio data will have to be moved");
+	/// }
+	/// # Ok(()) }
+	/// ```
+	///
+	/// [`Probe`]: crate::probe::Probe
+	pub fn write_requires_rewrite(&self) -> bool {
+		super::tag_space::requires_rewrite(
+			self.ty,
+			self.tags
+				.iter()
+				.filter(|tag| self.tag_support(tag.tag_type()).is_writable()),
+			self.tag_space,
+		)
+	}
 }
 
 impl TaggedFileExt for TaggedFile {
@@ -395,7 +625,7 @@ impl TaggedFileExt for TaggedFile {
 	fn insert_tag(&mut self, tag: Tag) -> Option<Tag> {
 		let tag_type = tag.tag_type();
 
-		if self.tag_support(tag_type).is_readable() {
+		if self.ty.supported_tag_types().contains(&tag_type) {
 			let ret = self.remove(tag_type);
 			self.tags.push(tag);
 
@@ -542,6 +772,15 @@ impl<F> BoundTaggedFile<F> {
 	pub fn into_inner(self) -> F {
 		self.file_handle
 	}
+
+	/// Whether writing the tags would require the audio data to be moved
+	///
+	/// The space available to the tags is updated on every [`save`](Self::save).
+	///
+	/// See [`TaggedFile::write_requires_rewrite`].
+	pub fn write_requires_rewrite(&self) -> bool {
+		self.inner.write_requires_rewrite()
+	}
 }
 
 impl<F: FileLike> BoundTaggedFile<F>
@@ -614,6 +853,10 @@ where
 		self.inner.save_to(&mut self.file_handle, write_options)?;
 		self.inner.tags.retain(|tag| !tag.is_empty());
 
+		self.file_handle.rewind()?;
+		self.inner.tag_space =
+			super::tag_space::reread_tag_space(&mut self.file_handle, self.inner.ty);
+
 		Ok(())
 	}
 }
@@ -686,3 +929,50 @@ impl<T> AudioFile for BoundTaggedFile<T> {
 		self.inner.contains_tag_type(tag_type)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::config::WriteOptions;
+	use crate::file::TaggedFileExt;
+	use crate::tag::Accessor;
+
+	#[test_log::test]
+	fn save_if_changed() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("test.mp3");
+		std::fs::copy("tests/files/assets/minimal/full_test.mp3", &path).unwrap();
+
+		let original_content = std::fs::read(&path).unwrap();
+		let original_mtime = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+		let mut tagged_file = crate::read_from_path(&path).unwrap();
+
+		// Writing an unchanged file shouldn't touch it at all
+		assert!(
+			!tagged_file
+				.save_if_changed(&path, WriteOptions::default())
+				.unwrap()
+		);
+		assert_eq!(
+			std::fs::metadata(&path).unwrap().modified().unwrap(),
+			original_mtime
+		);
+		assert_eq!(std::fs::read(&path).unwrap(), original_content);
+
+		tagged_file
+			.primary_tag_mut()
+			.unwrap()
+			.set_title(String::from("Foo title"));
+		assert!(
+			tagged_file
+				.save_if_changed(&path, WriteOptions::default())
+				.unwrap()
+		);
+
+		let tagged_file = crate::read_from_path(&path).unwrap();
+		assert_eq!(
+			tagged_file.primary_tag().unwrap().title().as_deref(),
+			Some("Foo title")
+		);
+	}
+}
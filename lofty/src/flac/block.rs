@@ -9,6 +9,7 @@ use byteorder::{BigEndian, ReadBytesExt};
 
 pub(in crate::flac) const BLOCK_ID_STREAMINFO: u8 = 0;
 pub(in crate::flac) const BLOCK_ID_PADDING: u8 = 1;
+pub(in crate::flac) const BLOCK_ID_APPLICATION: u8 = 2;
 pub(in crate::flac) const BLOCK_ID_SEEKTABLE: u8 = 3;
 pub(in crate::flac) const BLOCK_ID_VORBIS_COMMENTS: u8 = 4;
 pub(in crate::flac) const BLOCK_ID_PICTURE: u8 = 6;
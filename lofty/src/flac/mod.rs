@@ -11,7 +11,9 @@ pub(crate) mod write;
 
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
+use crate::file::tag_space::TagSpace;
 use crate::file::{FileType, TaggedFile};
+use crate::flac::write::BlockReplacement;
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::ogg::tag::VorbisCommentsRef;
 use crate::ogg::{OggPictureStorage, VorbisComments};
@@ -49,11 +51,94 @@ pub struct FlacFile {
 	#[lofty(tag_type = "VorbisComments")]
 	pub(crate) vorbis_comments_tag: Option<VorbisComments>,
 	pub(crate) pictures: Vec<(Picture, PictureInformation)>,
+	pub(crate) application_blocks: Vec<(u32, Vec<u8>)>,
+	// Whether `application_blocks` reflects the blocks in the file
+	pub(crate) application_blocks_read: bool,
+	pub(crate) application_blocks_modified: bool,
 	/// The file's audio properties
 	pub(crate) properties: FlacProperties,
+	pub(crate) tag_space: Option<TagSpace>,
 }
 
 impl FlacFile {
+	/// Returns the file's APPLICATION blocks
+	///
+	/// Each block is an application ID (see the [registered IDs]) and its data.
+	///
+	/// NOTE: These are only read if [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()) is enabled.
+	///
+	/// [registered IDs]: https://www.xiph.org/flac/id.html
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::flac::FlacFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/full_test.flac")?;
+	/// let mut flac_file = FlacFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// flac_file.insert_application_block(u32::from_be_bytes(*b"foo!"), vec![1, 2, 3]);
+	///
+	/// for (id, data) in flac_file.application_blocks() {
+	/// 	println!("{}: {data:?}", String::from_utf8_lossy(&id.to_be_bytes()));
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn application_blocks(&self) -> impl Iterator<Item = (u32, &[u8])> + Clone {
+		self.application_blocks
+			.iter()
+			.map(|(id, data)| (*id, data.as_slice()))
+	}
+
+	/// Appends an APPLICATION block
+	///
+	/// NOTES:
+	///
+	/// * This will not replace any existing blocks with the same ID, see [`FlacFile::remove_application_blocks`].
+	/// * If the file was read without [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()),
+	///   the block will be written alongside the blocks already in the file.
+	pub fn insert_application_block(&mut self, id: u32, data: Vec<u8>) {
+		self.application_blocks.push((id, data));
+		self.application_blocks_modified = true;
+	}
+
+	/// Removes all APPLICATION blocks with the ID `id`
+	///
+	/// NOTE: If the file was read without [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()),
+	///       this will only remove blocks added with [`FlacFile::insert_application_block`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::flac::FlacFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/full_test.flac")?;
+	/// let mut flac_file = FlacFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// let id = u32::from_be_bytes(*b"foo!");
+	/// flac_file.insert_application_block(id, vec![1, 2, 3]);
+	/// assert_eq!(flac_file.application_blocks().count(), 1);
+	///
+	/// flac_file.remove_application_blocks(id);
+	/// assert_eq!(flac_file.application_blocks().count(), 0);
+	/// # Ok(()) }
+	/// ```
+	pub fn remove_application_blocks(&mut self, id: u32) {
+		let len = self.application_blocks.len();
+		self.application_blocks
+			.retain(|(block_id, _)| *block_id != id);
+
+		if self.application_blocks.len() != len {
+			self.application_blocks_modified = true;
+		}
+	}
+
 	// We need a special write fn to append our pictures into a `VorbisComments` tag
 	fn write_to<F>(&self, file: &mut F, write_options: WriteOptions) -> Result<()>
 	where
@@ -66,9 +151,17 @@ impl FlacFile {
 			file.rewind()?;
 		}
 
+		// Blocks that were never read can't be replaced, only added to
+		let application_blocks = self.application_blocks.as_slice();
+		let replacement = if self.application_blocks_read {
+			BlockReplacement::TagsAndApplicationBlocks
+		} else {
+			BlockReplacement::Tags
+		};
+
 		// We have an existing vorbis comments tag, we can just append our pictures to it
 		if let Some(ref vorbis_comments) = self.vorbis_comments_tag {
-			return VorbisCommentsRef {
+			let mut comments_ref = VorbisCommentsRef {
 				vendor: Cow::from(vorbis_comments.vendor.as_str()),
 				items: vorbis_comments
 					.items
@@ -79,18 +172,42 @@ impl FlacFile {
 					.iter()
 					.map(|(p, i)| (p, *i))
 					.chain(self.pictures.iter().map(|(p, i)| (p, *i))),
-			}
-			.write_to(file, write_options);
+			};
+
+			return write::write_to_inner(
+				file,
+				&mut comments_ref,
+				application_blocks,
+				replacement,
+				write_options,
+			);
 		}
 
-		// We have pictures, but no vorbis comments tag, we'll need to create a dummy one
-		if !self.pictures.is_empty() {
-			return VorbisCommentsRef {
+		// We have pictures or application block changes, but no vorbis comments tag, we'll need a dummy one
+		if !self.pictures.is_empty()
+			|| !self.application_blocks.is_empty()
+			|| self.application_blocks_modified
+		{
+			// The comments and pictures were never read either, so the existing ones have to stay
+			let replacement = if self.application_blocks_read {
+				replacement
+			} else {
+				BlockReplacement::None
+			};
+
+			let mut comments_ref = VorbisCommentsRef {
 				vendor: Cow::from(""),
 				items: std::iter::empty(),
 				pictures: self.pictures.iter().map(|(p, i)| (p, *i)),
-			}
-			.write_to(file, write_options);
+			};
+
+			return write::write_to_inner(
+				file,
+				&mut comments_ref,
+				application_blocks,
+				replacement,
+				write_options,
+			);
 		}
 
 		Ok(())
@@ -126,6 +243,7 @@ impl From<FlacFile> for TaggedFile {
 							vendor: String::new(),
 							items: Vec::new(),
 							pictures: value.pictures,
+							padding_len: 0,
 						}
 						.into(),
 					),
@@ -134,6 +252,7 @@ impl From<FlacFile> for TaggedFile {
 
 				tags
 			},
+			tag_space: value.tag_space,
 		}
 	}
 }
@@ -29,6 +29,8 @@ impl From<FlacProperties> for FileProperties {
 			bit_depth: Some(input.bit_depth),
 			channels: Some(input.channels),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
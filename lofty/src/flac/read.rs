@@ -3,12 +3,17 @@ use super::block::Block;
 use super::properties::FlacProperties;
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
-use crate::flac::block::{BLOCK_ID_PICTURE, BLOCK_ID_STREAMINFO, BLOCK_ID_VORBIS_COMMENTS};
+use crate::file::tag_space::TagSpace;
+use crate::flac::block::{
+	BLOCK_ID_APPLICATION, BLOCK_ID_PADDING, BLOCK_ID_PICTURE, BLOCK_ID_STREAMINFO,
+	BLOCK_ID_VORBIS_COMMENTS,
+};
 use crate::id3::v2::read::parse_id3v2;
 use crate::id3::{FindId3v2Config, ID3FindResults, find_id3v2};
 use crate::macros::{decode_err, err};
 use crate::ogg::read::read_comments;
 use crate::picture::Picture;
+use crate::tag::TagType;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -41,7 +46,11 @@ where
 		id3v2_tag: None,
 		vorbis_comments_tag: None,
 		pictures: Vec::new(),
+		application_blocks: Vec::new(),
+		application_blocks_read: parse_options.read_tags,
+		application_blocks_modified: false,
 		properties: FlacProperties::default(),
+		tag_space: None,
 	};
 
 	let find_id3v2_config = if parse_options.read_tags {
@@ -69,14 +78,32 @@ where
 
 	let mut last_block = stream_info.last;
 
+	// The space taken up by the Vorbis comments, pictures, and padding, which can be reused when
+	// writing. The vendor string is excluded, as it is always retained.
+	let mut tag_space = 0;
+
 	while !last_block {
 		let block = Block::read(data, |block_type| {
-			(block_type == BLOCK_ID_VORBIS_COMMENTS && parse_options.read_tags)
+			((block_type == BLOCK_ID_VORBIS_COMMENTS || block_type == BLOCK_ID_APPLICATION)
+				&& parse_options.read_tags)
 				|| (block_type == BLOCK_ID_PICTURE && parse_options.read_cover_art)
 		})?;
 
 		last_block = block.last;
 
+		let block_len = block.end - block.start;
+		match block.ty {
+			BLOCK_ID_PICTURE | BLOCK_ID_PADDING => tag_space += block_len,
+			BLOCK_ID_VORBIS_COMMENTS => {
+				let vendor_len = block
+					.content
+					.first_chunk::<4>()
+					.map_or(0, |vendor_len| u32::from_le_bytes(*vendor_len));
+				tag_space += block_len.saturating_sub(u64::from(vendor_len));
+			},
+			_ => {},
+		}
+
 		if block.content.is_empty() {
 			continue;
 		}
@@ -109,6 +136,24 @@ where
 			continue;
 		}
 
+		if block.ty == BLOCK_ID_APPLICATION && parse_options.read_tags {
+			log::debug!("Encountered a FLAC application block, parsing");
+
+			let Some((id, data)) = block.content.split_first_chunk::<4>() else {
+				if parse_options.parsing_mode == ParsingMode::Strict {
+					decode_err!(@BAIL Flac, "Application block is too small to contain an ID");
+				}
+
+				log::warn!("Application block is too small to contain an ID, discarding");
+				continue;
+			};
+
+			flac_file
+				.application_blocks
+				.push((u32::from_be_bytes(*id), data.to_vec()));
+			continue;
+		}
+
 		if block.ty == BLOCK_ID_PICTURE && parse_options.read_cover_art {
 			log::debug!("Encountered a FLAC picture block, parsing");
 
@@ -126,6 +171,13 @@ where
 		}
 	}
 
+	if parse_options.read_tags {
+		flac_file.tag_space = Some(TagSpace {
+			tag_type: TagType::VorbisComments,
+			size: tag_space,
+		});
+	}
+
 	if !parse_options.read_properties {
 		return Ok(flac_file);
 	}
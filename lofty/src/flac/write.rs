@@ -1,4 +1,6 @@
-use super::block::{BLOCK_ID_PADDING, BLOCK_ID_PICTURE, BLOCK_ID_VORBIS_COMMENTS, Block};
+use super::block::{
+	BLOCK_ID_APPLICATION, BLOCK_ID_PADDING, BLOCK_ID_PICTURE, BLOCK_ID_VORBIS_COMMENTS, Block,
+};
 use super::read::verify_flac;
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
@@ -33,7 +35,13 @@ where
 				pictures,
 			};
 
-			write_to_inner(file, &mut comments_ref, write_options)
+			write_to_inner(
+				file,
+				&mut comments_ref,
+				&[],
+				BlockReplacement::Tags,
+				write_options,
+			)
 		},
 		// This tag can *only* be removed in this format
 		TagType::Id3v2 => {
@@ -43,9 +51,33 @@ where
 	}
 }
 
+/// The existing blocks that a write replaces
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum BlockReplacement {
+	/// Replace the comments and pictures, leaving the APPLICATION blocks untouched
+	Tags,
+	/// Replace the comments, pictures, and APPLICATION blocks
+	TagsAndApplicationBlocks,
+	/// Leave the comments, pictures, and APPLICATION blocks untouched
+	///
+	/// This is used when the tags were never read, so the new APPLICATION blocks can only be added
+	/// to the file.
+	None,
+}
+
+/// Writes the comments, pictures, and APPLICATION blocks to the file
+///
+/// `application_blocks` are always written. `replacement` determines which of the existing blocks
+/// are removed.
+///
+/// If the new metadata fits in the space of the old metadata, including any PADDING blocks, it is
+/// written in place, and what's left over becomes the new PADDING block. Otherwise, the audio data
+/// has to be moved, and a PADDING block of [`WriteOptions::preferred_padding`] is added.
 pub(crate) fn write_to_inner<'a, F, II, IP>(
 	file: &mut F,
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
+	application_blocks: &[(u32, Vec<u8>)],
+	replacement: BlockReplacement,
 	write_options: WriteOptions,
 ) -> Result<()>
 where
@@ -55,40 +87,20 @@ where
 	IP: Iterator<Item = (&'a Picture, PictureInformation)>,
 {
 	let stream_info = verify_flac(file)?;
+	let metadata_start = stream_info.end;
 
 	let mut last_block = stream_info.last;
 
-	let mut file_bytes = Vec::new();
-	file.read_to_end(&mut file_bytes)?;
-
-	let mut cursor = Cursor::new(file_bytes);
-
-	// TODO: We need to actually use padding (https://github.com/Serial-ATA/lofty-rs/issues/445)
-	let mut end_padding_exists = false;
-	let mut last_block_info = (
-		stream_info.byte,
-		stream_info.start as usize,
-		stream_info.end as usize,
-	);
-
-	let mut blocks_to_remove = Vec::new();
+	// The blocks that are kept as is, and the position to insert the new blocks at
+	let mut kept_blocks = Vec::new();
+	let mut insert_pos = None;
 
 	while !last_block {
-		let block = Block::read(&mut cursor, |block_ty| block_ty == BLOCK_ID_VORBIS_COMMENTS)?;
-		let start = block.start;
-		let end = block.end;
-
-		let block_type = block.ty;
+		let block = Block::read(file, |block_ty| block_ty != BLOCK_ID_PADDING)?;
 		last_block = block.last;
 
-		if last_block {
-			last_block_info = (block.byte, (end - start) as usize, end as usize)
-		}
-
-		match block_type {
-			BLOCK_ID_VORBIS_COMMENTS => {
-				blocks_to_remove.push((start, end));
-
+		let replaced = match block.ty {
+			BLOCK_ID_VORBIS_COMMENTS if replacement != BlockReplacement::None => {
 				// Retain the original vendor string
 				let reader = &mut &block.content[..];
 
@@ -97,77 +109,154 @@ where
 				reader.read_exact(&mut vendor)?;
 
 				// TODO: Error on strict?
-				let Ok(vendor_str) = String::from_utf8(vendor) else {
-					log::warn!("FLAC vendor string is not valid UTF-8, not re-using");
-					tag.vendor = Cow::Borrowed("");
-					continue;
-				};
-
-				tag.vendor = Cow::Owned(vendor_str);
-			},
-			BLOCK_ID_PICTURE => blocks_to_remove.push((start, end)),
-			BLOCK_ID_PADDING => {
-				if last_block {
-					end_padding_exists = true
-				} else {
-					blocks_to_remove.push((start, end))
+				match String::from_utf8(vendor) {
+					Ok(vendor_str) => tag.vendor = Cow::Owned(vendor_str),
+					Err(_) => {
+						log::warn!("FLAC vendor string is not valid UTF-8, not re-using");
+						tag.vendor = Cow::Borrowed("");
+					},
 				}
+
+				true
 			},
-			_ => {},
+			BLOCK_ID_PICTURE => replacement != BlockReplacement::None,
+			BLOCK_ID_APPLICATION => replacement == BlockReplacement::TagsAndApplicationBlocks,
+			// The padding is always recreated to fit the new blocks
+			BLOCK_ID_PADDING => continue,
+			_ => false,
+		};
+
+		if replaced {
+			insert_pos = insert_pos.or(Some(kept_blocks.len()));
+			continue;
 		}
+
+		kept_blocks.push(block);
 	}
 
-	let mut file_bytes = cursor.into_inner();
+	let metadata_end = file.stream_position()?;
+
+	let mut comment_blocks = Cursor::new(Vec::new());
+
+	create_comment_block(&mut comment_blocks, &tag.vendor, &mut tag.items)?;
+
+	let mut comment_blocks = comment_blocks.into_inner();
 
-	if !end_padding_exists {
-		if let Some(preferred_padding) = write_options.preferred_padding {
-			log::warn!("File is missing a PADDING block. Adding one");
+	create_picture_blocks(&mut comment_blocks, &mut tag.pictures, write_options)?;
 
-			let mut first_byte = 0_u8;
-			first_byte |= last_block_info.0 & 0x7F;
+	create_application_blocks(&mut comment_blocks, application_blocks)?;
 
-			file_bytes[last_block_info.1] = first_byte;
+	// The new blocks go in place of the first replaced block, or directly after STREAMINFO
+	let insert_pos = insert_pos.unwrap_or(0);
 
-			let block_size = core::cmp::min(preferred_padding, MAX_BLOCK_SIZE);
-			let mut padding_block = try_vec![0; BLOCK_HEADER_SIZE + block_size as usize];
+	let mut metadata = Vec::new();
+	for (idx, block) in kept_blocks.iter().enumerate() {
+		if idx == insert_pos {
+			metadata.append(&mut comment_blocks);
+		}
+
+		metadata.write_u8(block.ty)?;
+		metadata.write_all(&(block.content.len() as u32).to_be_bytes()[1..])?;
+		metadata.write_all(&block.content)?;
+	}
 
-			let mut padding_byte = 0;
-			padding_byte |= 0x80;
-			padding_byte |= 1 & 0x7F;
+	metadata.append(&mut comment_blocks);
 
-			padding_block[0] = padding_byte;
-			padding_block[1..4].copy_from_slice(&block_size.to_be_bytes()[1..]);
+	// Any space left over is filled with a PADDING block, which needs room for its header
+	let space = metadata_end - metadata_start;
+	let metadata_len = metadata.len() as u64;
+	let fits_in_place = metadata_len == space
+		|| (metadata_len + BLOCK_HEADER_SIZE as u64 <= space
+			&& space - metadata_len - BLOCK_HEADER_SIZE as u64 <= u64::from(MAX_BLOCK_SIZE));
 
-			file_bytes.splice(last_block_info.2..last_block_info.2, padding_block);
+	if fits_in_place {
+		if metadata_len < space {
+			let padding_size = (space - metadata_len) as u32 - BLOCK_HEADER_SIZE as u32;
+			create_padding_block(&mut metadata, padding_size)?;
 		}
+
+		log::debug!("Writing FLAC metadata in place");
+
+		set_last_block_flag(&mut metadata);
+		write_stream_info_flag(file, &stream_info, metadata.is_empty())?;
+
+		file.seek(SeekFrom::Start(metadata_start))?;
+		file.write_all(&metadata)?;
+
+		return Ok(());
 	}
 
-	let mut comment_blocks = Cursor::new(Vec::new());
+	if let Some(preferred_padding) = write_options.preferred_padding {
+		let padding_size = core::cmp::min(preferred_padding, MAX_BLOCK_SIZE);
+		create_padding_block(&mut metadata, padding_size)?;
+	}
 
-	create_comment_block(&mut comment_blocks, &tag.vendor, &mut tag.items)?;
+	set_last_block_flag(&mut metadata);
+	write_stream_info_flag(file, &stream_info, metadata.is_empty())?;
+
+	let mut audio = Vec::new();
+	file.seek(SeekFrom::Start(metadata_end))?;
+	file.read_to_end(&mut audio)?;
+
+	file.seek(SeekFrom::Start(metadata_start))?;
+	file.truncate(metadata_start)?;
+	file.write_all(&metadata)?;
+	file.write_all(&audio)?;
+
+	Ok(())
+}
+
+// The size of the blocks that would be written for `tag`, excluding the vendor string
+pub(crate) fn comment_blocks_size(tag: &Tag) -> Result<u64> {
+	let (_, mut items, mut pictures) = crate::ogg::tag::create_vorbis_comments_ref(tag);
+
+	let mut comment_blocks = Cursor::new(Vec::new());
+	create_comment_block(&mut comment_blocks, "", &mut items)?;
 
 	let mut comment_blocks = comment_blocks.into_inner();
+	create_picture_blocks(&mut comment_blocks, &mut pictures, WriteOptions::default())?;
 
-	create_picture_blocks(&mut comment_blocks, &mut tag.pictures)?;
+	Ok(comment_blocks.len() as u64)
+}
 
-	if blocks_to_remove.is_empty() {
-		file_bytes.splice(0..0, comment_blocks);
-	} else {
-		blocks_to_remove.sort_unstable();
-		blocks_to_remove.reverse();
+fn create_padding_block(writer: &mut Vec<u8>, size: u32) -> Result<()> {
+	writer.write_u8(BLOCK_ID_PADDING)?;
+	writer.write_all(&size.to_be_bytes()[1..])?;
+	writer.write_all(&try_vec![0; size as usize])?;
 
-		let first = blocks_to_remove.pop().unwrap(); // Infallible
+	log::trace!("Wrote a padding block, size: {}", 1 + 3 + size);
 
-		for (s, e) in &blocks_to_remove {
-			file_bytes.drain(*s as usize..*e as usize);
-		}
+	Ok(())
+}
 
-		file_bytes.splice(first.0 as usize..first.1 as usize, comment_blocks);
+// Marks the final block in `metadata` as the last metadata block
+fn set_last_block_flag(metadata: &mut [u8]) {
+	let mut pos = 0;
+	let mut last_block_pos = None;
+	while pos + BLOCK_HEADER_SIZE <= metadata.len() {
+		last_block_pos = Some(pos);
+
+		let size = u32::from_be_bytes([0, metadata[pos + 1], metadata[pos + 2], metadata[pos + 3]]);
+		pos += BLOCK_HEADER_SIZE + size as usize;
 	}
 
-	file.seek(SeekFrom::Start(stream_info.end))?;
-	file.truncate(stream_info.end)?;
-	file.write_all(&file_bytes)?;
+	if let Some(last_block_pos) = last_block_pos {
+		metadata[last_block_pos] |= 0x80;
+	}
+}
+
+// STREAMINFO is only the last metadata block if nothing follows it
+fn write_stream_info_flag<F>(file: &mut F, stream_info: &Block, last: bool) -> Result<()>
+where
+	F: Write + Seek,
+{
+	let mut byte = stream_info.byte & 0x7F;
+	if last {
+		byte |= 0x80;
+	}
+
+	file.seek(SeekFrom::Start(stream_info.start))?;
+	file.write_u8(byte)?;
 
 	Ok(())
 }
@@ -223,11 +312,16 @@ fn create_comment_block(
 fn create_picture_blocks(
 	writer: &mut Vec<u8>,
 	pictures: &mut dyn Iterator<Item = (&Picture, PictureInformation)>,
+	write_options: WriteOptions,
 ) -> Result<()> {
 	let mut byte = 0_u8;
 	byte |= 6 & 0x7F;
 
 	for (pic, info) in pictures {
+		if !write_options.is_picture_allowed(pic) {
+			continue;
+		}
+
 		writer.write_u8(byte)?;
 
 		let pic_bytes = pic.as_flac_bytes(info, false);
@@ -246,3 +340,29 @@ fn create_picture_blocks(
 
 	Ok(())
 }
+
+fn create_application_blocks(
+	writer: &mut Vec<u8>,
+	application_blocks: &[(u32, Vec<u8>)],
+) -> Result<()> {
+	let mut byte = 0_u8;
+	byte |= BLOCK_ID_APPLICATION & 0x7F;
+
+	for (id, data) in application_blocks {
+		// The application ID is included in the block size
+		let block_len = (data.len() + 4) as u64;
+		if block_len > u64::from(MAX_BLOCK_SIZE) {
+			err!(TooMuchData);
+		}
+
+		writer.write_u8(byte)?;
+		writer.write_all(&(block_len as u32).to_be_bytes()[1..])?;
+		writer.write_all(&id.to_be_bytes())?;
+		writer.write_all(data)?;
+
+		// size = block type + block length + data
+		log::trace!("Wrote an application block, size: {}", 1 + 3 + block_len);
+	}
+
+	Ok(())
+}
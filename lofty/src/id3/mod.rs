@@ -148,12 +148,12 @@ where
 		log::debug!("Found an ID3v2 tag, parsing");
 
 		if config.read {
-			let mut tag = try_vec![0; id3v2_header.size as usize];
+			let mut tag = try_vec![0; id3v2_header.content_size() as usize];
 			data.read_exact(&mut tag)?;
 
 			id3v2 = Some(tag)
 		} else {
-			data.seek(SeekFrom::Current(i64::from(id3v2_header.size)))?;
+			data.seek(SeekFrom::Current(i64::from(id3v2_header.content_size())))?;
 		}
 
 		if id3v2_header.flags.footer {
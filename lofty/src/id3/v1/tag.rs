@@ -4,6 +4,7 @@ use crate::id3::v1::constants::GENRES;
 use crate::tag::items::Timestamp;
 use crate::tag::{Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType};
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::text::parse_number;
 
 use std::borrow::Cow;
 use std::io::Write;
@@ -127,6 +128,23 @@ impl Id3v1Tag {
 	pub fn new() -> Self {
 		Self::default()
 	}
+
+	// Shortens the text fields to the lengths that will actually be written
+	pub(crate) fn truncate_fields(&mut self) {
+		use super::write::truncate_str;
+
+		for (field, size) in [
+			(&mut self.title, 30),
+			(&mut self.artist, 30),
+			(&mut self.album, 30),
+			(&mut self.comment, 28),
+		] {
+			if let Some(value) = field {
+				let len = truncate_str(value, size).len();
+				value.truncate(len);
+			}
+		}
+	}
 }
 
 impl Accessor for Id3v1Tag {
@@ -357,7 +375,7 @@ impl From<Tag> for Id3v1Tag {
 		let album = input.take_strings(ItemKey::AlbumTitle).next();
 		let year = input
 			.get_string(ItemKey::Year)
-			.and_then(|year| year.parse().ok())
+			.and_then(parse_number)
 			.or_else(|| input.date().map(|y| y.year));
 		let comment = input.take_strings(ItemKey::Comment).next();
 		Self {
@@ -415,7 +433,7 @@ impl<'a> Into<Id3v1TagRef<'a>> for &'a Tag {
 			album: self.get_string(ItemKey::AlbumTitle),
 			year: self
 				.get_string(ItemKey::Year)
-				.and_then(|year| year.parse().ok())
+				.and_then(parse_number)
 				.or_else(|| self.date().map(|date| date.year)),
 			comment: self.get_string(ItemKey::Comment),
 			track_number: self
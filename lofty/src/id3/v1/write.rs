@@ -49,17 +49,27 @@ where
 	Ok(())
 }
 
+// Shortens `value` to at most `size` bytes, without splitting a character
+pub(super) fn truncate_str(value: &str, size: usize) -> &str {
+	if value.len() <= size {
+		return value;
+	}
+
+	let mut end = size;
+	while !value.is_char_boundary(end) {
+		end -= 1;
+	}
+
+	&value[..end]
+}
+
 pub(super) fn encode(tag: &Id3v1TagRef<'_>) -> std::io::Result<Vec<u8>> {
 	fn resize_string(value: Option<&str>, size: usize) -> std::io::Result<Vec<u8>> {
 		let mut cursor = Cursor::new(vec![0; size]);
 		cursor.rewind()?;
 
 		if let Some(val) = value {
-			if val.len() > size {
-				cursor.write_all(val.split_at(size).0.as_bytes())?;
-			} else {
-				cursor.write_all(val.as_bytes())?;
-			}
+			cursor.write_all(truncate_str(val, size).as_bytes())?;
 		}
 
 		Ok(cursor.into_inner())
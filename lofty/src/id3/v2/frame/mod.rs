@@ -216,6 +216,65 @@ impl Frame<'_> {
 }
 
 impl Frame<'_> {
+	/// Used for `WriteOptions::id3v2_text_encoding`
+	///
+	/// Latin-1 is only used if all of the frame's text can be represented with it, otherwise
+	/// `fallback` is used. Frames without a text encoding are left untouched.
+	pub(super) fn set_preferred_encoding(
+		&mut self,
+		preferred: TextEncoding,
+		fallback: TextEncoding,
+	) {
+		let (encoding, text): (&mut TextEncoding, Vec<&str>) = match self {
+			Frame::Text(TextInformationFrame {
+				encoding, value, ..
+			}) => (encoding, vec![&**value]),
+			Frame::Comment(CommentFrame {
+				encoding,
+				description,
+				content,
+				..
+			})
+			| Frame::UnsynchronizedText(UnsynchronizedTextFrame {
+				encoding,
+				description,
+				content,
+				..
+			})
+			| Frame::UserText(ExtendedTextFrame {
+				encoding,
+				description,
+				content,
+				..
+			}) => (encoding, vec![&**description, &**content]),
+			// The URL itself is always Latin-1
+			Frame::UserUrl(ExtendedUrlFrame {
+				encoding,
+				description,
+				..
+			}) => (encoding, vec![&**description]),
+			Frame::KeyValue(KeyValueFrame {
+				encoding,
+				key_value_pairs,
+				..
+			}) => (
+				encoding,
+				key_value_pairs
+					.iter()
+					.flat_map(|(key, value)| [&**key, &**value])
+					.collect(),
+			),
+			_ => return,
+		};
+
+		if preferred == TextEncoding::Latin1 && !text.into_iter().all(TextEncoding::verify_latin1) {
+			*encoding = fallback;
+			return;
+		}
+
+		*encoding = preferred;
+	}
+
 	pub(super) fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
 		Ok(match self {
 			Frame::Comment(comment) => comment.as_bytes(is_id3v23)?,
@@ -85,7 +85,10 @@ pub(crate) struct Id3v2Header {
 	pub version: Id3v2Version,
 	pub flags: Id3v2TagFlags,
 	/// The size of the tag contents (**DOES NOT INCLUDE THE HEADER/FOOTER**)
+	///
+	/// This includes the extended header, if one exists.
 	pub size: u32,
+	/// The full size of the extended header, or 0 if there is none
 	pub extended_size: u32,
 }
 
@@ -141,33 +144,20 @@ impl Id3v2Header {
 			(version == Id3v2Version::V4 || version == Id3v2Version::V3) && flags & 0x40 == 0x40;
 
 		if extended_header {
-			extended_size = bytes.read_u32::<BigEndian>()?.unsynch();
-
-			if extended_size < 6 {
+			let bytes_read;
+			(extended_size, bytes_read) = if version == Id3v2Version::V3 {
+				parse_extended_header_v3(bytes, &mut flags_parsed)?
+			} else {
+				parse_extended_header_v4(bytes, &mut flags_parsed)?
+			};
+
+			if bytes_read > extended_size {
 				return Err(Id3v2Error::new(Id3v2ErrorKind::BadExtendedHeaderSize).into());
 			}
 
-			// Useless byte since there's only 1 byte for flags
-			let _num_flag_bytes = bytes.read_u8()?;
-
-			let extended_flags = bytes.read_u8()?;
-
-			// The only flags we care about here are the CRC and restrictions
-
-			if extended_flags & 0x20 == 0x20 {
-				flags_parsed.crc = true;
-
-				// We don't care about the existing CRC (5) or its length byte (1)
-				let mut crc = [0; 6];
-				bytes.read_exact(&mut crc)?;
-			}
-
-			if extended_flags & 0x10 == 0x10 {
-				// We don't care about the length byte, it is always 1
-				let _data_length = bytes.read_u8()?;
-
-				flags_parsed.restrictions = Some(TagRestrictions::from_byte(bytes.read_u8()?));
-			}
+			// Skip anything we don't understand, so the reader ends up at the first frame
+			let remaining = u64::from(extended_size - bytes_read);
+			std::io::copy(&mut bytes.by_ref().take(remaining), &mut std::io::sink())?;
 		}
 
 		if extended_size > 0 && extended_size >= size {
@@ -182,8 +172,96 @@ impl Id3v2Header {
 		})
 	}
 
+	/// The size of the frames and padding, following the extended header
+	///
+	/// After [`Id3v2Header::parse`], this is the number of bytes left in the tag (excluding the footer).
+	pub(crate) fn content_size(&self) -> u32 {
+		self.size - self.extended_size
+	}
+
 	/// The total size of the tag, including the header, footer, and extended header
 	pub(crate) fn full_tag_size(&self) -> u32 {
-		self.size + 10 + self.extended_size + if self.flags.footer { 10 } else { 0 }
+		// The extended header is already accounted for in the tag size
+		self.size + 10 + if self.flags.footer { 10 } else { 0 }
+	}
+}
+
+// Returns the full size of the extended header, and the number of bytes read
+fn parse_extended_header_v3<R>(bytes: &mut R, flags: &mut Id3v2TagFlags) -> Result<(u32, u32)>
+where
+	R: Read,
+{
+	// Structure of an ID3v2.3 extended header:
+	//
+	// Size (4) (Excluding itself, either 6 or 10)
+	// Flags (2)
+	// Padding size (4)
+	// CRC (4) (Optional)
+	let size = bytes.read_u32::<BigEndian>()?;
+	if size < 6 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadExtendedHeaderSize).into());
+	}
+
+	let extended_flags = bytes.read_u16::<BigEndian>()?;
+	let _padding_size = bytes.read_u32::<BigEndian>()?;
+
+	let mut bytes_read = 10;
+	if extended_flags & 0x8000 == 0x8000 {
+		flags.crc = true;
+
+		// We don't care about the existing CRC, it gets recalculated on write
+		let _crc = bytes.read_u32::<BigEndian>()?;
+		bytes_read += 4;
+	}
+
+	Ok((size + 4, bytes_read))
+}
+
+// Returns the full size of the extended header, and the number of bytes read
+fn parse_extended_header_v4<R>(bytes: &mut R, flags: &mut Id3v2TagFlags) -> Result<(u32, u32)>
+where
+	R: Read,
+{
+	// Structure of an ID3v2.4 extended header:
+	//
+	// Size (4) (Including itself)
+	// Number of flag bytes (1) (Always 1)
+	// Flags (1)
+	// Followed by the data for each set flag, in order
+	let size = bytes.read_u32::<BigEndian>()?.unsynch();
+	if size < 6 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadExtendedHeaderSize).into());
+	}
+
+	// Useless byte since there's only 1 byte for flags
+	let _num_flag_bytes = bytes.read_u8()?;
+
+	let extended_flags = bytes.read_u8()?;
+
+	let mut bytes_read = 6;
+
+	// "Tag is an update", this has no data, only the length byte
+	if extended_flags & 0x40 == 0x40 {
+		let _data_length = bytes.read_u8()?;
+		bytes_read += 1;
+	}
+
+	if extended_flags & 0x20 == 0x20 {
+		flags.crc = true;
+
+		// We don't care about the existing CRC (5) or its length byte (1), it gets recalculated on write
+		let mut crc = [0; 6];
+		bytes.read_exact(&mut crc)?;
+		bytes_read += 6;
 	}
+
+	if extended_flags & 0x10 == 0x10 {
+		// We don't care about the length byte, it is always 1
+		let _data_length = bytes.read_u8()?;
+
+		flags.restrictions = Some(TagRestrictions::from_byte(bytes.read_u8()?));
+		bytes_read += 2;
+	}
+
+	Ok((size, bytes_read))
 }
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::id3::v2::util::counter::{decode_counter, encode_counter};
 use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
 use crate::util::alloc::VecFallibleCapacity;
 use crate::util::text::{TextDecodeOptions, TextEncoding, decode_text, encode_text};
@@ -27,7 +28,7 @@ pub struct PopularimeterFrame<'a> {
 	pub rating: u8,
 	/// A play counter for the user. It is to be incremented each time the file is played.
 	///
-	/// This is a `u64` for simplicity. It may change if it becomes an issue.
+	/// This is a `u64` for simplicity. Larger counters will be read as [`u64::MAX`].
 	pub counter: u64,
 }
 
@@ -91,17 +92,7 @@ impl<'a> PopularimeterFrame<'a> {
 		let mut counter_content = Vec::new();
 		reader.read_to_end(&mut counter_content)?;
 
-		let counter;
-		let remaining_size = counter_content.len();
-		if remaining_size > 8 {
-			counter = u64::MAX;
-		} else {
-			let mut counter_bytes = [0; 8];
-			let counter_start_pos = 8 - remaining_size;
-
-			counter_bytes[counter_start_pos..].copy_from_slice(&counter_content);
-			counter = u64::from_be_bytes(counter_bytes);
-		}
+		let counter = decode_counter(&counter_content);
 
 		let header = FrameHeader::new(FRAME_ID, frame_flags);
 		Ok(Self {
@@ -124,18 +115,7 @@ impl<'a> PopularimeterFrame<'a> {
 		content.extend(encode_text(&self.email, TextEncoding::Latin1, true));
 		content.push(self.rating);
 
-		// When the counter reaches all one's, one byte is inserted in front of the counter
-		// thus making the counter eight bits bigger in the same away as the play counter ("PCNT")
-		//
-		// $xx xx xx xx (xx ...)
-		if let Ok(counter) = u32::try_from(self.counter) {
-			content.extend(counter.to_be_bytes())
-		} else {
-			let counter_bytes = self.counter.to_be_bytes();
-			let i = counter_bytes.iter().position(|b| *b != 0).unwrap_or(4);
-
-			content.extend(&counter_bytes[i..]);
-		}
+		content.extend(encode_counter(self.counter));
 
 		Ok(content)
 	}
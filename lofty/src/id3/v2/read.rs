@@ -24,7 +24,7 @@ where
 		header.version
 	);
 
-	let mut tag_bytes = bytes.take(u64::from(header.size - header.extended_size));
+	let mut tag_bytes = bytes.take(u64::from(header.content_size()));
 
 	let mut ret;
 	if header.flags.unsynchronisation {
@@ -9,18 +9,21 @@ use crate::error::LoftyError;
 use crate::id3::v1::GENRES;
 use crate::id3::v2::frame::MUSICBRAINZ_UFID_OWNER;
 use crate::id3::v2::items::{
-	AttachedPictureFrame, CommentFrame, ExtendedTextFrame, ExtendedUrlFrame, TextInformationFrame,
-	UniqueFileIdentifierFrame, UnsynchronizedTextFrame, UrlLinkFrame,
+	AttachedPictureFrame, BinaryFrame, CommentFrame, ExtendedTextFrame, ExtendedUrlFrame,
+	GeneralEncapsulatedObject, PopularimeterFrame, TextInformationFrame, UniqueFileIdentifierFrame,
+	UnsynchronizedTextFrame, UrlLinkFrame,
 };
+use crate::id3::v2::util::counter::{decode_counter, encode_counter};
 use crate::id3::v2::util::mappings::TIPL_MAPPINGS;
 use crate::id3::v2::util::pairs::{NUMBER_PAIR_SEPARATOR, format_number_pair};
 use crate::id3::v2::{FrameHeader, FrameId, KeyValueFrame, TimestampFrame};
 use crate::picture::{Picture, PictureType};
 use crate::tag::companion_tag::CompanionTag;
-use crate::tag::items::{Timestamp, UNKNOWN_LANGUAGE};
+use crate::tag::items::{Lang, Timestamp, UNKNOWN_LANGUAGE};
 use crate::tag::{Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType};
+use crate::util::flag_item;
 use crate::util::io::{FileLike, Length, Truncate};
-use crate::util::text::{TextDecodeOptions, TextEncoding, decode_text};
+use crate::util::text::{TextDecodeOptions, TextEncoding, decode_text, parse_number};
 use conversion::Id3v2TagRef;
 
 use std::borrow::Cow;
@@ -79,6 +82,7 @@ macro_rules! impl_accessor {
 /// ## Special Frames
 ///
 /// ID3v2 has `GEOB` and `SYLT` frames, which are not parsed by default, instead storing them as [`FrameType::Binary`].
+/// `GEOB` frames can be accessed directly with [`Id3v2Tag::encapsulated_objects`].
 /// They can easily be parsed with [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse)
 /// and [`SynchronizedText::parse`](crate::id3::v2::SynchronizedTextFrame::parse) respectively, and converted back to binary with
 /// [`GeneralEncapsulatedObject::as_bytes`](crate::id3::v2::GeneralEncapsulatedObject::as_bytes) and
@@ -90,8 +94,11 @@ macro_rules! impl_accessor {
 ///
 /// * TXXX/WXXX
 /// 	* These frames map to [`ItemKey`] by their description, rather than their frame ID (e.g. `TXXX:REPLAYGAIN_ALBUM_GAIN` maps to [`ItemKey::ReplayGainAlbumGain`]).
-///     * Anything without a mapping will be discarded.
+///     * Anything without a mapping will be retained in the tag's companion, and restored when converting
+///       back to an `Id3v2Tag` (see [`GlobalOptions::preserve_format_specific_items`](crate::config::GlobalOptions::preserve_format_specific_items)).
 /// * POPM - These frames will be stored as a raw [`ItemValue::Binary`] value under the [`ItemKey::Popularimeter`] key.
+/// * APIC - Every picture is kept, in the order it appears in the tag, along with its type and description.
+///   The text encoding of the description is not kept, see [`Id3v2Tag::pictures`].
 ///
 /// ### From `Tag`
 ///
@@ -289,6 +296,40 @@ impl Id3v2Tag {
 			})
 	}
 
+	/// Returns all user-defined text frames (`TXXX`) as `(description, content)` pairs
+	///
+	/// This includes frames that have no [`ItemKey`] mapping, which are otherwise only accessible
+	/// through [`Id3v2Tag::get_user_text`].
+	///
+	/// NOTE: If the tag is [`Id3v2Version::V4`], there could be multiple values separated by null characters (`'\0'`).
+	///       The caller is responsible for splitting these values as necessary.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let _ = tag.insert_user_text(String::from("FOO"), String::from("Foo value"));
+	/// let _ = tag.insert_user_text(String::from("BAR"), String::from("Bar value"));
+	///
+	/// let mut user_texts = tag.user_texts();
+	/// assert_eq!(user_texts.next(), Some(("FOO", "Foo value")));
+	/// assert_eq!(user_texts.next(), Some(("BAR", "Bar value")));
+	/// assert_eq!(user_texts.next(), None);
+	/// ```
+	pub fn user_texts(&self) -> impl Iterator<Item = (&str, &str)> + Clone {
+		self.frames.iter().filter_map(|frame| match frame {
+			Frame::UserText(ExtendedTextFrame {
+				description,
+				content,
+				..
+			}) => Some((&**description, &**content)),
+			_ => None,
+		})
+	}
+
 	/// Inserts a new user-defined text frame (`TXXX`)
 	///
 	/// NOTE: The encoding will be UTF-8
@@ -499,6 +540,42 @@ impl Id3v2Tag {
 		})
 	}
 
+	/// Returns all `APIC` frames, in the order they appear in the tag
+	///
+	/// Unlike [`Tag::pictures`], this keeps the text encoding of each picture's description.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	/// use lofty::picture::{MimeType, Picture, PictureType};
+	///
+	/// let front_cover = Picture::unchecked(vec![1])
+	/// 	.pic_type(PictureType::CoverFront)
+	/// 	.mime_type(MimeType::Png)
+	/// 	.build();
+	/// let back_cover = Picture::unchecked(vec![2])
+	/// 	.pic_type(PictureType::CoverBack)
+	/// 	.mime_type(MimeType::Png)
+	/// 	.build();
+	///
+	/// let mut tag = Id3v2Tag::new();
+	/// let _ = tag.insert_picture(front_cover);
+	/// let _ = tag.insert_picture(back_cover);
+	///
+	/// let picture_types = tag
+	/// 	.pictures()
+	/// 	.map(|frame| frame.picture.pic_type())
+	/// 	.collect::<Vec<_>>();
+	/// assert_eq!(picture_types, [PictureType::CoverFront, PictureType::CoverBack]);
+	/// ```
+	pub fn pictures(&self) -> impl Iterator<Item = &AttachedPictureFrame<'_>> + Clone {
+		self.frames.iter().filter_map(|f| match f {
+			Frame::Picture(val) => Some(val),
+			_ => None,
+		})
+	}
+
 	/// Returns all `USLT` frames
 	pub fn unsync_text(&self) -> impl Iterator<Item = &UnsynchronizedTextFrame<'_>> + Clone {
 		self.frames.iter().filter_map(|f| match f {
@@ -507,6 +584,65 @@ impl Id3v2Tag {
 		})
 	}
 
+	/// Returns the first `USLT` frame
+	///
+	/// Unlike [`Accessor`] methods, this keeps the language and content descriptor of the frame.
+	/// To get *all* lyrics frames, see [`Id3v2Tag::unsync_text`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	/// let _ = tag.set_lyrics(*b"deu", String::from("Verse"), String::from("Hallo Welt"));
+	///
+	/// let lyrics = tag.lyrics().unwrap();
+	/// assert_eq!(lyrics.language, *b"deu");
+	/// assert_eq!(lyrics.description, "Verse");
+	/// assert_eq!(lyrics.content, "Hallo Welt");
+	/// ```
+	pub fn lyrics(&self) -> Option<&UnsynchronizedTextFrame<'_>> {
+		self.unsync_text().next()
+	}
+
+	/// Inserts a new `USLT` frame
+	///
+	/// NOTE: The encoding will be UTF-8
+	///
+	/// This will replace any `USLT` frame with the same language **and** description, see [`Id3v2Tag::insert`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	/// use lofty::tag::TagExt;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let _ = tag.set_lyrics(*b"eng", String::new(), String::from("Foo"));
+	/// let _ = tag.set_lyrics(*b"deu", String::new(), String::from("Bar"));
+	/// assert_eq!(tag.len(), 2);
+	///
+	/// // Same language and description, the existing frame is replaced
+	/// let replaced = tag.set_lyrics(*b"eng", String::new(), String::from("Baz"));
+	/// assert!(replaced.is_some());
+	/// assert_eq!(tag.len(), 2);
+	/// ```
+	pub fn set_lyrics(
+		&mut self,
+		language: Lang,
+		description: String,
+		text: String,
+	) -> Option<Frame<'static>> {
+		self.insert(Frame::UnsynchronizedText(UnsynchronizedTextFrame::new(
+			TextEncoding::UTF8,
+			language,
+			description,
+			text,
+		)))
+	}
+
 	/// Returns all `COMM` frames with an empty content descriptor
 	pub fn comments(&self) -> impl Iterator<Item = &CommentFrame<'_>> {
 		self.frames.iter().filter_map(|frame| {
@@ -514,11 +650,166 @@ impl Id3v2Tag {
 		})
 	}
 
+	/// Returns the play counter stored in the `PCNT` frame
+	///
+	/// Counters too large for a `u64` will be returned as [`u64::MAX`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	/// assert_eq!(tag.play_count(), None);
+	///
+	/// tag.set_play_count(1_000);
+	/// assert_eq!(tag.play_count(), Some(1_000));
+	/// ```
+	pub fn play_count(&self) -> Option<u64> {
+		match self.get(&PLAY_COUNTER_ID) {
+			Some(Frame::Binary(BinaryFrame { data, .. })) => Some(decode_counter(data)),
+			_ => None,
+		}
+	}
+
+	/// Sets the play counter, replacing any existing `PCNT` frame
+	///
+	/// The counter will be written with as many bytes as it needs, with a minimum of 4.
+	pub fn set_play_count(&mut self, play_count: u64) {
+		self.insert(Frame::Binary(BinaryFrame::new(
+			PLAY_COUNTER_ID,
+			encode_counter(play_count),
+		)));
+	}
+
+	/// Returns all `POPM` frames
+	pub fn popularimeters(&self) -> impl Iterator<Item = &PopularimeterFrame<'_>> + Clone {
+		self.frames.iter().filter_map(|f| match f {
+			Frame::Popularimeter(val) => Some(val),
+			_ => None,
+		})
+	}
+
+	/// Returns the `POPM` frame for a user, identified by their email
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Frame, Id3v2Tag, PopularimeterFrame};
+	///
+	/// let mut tag = Id3v2Tag::new();
+	/// let _ = tag.insert(Frame::Popularimeter(PopularimeterFrame::new(
+	/// 	"foo@bar.com",
+	/// 	196,
+	/// 	u64::from(u32::MAX) + 1,
+	/// )));
+	///
+	/// let popularimeter = tag.popularimeter("foo@bar.com").unwrap();
+	/// assert_eq!(popularimeter.counter, u64::from(u32::MAX) + 1);
+	///
+	/// assert!(tag.popularimeter("baz@qux.com").is_none());
+	/// ```
+	pub fn popularimeter(&self, email: &str) -> Option<&PopularimeterFrame<'_>> {
+		self.popularimeters()
+			.find(|popularimeter| popularimeter.email == email)
+	}
+
+	/// Returns all `GEOB` frames
+	///
+	/// `GEOB` frames are stored as [`Frame::Binary`], and are parsed on demand. Any frames that fail
+	/// to parse are skipped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::TextEncoding;
+	/// use lofty::id3::v2::{GeneralEncapsulatedObject, Id3v2Tag};
+	///
+	/// let mut tag = Id3v2Tag::new();
+	/// let _ = tag.insert_encapsulated_object(GeneralEncapsulatedObject::new(
+	/// 	TextEncoding::Latin1,
+	/// 	Some(String::from("application/octet-stream")),
+	/// 	None,
+	/// 	Some(String::from("Serato Markers2")),
+	/// 	vec![1, 2, 3],
+	/// ));
+	///
+	/// let object = tag.encapsulated_objects().next().unwrap();
+	/// assert_eq!(object.descriptor.as_deref(), Some("Serato Markers2"));
+	/// assert_eq!(object.data, &[1, 2, 3]);
+	/// ```
+	pub fn encapsulated_objects(
+		&self,
+	) -> impl Iterator<Item = GeneralEncapsulatedObject<'static>> + '_ {
+		self.frames.iter().filter_map(|frame| match frame {
+			Frame::Binary(binary_frame) if *binary_frame.id() == GENERAL_ENCAPSULATED_OBJECT_ID => {
+				match GeneralEncapsulatedObject::parse(&binary_frame.data, binary_frame.flags()) {
+					Ok(object) => Some(object),
+					Err(e) => {
+						log::warn!("Failed to parse GEOB frame: {e}");
+						None
+					},
+				}
+			},
+			_ => None,
+		})
+	}
+
+	/// Returns the `GEOB` frame with the given content descriptor
+	///
+	/// See [`Id3v2Tag::encapsulated_objects`].
+	pub fn encapsulated_object(
+		&self,
+		descriptor: &str,
+	) -> Option<GeneralEncapsulatedObject<'static>> {
+		self.encapsulated_objects()
+			.find(|object| object.descriptor.as_deref().unwrap_or("") == descriptor)
+	}
+
+	/// Inserts a `GEOB` frame
+	///
+	/// This will replace any `GEOB` frame with the same content descriptor, returning it.
+	pub fn insert_encapsulated_object(
+		&mut self,
+		object: GeneralEncapsulatedObject<'_>,
+	) -> Option<GeneralEncapsulatedObject<'static>> {
+		let descriptor = object.descriptor.as_deref().unwrap_or("");
+		let replaced = self.remove_encapsulated_object(descriptor);
+
+		let mut frame = BinaryFrame::new(GENERAL_ENCAPSULATED_OBJECT_ID, object.as_bytes());
+		frame.set_flags(object.flags());
+		self.frames.push(Frame::Binary(frame));
+
+		replaced
+	}
+
+	/// Removes the `GEOB` frame with the given content descriptor
+	///
+	/// This will return the removed frame, if it exists.
+	pub fn remove_encapsulated_object(
+		&mut self,
+		descriptor: &str,
+	) -> Option<GeneralEncapsulatedObject<'static>> {
+		let position = self.frames.iter().position(|frame| match frame {
+			Frame::Binary(binary_frame) if *binary_frame.id() == GENERAL_ENCAPSULATED_OBJECT_ID => {
+				GeneralEncapsulatedObject::parse(&binary_frame.data, binary_frame.flags())
+					.is_ok_and(|object| object.descriptor.as_deref().unwrap_or("") == descriptor)
+			},
+			_ => false,
+		})?;
+
+		let Frame::Binary(binary_frame) = self.frames.remove(position) else {
+			unreachable!("Frames validated before this point");
+		};
+
+		GeneralEncapsulatedObject::parse(&binary_frame.data, binary_frame.flags()).ok()
+	}
+
 	fn split_num_pair(&self, id: &FrameId<'_>) -> (Option<u32>, Option<u32>) {
 		if let Some(Frame::Text(TextInformationFrame { value, .. })) = self.get(id) {
 			let mut split = value
 				.split(&[V4_MULTI_VALUE_SEPARATOR, NUMBER_PAIR_SEPARATOR][..])
-				.flat_map(str::parse::<u32>);
+				.flat_map(parse_number::<u32>);
 			return (split.next(), split.next());
 		}
 
@@ -686,6 +977,9 @@ const GENRE_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TCON"));
 const TRACK_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TRCK"));
 const DISC_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TPOS"));
 const RECORDING_TIME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TDRC"));
+const PLAY_COUNTER_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("PCNT"));
+const PODCAST_FLAG_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("PCST"));
+const GENERAL_ENCAPSULATED_OBJECT_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("GEOB"));
 pub(super) const ATTACHED_PICTURE_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("APIC"));
 
 impl Accessor for Id3v2Tag {
@@ -929,39 +1223,30 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 		number_key: ItemKey,
 		total_key: ItemKey,
 	) -> Option<()> {
-		fn parse_number(source: &str) -> Option<&str> {
-			let number = source.trim();
-
-			if number.is_empty() {
-				return None;
+		fn parse(source: &str) -> Option<u32> {
+			let number = parse_number::<u32>(source);
+			if number.is_none() {
+				log::warn!("{source:?} could not be parsed as a number.");
 			}
 
-			if str::parse::<u32>(number).is_ok() {
-				Some(number)
-			} else {
-				log::warn!("{number:?} could not be parsed as a number.");
-
-				None
-			}
+			number
 		}
 
 		let mut split = content.splitn(2, &[V4_MULTI_VALUE_SEPARATOR, NUMBER_PAIR_SEPARATOR][..]);
 
-		let number = parse_number(split.next()?)?;
+		let number = parse(split.next()?)?;
 		let total = if let Some(total_source) = split.next() {
-			Some(parse_number(total_source)?)
+			Some(parse(total_source)?)
 		} else {
 			None
 		};
 		debug_assert!(split.next().is_none());
 
-		debug_assert!(!number.is_empty());
 		tag.items.push(TagItem::new(
 			number_key,
 			ItemValue::Text(number.to_string()),
 		));
 		if let Some(total) = total {
-			debug_assert!(!total.is_empty());
 			tag.items
 				.push(TagItem::new(total_key, ItemValue::Text(total.to_string())))
 		}
@@ -1193,6 +1478,23 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 			return FRAME_CONSUMED;
 		},
 
+		Frame::Binary(BinaryFrame {
+			header: FrameHeader { id, .. },
+			data,
+		}) if *id == PODCAST_FLAG_ID => {
+			match decode_podcast_flag(data) {
+				Some(true) => tag.items.push(TagItem::new(
+					ItemKey::FlagPodcast,
+					ItemValue::Text(String::from("1")),
+				)),
+				// A `false` podcast flag is represented by the absence of the frame
+				Some(false) => {},
+				None => return FRAME_RETAINED,
+			}
+
+			return FRAME_CONSUMED;
+		},
+
 		Frame::Binary(_)
 		| Frame::UserText(_)
 		| Frame::UserUrl(_) // Bare extended text/URL frames make no sense to support.
@@ -1207,6 +1509,23 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 	}
 }
 
+fn decode_podcast_flag(data: &[u8]) -> Option<bool> {
+	// iTunes writes 4 zero bytes, only the presence of the frame matters
+	if data.len() == 4 {
+		return Some(true);
+	}
+
+	// Older versions of Lofty wrote this as a text frame ("0" or "1")
+	let (&encoding, text) = data.split_first()?;
+	let text = decode_text(
+		&mut &*text,
+		TextDecodeOptions::new().encoding(TextEncoding::from_u8(encoding)?),
+	)
+	.ok()?;
+
+	flag_item(text.content.trim_end_matches('\0'))
+}
+
 impl SplitTag for Id3v2Tag {
 	type Remainder = SplitTagRemainder;
 
@@ -4,7 +4,7 @@ use crate::error::LoftyError;
 use crate::id3::v2::frame::MUSICBRAINZ_UFID_OWNER;
 use crate::id3::v2::util::pairs::new_number_pair_frame;
 use crate::id3::v2::{
-	AttachedPictureFrame, CommentFrame, Frame, FrameId, Id3v2TagFlags, KeyValueFrame,
+	AttachedPictureFrame, BinaryFrame, CommentFrame, Frame, FrameId, Id3v2TagFlags, KeyValueFrame,
 	UniqueFileIdentifierFrame, UnsynchronizedTextFrame, write,
 };
 use crate::io::{FileLike, Length, Truncate};
@@ -14,12 +14,13 @@ use crate::tag::{Tag, TagItem, TagType};
 
 use super::V4_MULTI_VALUE_SEPARATOR;
 use crate::id3::v2::tag::{
-	new_text_frame, new_timestamp_frame, new_url_frame, new_user_text_frame,
+	PODCAST_FLAG_ID, new_text_frame, new_timestamp_frame, new_url_frame, new_user_text_frame,
 };
 use crate::id3::v2::util::mappings::TIPL_MAPPINGS;
 use crate::mp4::AdvisoryRating;
 use crate::tag::items::{Lang, Timestamp};
 use crate::util::flag_item;
+use crate::util::musical::{is_valid_initial_key, parse_integer_bpm};
 
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
@@ -105,8 +106,7 @@ pub(crate) fn from_tag<'a>(
 		}
 
 		fn parse_number(item: &TagItem) -> Option<u32> {
-			let text = item.item_value.text()?;
-			text.parse().ok()
+			crate::util::text::parse_number(item.item_value.text()?)
 		}
 
 		let item_key = item.key();
@@ -139,6 +139,7 @@ pub(crate) fn from_tag<'a>(
 			| ItemKey::FileOwner
 			| ItemKey::CopyrightMessage
 			| ItemKey::Language
+			| ItemKey::PodcastKeywords
 			| ItemKey::Publisher => {
 				let (value, _) = take_item_text_and_description(item)?;
 
@@ -195,7 +196,7 @@ pub(crate) fn from_tag<'a>(
 			},
 
 			// Flag items
-			ItemKey::FlagCompilation | ItemKey::FlagPodcast => {
+			ItemKey::FlagCompilation => {
 				let text = item.item_value.text()?;
 				let flag_value = flag_item(text)?;
 
@@ -206,6 +207,19 @@ pub(crate) fn from_tag<'a>(
 					Cow::Owned(u8::from(flag_value).to_string()),
 				))
 			},
+			// iTunes only checks for the presence of a `PCST` frame, its content is always 4 zero bytes.
+			// As such, a `false` flag can only be represented by leaving the frame out.
+			ItemKey::FlagPodcast => {
+				let text = item.item_value.text()?;
+				if !flag_item(text)? {
+					return None;
+				}
+
+				Some(Frame::Binary(BinaryFrame::new(
+					PODCAST_FLAG_ID,
+					Cow::Borrowed(&[0; 4][..]),
+				)))
+			},
 
 			// iTunes advisory rating
 			ItemKey::ParentalAdvisory => {
@@ -283,6 +297,35 @@ pub(crate) fn from_tag<'a>(
 				)))
 			},
 
+			ItemKey::InitialKey
+				if item
+					.item_value
+					.text()
+					.is_some_and(|key| !is_valid_initial_key(key)) =>
+			{
+				let key = item.item_value.text()?;
+				log::warn!("Initial key is not a musical key: {key}, discarding");
+				None
+			},
+
+			// `TBPM` can only hold integers, so a decimal BPM is only used as a fallback
+			ItemKey::IntegerBpm | ItemKey::Bpm => {
+				let text = item.item_value.text()?;
+				let Some(bpm) = parse_integer_bpm(text) else {
+					log::warn!("BPM is not a positive number: {text}, discarding");
+					return None;
+				};
+
+				let space = match item_key {
+					ItemKey::IntegerBpm => &mut ctx.integer_bpm,
+					_ => &mut ctx.bpm,
+				};
+
+				// Only the first value is kept
+				space.get_or_insert(bpm);
+				None
+			},
+
 			// TIPL key-value mappings
 			_ if TIPL_MAPPINGS.iter().any(|(k, _)| *k == item_key) => {
 				let (_, tipl_key) = TIPL_MAPPINGS.iter().find(|(k, _)| *k == item_key)?;
@@ -302,7 +345,8 @@ pub(crate) fn from_tag<'a>(
 					return None;
 				};
 
-				if id.as_str().starts_with('T') {
+				// WFED (Podcast URL) is an Apple proprietary text frame, despite its ID
+				if id.as_str().starts_with('T') || id.as_str() == "WFED" {
 					let (value, _) = take_item_text_and_description(item)?;
 					return Some(new_text_frame(id, value));
 				}
@@ -338,6 +382,10 @@ pub(crate) fn from_tag<'a>(
 		track_total: Option<u32>,
 		disc_number: Option<u32>,
 		disc_total: Option<u32>,
+
+		// Both BPM items map to `TBPM`, which is written at the end
+		integer_bpm: Option<u16>,
+		bpm: Option<u16>,
 	}
 
 	#[derive(Hash, PartialEq, Eq)]
@@ -360,6 +408,8 @@ pub(crate) fn from_tag<'a>(
 		track_total: None,
 		disc_number: None,
 		disc_total: None,
+		integer_bpm: None,
+		bpm: None,
 	};
 
 	let mut frames = HashSet::with_capacity(items.len());
@@ -413,6 +463,13 @@ pub(crate) fn from_tag<'a>(
 		frames.insert(disc_frame);
 	}
 
+	if let Some(bpm) = ctx.integer_bpm.or(ctx.bpm) {
+		frames.insert(new_text_frame(
+			FrameId::Valid(Cow::Borrowed("TBPM")),
+			Cow::Owned(bpm.to_string()),
+		));
+	}
+
 	frames
 }
 
@@ -480,7 +537,7 @@ impl<'a, I: Iterator<Item = Frame<'a>> + 'a> Id3v2TagRef<'a, I> {
 		writer: &mut W,
 		write_options: WriteOptions,
 	) -> crate::error::Result<()> {
-		let temp = write::create_tag(self, write_options)?;
+		let temp = write::create_tag(self, write_options, None)?;
 		writer.write_all(&temp)?;
 
 		Ok(())
@@ -1104,9 +1104,65 @@ fn flag_item_conversion() {
 		tag.get_text(&FrameId::Valid(Cow::Borrowed("TCMP"))),
 		Some("1")
 	);
+	assert!(tag.get(&FrameId::Valid(Cow::Borrowed("PCST"))).is_none());
+
+	// A `false` podcast flag shouldn't read back as `true`
+	let tag = dump_and_re_read(&tag, WriteOptions::default());
+	let tag: Tag = tag.into();
+	assert_eq!(tag.get_string(ItemKey::FlagCompilation), Some("1"));
+	assert!(tag.get(ItemKey::FlagPodcast).is_none());
+}
+
+#[test_log::test]
+fn podcast_roundtrip() {
+	let mut tag = Tag::new(TagType::Id3v2);
+	tag.insert_text(ItemKey::FlagPodcast, String::from("1"));
+	tag.insert_text(
+		ItemKey::PodcastGlobalUniqueId,
+		String::from("urn:uuid:6d8e2c38-0c7e-4e0c-9d34-3a1b2c4d5e6f"),
+	);
+	tag.insert_text(
+		ItemKey::PodcastUrl,
+		String::from("https://example.com/feed.xml"),
+	);
+	tag.push(TagItem::new(
+		ItemKey::PodcastKeywords,
+		ItemValue::Text(String::from("foo")),
+	));
+	tag.push(TagItem::new(
+		ItemKey::PodcastKeywords,
+		ItemValue::Text(String::from("bar")),
+	));
+
+	let id3v2: Id3v2Tag = tag.into();
+
+	// Written the same way as iTunes
+	let Some(Frame::Binary(podcast_flag)) = id3v2.get(&FrameId::Valid(Cow::Borrowed("PCST")))
+	else {
+		panic!("Expected a binary PCST frame");
+	};
+	assert_eq!(&*podcast_flag.data, &[0; 4]);
+	assert_eq!(
+		id3v2.get_text(&FrameId::Valid(Cow::Borrowed("TKWD"))),
+		Some("foo\0bar")
+	);
+
+	let id3v2 = dump_and_re_read(&id3v2, WriteOptions::default());
+
+	let tag: Tag = id3v2.into();
+	assert_eq!(tag.get_string(ItemKey::FlagPodcast), Some("1"));
 	assert_eq!(
-		tag.get_text(&FrameId::Valid(Cow::Borrowed("PCST"))),
-		Some("0")
+		tag.get_string(ItemKey::PodcastGlobalUniqueId),
+		Some("urn:uuid:6d8e2c38-0c7e-4e0c-9d34-3a1b2c4d5e6f")
+	);
+	assert_eq!(
+		tag.get_string(ItemKey::PodcastUrl),
+		Some("https://example.com/feed.xml")
+	);
+	assert_eq!(
+		tag.get_strings(ItemKey::PodcastKeywords)
+			.collect::<Vec<_>>(),
+		["foo", "bar"]
 	);
 }
 
@@ -1569,3 +1625,354 @@ fn single_value_frame() {
 	let artist_tag = tag.get_text(&FrameId::new("TBPM").unwrap()).unwrap();
 	assert_eq!(artist_tag, "120");
 }
+
+#[test_log::test]
+fn lyrics_language_roundtrip() {
+	const GERMAN: Lang = *b"deu";
+
+	let mut tag = Id3v2Tag::new();
+	let _ = tag.set_lyrics(
+		GERMAN,
+		String::from("Some description"),
+		String::from("Foo lyrics"),
+	);
+
+	// Through a dump
+	let tag = dump_and_re_read(&tag, WriteOptions::default());
+	let lyrics = tag.lyrics().unwrap();
+	assert_eq!(lyrics.language, GERMAN);
+	assert_eq!(lyrics.description, "Some description");
+	assert_eq!(lyrics.content, "Foo lyrics");
+
+	// Through a `Tag` conversion
+	let tag: Tag = tag.into();
+	assert_eq!(tag.len(), 1);
+
+	let item = tag.get(ItemKey::Lyrics).unwrap();
+	assert_eq!(item.lang(), &GERMAN);
+
+	let tag: Id3v2Tag = tag.into();
+	let lyrics = tag.lyrics().unwrap();
+	assert_eq!(lyrics.language, GERMAN);
+	assert_eq!(lyrics.description, "Some description");
+	assert_eq!(lyrics.content, "Foo lyrics");
+}
+
+#[test_log::test]
+fn lyrics_default_language_from_tag() {
+	let mut tag = Tag::new(TagType::Id3v2);
+	tag.insert_text(ItemKey::Lyrics, String::from("Foo lyrics"));
+
+	let tag: Id3v2Tag = tag.into();
+	let lyrics = tag.lyrics().unwrap();
+	assert_eq!(lyrics.language, UNKNOWN_LANGUAGE);
+	assert_eq!(lyrics.description, EMPTY_CONTENT_DESCRIPTOR);
+	assert_eq!(lyrics.content, "Foo lyrics");
+}
+
+#[test_log::test]
+fn unmapped_txxx_roundtrip() {
+	let mut tag = Id3v2Tag::new();
+	let _ = tag.insert_user_text(String::from("MY_CUSTOM_FIELD"), String::from("Some value"));
+	let _ = tag.insert_user_text(
+		String::from("REPLAYGAIN_TRACK_GAIN"),
+		String::from("-6.5 dB"),
+	);
+
+	// The unmapped frame is held back, while the mapped frame makes it into the `Tag`
+	let generic: Tag = tag.into();
+	assert_eq!(generic.len(), 1);
+	assert_eq!(
+		generic.get_string(ItemKey::ReplayGainTrackGain),
+		Some("-6.5 dB")
+	);
+
+	// Writing the `Tag` directly
+	let mut tag_bytes = Vec::new();
+	generic
+		.dump_to(&mut tag_bytes, WriteOptions::default())
+		.unwrap();
+	let re_read = read_tag_with_options(
+		&tag_bytes,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	);
+	assert_eq!(re_read.get_user_text("MY_CUSTOM_FIELD"), Some("Some value"));
+
+	// Converting back to an `Id3v2Tag`
+	let tag: Id3v2Tag = generic.into();
+	let mut user_texts = tag.user_texts().collect::<Vec<_>>();
+	user_texts.sort_unstable();
+	assert_eq!(
+		user_texts,
+		vec![
+			("MY_CUSTOM_FIELD", "Some value"),
+			("REPLAYGAIN_TRACK_GAIN", "-6.5 dB"),
+		]
+	);
+}
+
+#[test_log::test]
+fn multiple_pictures_roundtrip() {
+	let pictures = [
+		(TextEncoding::Latin1, PictureType::CoverFront, "Front"),
+		(TextEncoding::UTF16, PictureType::CoverBack, "Bäck"),
+		(TextEncoding::UTF8, PictureType::Artist, "Ärtist"),
+	];
+
+	let mut tag = Id3v2Tag::default();
+	for (idx, (encoding, pic_type, description)) in pictures.into_iter().enumerate() {
+		let picture = Picture::unchecked(vec![idx as u8; 10])
+			.pic_type(pic_type)
+			.mime_type(MimeType::Png)
+			.description(description)
+			.build();
+		tag.insert(Frame::Picture(AttachedPictureFrame::new(encoding, picture)));
+	}
+
+	let re_read = dump_and_re_read(&tag, WriteOptions::default());
+
+	let re_read_pictures = re_read
+		.pictures()
+		.map(|frame| {
+			(
+				frame.encoding,
+				frame.picture.pic_type(),
+				frame.picture.description().unwrap(),
+			)
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(re_read_pictures, pictures);
+
+	// The generic tag keeps every picture in order, along with its description
+	let generic: Tag = re_read.into();
+	let generic_pictures = generic
+		.pictures()
+		.iter()
+		.map(|picture| (picture.pic_type(), picture.description().unwrap()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		generic_pictures,
+		pictures.map(|(_, pic_type, description)| (pic_type, description))
+	);
+
+	let id3v2: Id3v2Tag = generic.into();
+	assert_eq!(id3v2.pictures().count(), 3);
+	assert!(
+		id3v2
+			.pictures()
+			.zip(tag.pictures())
+			.all(|(a, b)| a.picture == b.picture)
+	);
+}
+
+#[test_log::test]
+fn play_counters_over_u32() {
+	let play_count = u64::from(u32::MAX) + 42;
+
+	let mut tag = Id3v2Tag::default();
+	tag.set_play_count(play_count);
+	tag.insert(Frame::Popularimeter(PopularimeterFrame::new(
+		"foo@bar.com",
+		128,
+		play_count * 2,
+	)));
+	tag.insert(Frame::Popularimeter(PopularimeterFrame::new(
+		"baz@qux.com",
+		255,
+		1,
+	)));
+
+	// 5 bytes, with no padding to 8
+	let Some(Frame::Binary(pcnt)) = tag.get(&FrameId::Valid(Cow::Borrowed("PCNT"))) else {
+		panic!("Expected a PCNT frame");
+	};
+	assert_eq!(&*pcnt.data, &[0x01, 0x00, 0x00, 0x00, 0x29]);
+
+	let re_read = dump_and_re_read(&tag, WriteOptions::default());
+	assert_eq!(re_read.play_count(), Some(play_count));
+	assert_eq!(
+		re_read.popularimeter("foo@bar.com").unwrap().counter,
+		play_count * 2
+	);
+	assert_eq!(re_read.popularimeter("baz@qux.com").unwrap().counter, 1);
+	assert_eq!(re_read.popularimeters().count(), 2);
+
+	// Only one PCNT frame is allowed
+	let mut tag = re_read;
+	tag.set_play_count(7);
+	assert_eq!(tag.play_count(), Some(7));
+	assert_eq!(tag.len(), 3);
+}
+
+#[test_log::test]
+fn preferred_text_encoding() {
+	fn text_encoding(tag: &Id3v2Tag, id: &'static str) -> TextEncoding {
+		match tag.get(&FrameId::Valid(Cow::Borrowed(id))) {
+			Some(Frame::Text(frame)) => frame.encoding,
+			Some(Frame::UserText(frame)) => frame.encoding,
+			_ => panic!("Expected a text frame for {id}"),
+		}
+	}
+
+	let mut tag = Id3v2Tag::default();
+	tag.set_title(String::from("Café"));
+	tag.set_artist(String::from("Łódź"));
+	tag.insert(Frame::UserText(ExtendedTextFrame::new(
+		TextEncoding::UTF16,
+		String::from("Fóo"),
+		String::from("Bär"),
+	)));
+
+	let write_options = WriteOptions::default().id3v2_text_encoding(Some(TextEncoding::Latin1));
+
+	// Only frames that can't be represented in Latin-1 are upgraded
+	let re_read = dump_and_re_read(&tag, write_options);
+	assert_eq!(text_encoding(&re_read, "TIT2"), TextEncoding::Latin1);
+	assert_eq!(text_encoding(&re_read, "TPE1"), TextEncoding::UTF8);
+	assert_eq!(text_encoding(&re_read, "TXXX"), TextEncoding::Latin1);
+	assert_eq!(re_read.title().as_deref(), Some("Café"));
+	assert_eq!(re_read.artist().as_deref(), Some("Łódź"));
+	assert_eq!(re_read.get_user_text("Fóo"), Some("Bär"));
+
+	// ID3v2.3 doesn't support UTF-8
+	let re_read = dump_and_re_read(&tag, write_options.use_id3v23(true));
+	assert_eq!(text_encoding(&re_read, "TIT2"), TextEncoding::Latin1);
+	assert_eq!(text_encoding(&re_read, "TPE1"), TextEncoding::UTF16);
+	assert_eq!(re_read.artist().as_deref(), Some("Łódź"));
+
+	// Without a preference, the frames keep their own encodings
+	let re_read = dump_and_re_read(&tag, WriteOptions::default());
+	assert_eq!(text_encoding(&re_read, "TIT2"), TextEncoding::UTF8);
+	assert_eq!(text_encoding(&re_read, "TXXX"), TextEncoding::UTF16);
+}
+
+#[test_log::test]
+fn text_encoding_roundtrip() {
+	for encoding in [
+		TextEncoding::Latin1,
+		TextEncoding::UTF16,
+		TextEncoding::UTF16BE,
+		TextEncoding::UTF8,
+	] {
+		let (description, content) = if encoding == TextEncoding::Latin1 {
+			("Fóo", "Bär")
+		} else {
+			("Fóo Ł", "Bär ź")
+		};
+
+		let mut tag = Id3v2Tag::default();
+		tag.insert(Frame::UserText(ExtendedTextFrame::new(
+			encoding,
+			String::from(description),
+			String::from(content),
+		)));
+		tag.insert(Frame::Comment(CommentFrame::new(
+			encoding,
+			*b"eng",
+			String::from(description),
+			String::from(content),
+		)));
+
+		// The description is null terminated, the content isn't
+		let re_read = dump_and_re_read(&tag, WriteOptions::default());
+		assert_eq!(re_read.get_user_text(description), Some(content));
+
+		let Some(Frame::Comment(comment)) =
+			re_read.get(&FrameId::Valid(Cow::Borrowed(COMMENT_FRAME_ID)))
+		else {
+			panic!("Expected a comment frame");
+		};
+		assert_eq!(comment.encoding, encoding);
+		assert_eq!(comment.description, description);
+		assert_eq!(comment.content, content);
+	}
+}
+
+#[test_log::test]
+fn id3v24_extended_header_and_footer() {
+	let mut frame = b"TIT2".to_vec();
+	frame.extend([0, 0, 0, 4, 0, 0]); // Size, flags
+	frame.extend(b"\0Foo");
+
+	let mut extended_header = vec![0, 0, 0, 15, 1, 0x70]; // Size, flag byte count, flags
+	extended_header.push(0); // "Tag is an update"
+	extended_header.extend([5, 0, 0, 0, 0, 0]); // CRC, recalculated on write
+	extended_header.extend([1, 0]); // Restrictions
+
+	let size = (extended_header.len() + frame.len()) as u8;
+
+	let mut tag_bytes = b"ID3\x04\x00\x50".to_vec();
+	tag_bytes.extend([0, 0, 0, size]);
+	tag_bytes.extend(&extended_header);
+	tag_bytes.extend(&frame);
+	tag_bytes.extend(b"3DI\x04\x00\x50");
+	tag_bytes.extend([0, 0, 0, size]);
+	tag_bytes.extend(b"audio");
+
+	let mut reader = Cursor::new(&tag_bytes[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert_eq!(header.extended_size, 15);
+	assert_eq!(header.full_tag_size() as usize, tag_bytes.len() - 5);
+	assert!(header.flags.crc);
+	assert!(header.flags.restrictions.is_some());
+	assert!(header.flags.footer);
+
+	let tag = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo"));
+
+	// Only the footer is left before the audio
+	assert_eq!(reader.position() as usize, tag_bytes.len() - 15);
+
+	// The CRC is regenerated, and the footer is kept
+	let mut re_written = Vec::new();
+	tag.dump_to(&mut re_written, WriteOptions::default())
+		.unwrap();
+
+	let mut reader = Cursor::new(&re_written[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert!(header.flags.crc);
+	assert!(header.flags.footer);
+	assert_eq!(header.full_tag_size() as usize, re_written.len());
+	assert_eq!(
+		&re_written[re_written.len() - 10..re_written.len() - 7],
+		b"3DI"
+	);
+}
+
+#[test_log::test]
+fn id3v23_extended_header() {
+	let mut frame = b"TIT2".to_vec();
+	frame.extend([0, 0, 0, 4, 0, 0]); // Size, flags
+	frame.extend(b"\0Foo");
+
+	let mut extended_header = vec![0, 0, 0, 10, 0x80, 0]; // Size (excluding itself), flags
+	extended_header.extend([0, 0, 0, 0]); // Padding size
+	extended_header.extend([0, 0, 0, 0]); // CRC
+
+	let size = (extended_header.len() + frame.len()) as u8;
+
+	let mut tag_bytes = b"ID3\x03\x00\x40".to_vec();
+	tag_bytes.extend([0, 0, 0, size]);
+	tag_bytes.extend(&extended_header);
+	tag_bytes.extend(&frame);
+
+	let mut reader = Cursor::new(&tag_bytes[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert_eq!(header.extended_size, 14);
+	assert_eq!(header.full_tag_size() as usize, tag_bytes.len());
+	assert!(header.flags.crc);
+
+	let tag = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo"));
+	assert_eq!(reader.position() as usize, tag_bytes.len());
+}
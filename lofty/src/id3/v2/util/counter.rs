@@ -0,0 +1,50 @@
+// Play counters ("PCNT", and the counter in "POPM") are variable-width big-endian integers
+//
+// They are at least 4 bytes, and when the counter reaches all one's, one byte is inserted in
+// front of the counter, making it eight bits bigger.
+//
+// $xx xx xx xx (xx ...)
+
+const MIN_COUNTER_SIZE: usize = 4;
+
+pub(crate) fn decode_counter(bytes: &[u8]) -> u64 {
+	// Anything larger can't be represented, just saturate
+	if bytes.len() > 8 {
+		return u64::MAX;
+	}
+
+	let mut counter_bytes = [0; 8];
+	counter_bytes[8 - bytes.len()..].copy_from_slice(bytes);
+	u64::from_be_bytes(counter_bytes)
+}
+
+pub(crate) fn encode_counter(counter: u64) -> Vec<u8> {
+	let counter_bytes = counter.to_be_bytes();
+	let start = counter_bytes
+		.iter()
+		.position(|b| *b != 0)
+		.unwrap_or(counter_bytes.len())
+		.min(counter_bytes.len() - MIN_COUNTER_SIZE);
+
+	counter_bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{decode_counter, encode_counter};
+
+	#[test_log::test]
+	fn counter_sizes() {
+		assert_eq!(encode_counter(0), [0, 0, 0, 0]);
+		assert_eq!(encode_counter(u64::from(u32::MAX)), [0xFF; 4]);
+		assert_eq!(encode_counter(u64::from(u32::MAX) + 1), [1, 0, 0, 0, 0]);
+		assert_eq!(encode_counter(u64::MAX), [0xFF; 8]);
+
+		for counter in [0, 1, u64::from(u32::MAX), u64::from(u32::MAX) + 1, u64::MAX] {
+			assert_eq!(decode_counter(&encode_counter(counter)), counter);
+		}
+
+		// Too large for a `u64`
+		assert_eq!(decode_counter(&[1; 9]), u64::MAX);
+	}
+}
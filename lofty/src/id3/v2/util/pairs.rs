@@ -2,6 +2,7 @@
 
 use crate::id3::v2::{Frame, FrameId};
 use crate::tag::{ItemKey, TagItem};
+use crate::util::text::trim_number_padding;
 
 use crate::id3::v2::tag::new_text_frame;
 use std::fmt::Display;
@@ -51,7 +52,7 @@ where
 pub(crate) fn set_number<F: FnMut(u32)>(item: &TagItem, mut setter: F) {
 	let text = item.value().text();
 
-	let trimmed_text = text.unwrap_or_default().trim();
+	let trimmed_text = trim_number_padding(text.unwrap_or_default());
 	if trimmed_text.is_empty() {
 		log::warn!("Value does not have text in {:?}", item.key());
 		return;
@@ -1,6 +1,7 @@
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::iff::chunk::Chunks;
+use crate::iff::wav::rf64::{Ds64, is_rf64};
 use crate::macros::err;
 use crate::util::io::{FileLike, Length, Truncate};
 
@@ -29,14 +30,22 @@ where
 	let mut chunks = Chunks::<B>::new(file_len);
 	chunks.next(file)?;
 
-	let mut actual_stream_size = chunks.size;
+	// RF64 files store their real sizes in a `ds64` chunk
+	let ds64 = if is_rf64(&chunks.fourcc) {
+		file.seek(SeekFrom::Start(12))?;
+		Some(Ds64::read(file)?)
+	} else {
+		None
+	};
+
+	let mut actual_stream_size = ds64.map_or(u64::from(chunks.size), |ds64| ds64.riff_size);
 
 	file.rewind()?;
 
 	let mut file_bytes = Cursor::new(Vec::with_capacity(actual_stream_size as usize));
 	file.read_to_end(file_bytes.get_mut())?;
 
-	if file_bytes.get_ref().len() < (actual_stream_size as usize + RIFF_CHUNK_HEADER_SIZE) {
+	if (file_bytes.get_ref().len() as u64) < (actual_stream_size + RIFF_CHUNK_HEADER_SIZE as u64) {
 		err!(SizeMismatch);
 	}
 
@@ -45,7 +54,10 @@ where
 
 	let (mut exising_id3_start, mut existing_id3_size) = (None, None);
 
-	let mut chunks = Chunks::<B>::new(u64::from(actual_stream_size));
+	let mut chunks = Chunks::<B>::new(actual_stream_size);
+	if let Some(ds64) = ds64 {
+		chunks.set_rf64_data_size(ds64.data_size);
+	}
 	while let Ok(true) = chunks.next(&mut file_bytes) {
 		if chunks.fourcc == CHUNK_NAME_UPPER || chunks.fourcc == CHUNK_NAME_LOWER {
 			exising_id3_start = Some(file_bytes.stream_position()? - 8);
@@ -70,7 +82,7 @@ where
 			.get_mut()
 			.drain(exising_id3_start as usize..existing_tag_end);
 
-		actual_stream_size -= existing_id3_size + RIFF_CHUNK_HEADER_SIZE as u32;
+		actual_stream_size -= u64::from(existing_id3_size) + RIFF_CHUNK_HEADER_SIZE as u64;
 	}
 
 	if !tag.is_empty() {
@@ -101,11 +113,19 @@ where
 			tag_bytes.get_ref().iter().copied(),
 		);
 
-		actual_stream_size += tag_size;
+		actual_stream_size += u64::from(tag_size);
 	}
 
-	file_bytes.seek(SeekFrom::Start(4))?;
-	file_bytes.write_u32::<B>(actual_stream_size)?;
+	if ds64.is_some() {
+		Ds64::write_riff_size(&mut file_bytes, actual_stream_size)?;
+	} else {
+		let Ok(actual_stream_size) = u32::try_from(actual_stream_size) else {
+			err!(TooMuchData);
+		};
+
+		file_bytes.seek(SeekFrom::Start(4))?;
+		file_bytes.write_u32::<B>(actual_stream_size)?;
+	}
 
 	file.rewind()?;
 	file.truncate(0)?;
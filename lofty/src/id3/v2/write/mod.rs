@@ -5,13 +5,14 @@ use super::{Frame, Id3v2TagFlags};
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::file::FileType;
-use crate::id3::v2::Id3v2Tag;
 use crate::id3::v2::tag::conversion::Id3v2TagRef;
 use crate::id3::v2::util::synchsafe::SynchsafeInteger;
-use crate::id3::{FindId3v2Config, find_id3v2};
+use crate::id3::v2::{AttachedPictureFrame, Id3v2Tag};
+use crate::id3::{FindId3v2Config, ID3FindResults, find_id3v2};
 use crate::macros::{err, try_vec};
 use crate::probe::Probe;
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::text::TextEncoding;
 
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use std::ops::Not;
@@ -70,15 +71,15 @@ where
 		err!(UnsupportedTag);
 	}
 
-	let id3v2 = create_tag(tag, write_options)?;
-
 	match file_type {
 		// Formats such as WAV and AIFF store the ID3v2 tag in an 'ID3 ' chunk rather than at the beginning of the file
 		FileType::Wav => {
+			let id3v2 = create_tag(tag, write_options, None)?;
 			tag.flags.footer = false;
 			return chunk_file::write_to_chunk_file::<F, LittleEndian>(file, &id3v2, write_options);
 		},
 		FileType::Aiff => {
+			let id3v2 = create_tag(tag, write_options, None)?;
 			tag.flags.footer = false;
 			return chunk_file::write_to_chunk_file::<F, BigEndian>(file, &id3v2, write_options);
 		},
@@ -87,7 +88,20 @@ where
 
 	// find_id3v2 will seek us to the end of the tag
 	// TODO: Search through junk
-	find_id3v2(file, FindId3v2Config::NO_READ_TAG)?;
+	let ID3FindResults(existing_header, _) = find_id3v2(file, FindId3v2Config::NO_READ_TAG)?;
+	let existing_size = existing_header.map(|header| header.full_tag_size());
+
+	let id3v2 = create_tag(tag, write_options, existing_size)?;
+
+	// The new tag was padded to the size of the existing one, so it can simply be written over it
+	if !id3v2.is_empty() && Some(id3v2.len() as u32) == existing_size {
+		log::debug!("Writing ID3v2 tag in place");
+
+		file.rewind()?;
+		file.write_all(&id3v2)?;
+
+		return Ok(());
+	}
 
 	let mut file_bytes = Vec::new();
 	file.read_to_end(&mut file_bytes)?;
@@ -101,28 +115,64 @@ where
 	Ok(())
 }
 
+/// Creates the tag, padded with [`WriteOptions::preferred_padding`]
+///
+/// If `existing_size` is given and the new tag fits in it, it is padded to that exact size instead,
+/// so that it can be written over the existing tag.
 pub(super) fn create_tag<'a, I: Iterator<Item = Frame<'a>> + 'a>(
 	tag: &mut Id3v2TagRef<'a, I>,
 	write_options: WriteOptions,
+	existing_size: Option<u32>,
 ) -> Result<Vec<u8>> {
+	let is_id3v23 = write_options.use_id3v23;
+
+	// The encoding to use if the preferred one can't represent a frame's text
+	let text_encoding = write_options.id3v2_text_encoding.map(|preferred| {
+		if is_id3v23 {
+			(preferred.to_id3v23(), TextEncoding::UTF16)
+		} else {
+			(preferred, TextEncoding::UTF8)
+		}
+	});
+
 	let frames = &mut tag.frames;
-	let mut peek = frames.peekable();
+	let mut peek = frames
+		.filter(|frame| match frame {
+			Frame::Picture(AttachedPictureFrame { picture, .. }) => {
+				write_options.is_picture_allowed(picture)
+			},
+			_ => true,
+		})
+		.map(|mut frame| {
+			if let Some((preferred, fallback)) = text_encoding {
+				frame.set_preferred_encoding(preferred, fallback);
+			}
+
+			frame
+		})
+		.peekable();
 
 	// We are stripping the tag
 	if peek.peek().is_none() {
 		return Ok(Vec::new());
 	}
 
-	let is_id3v23 = write_options.use_id3v23;
+	let mut flags = tag.flags;
 	if is_id3v23 {
 		log::debug!("Using ID3v2.3");
-	}
 
-	let has_footer = tag.flags.footer;
-	let needs_crc = tag.flags.crc;
-	let has_restrictions = tag.flags.restrictions.is_some();
+		if flags.footer {
+			log::warn!("Footers are not supported in ID3v2.3, discarding");
+			flags.footer = false;
+		}
+
+		if flags.restrictions.is_some() {
+			log::warn!("Tag restrictions are not supported in ID3v2.3, discarding");
+			flags.restrictions = None;
+		}
+	}
 
-	let (mut id3v2, extended_header_len) = create_tag_header(tag.flags, is_id3v23)?;
+	let (mut id3v2, extended_header_len) = create_tag_header(flags, is_id3v23)?;
 	let header_len = id3v2.get_ref().len();
 
 	// Write the items
@@ -132,46 +182,60 @@ pub(super) fn create_tag<'a, I: Iterator<Item = Frame<'a>> + 'a>(
 		frame::create_items(&mut id3v2, &mut peek)?;
 	}
 
-	let mut len = id3v2.get_ref().len() - header_len;
+	let frames_len = id3v2.get_ref().len() - header_len;
 
 	// https://mutagen-specs.readthedocs.io/en/latest/id3/id3v2.4.0-structure.html#padding:
 	//
 	// "[A tag] MUST NOT have any padding when a tag footer is added to the tag"
-	let padding_len = write_options.preferred_padding.unwrap_or(0) as usize;
-	if !has_footer {
-		len += padding_len;
+	let unpadded_len = id3v2.get_ref().len();
+	let padding_len = if flags.footer {
+		log::trace!("Footer requested, not padding tag");
+		0
+	} else {
+		match existing_size {
+			Some(existing_size) if unpadded_len <= existing_size as usize => {
+				existing_size as usize - unpadded_len
+			},
+			_ => write_options.preferred_padding.unwrap_or(0) as usize,
+		}
+	};
+
+	if padding_len > 0 {
+		log::trace!("Padding tag with {} bytes", padding_len);
+
+		id3v2.seek(SeekFrom::End(0))?;
+		id3v2.write_all(&try_vec![0; padding_len])?;
 	}
 
 	// Go back to the start and write the final size
 	id3v2.seek(SeekFrom::Start(6))?;
-	id3v2.write_u32::<BigEndian>((extended_header_len + len as u32).synch()?)?;
-
-	if needs_crc {
-		// The CRC is calculated on all the data between the header and footer
-		#[allow(unused_mut)]
-		// Past the CRC
-		let mut content_start_idx = 22;
-
-		if has_restrictions {
-			content_start_idx += 3;
+	id3v2.write_u32::<BigEndian>(
+		(extended_header_len + (frames_len + padding_len) as u32).synch()?,
+	)?;
+
+	if flags.crc {
+		if is_id3v23 {
+			// ID3v2.3 only covers the frames, and stores the padding size alongside the CRC
+			let crc = crc32(&id3v2.get_ref()[header_len..header_len + frames_len]);
+
+			// Normal ID3v2 header (10)
+			// Extended header size (4) and flags (2)
+			id3v2.seek(SeekFrom::Start(16))?;
+			id3v2.write_u32::<BigEndian>(padding_len as u32)?;
+			id3v2.write_u32::<BigEndian>(crc)?;
+		} else {
+			// The CRC is calculated on all the data between the header and footer
+			let encoded_crc = calculate_crc(&id3v2.get_ref()[header_len..]);
+
+			// Normal ID3v2 header (10)
+			// Extended header size (4), flag byte count (1), and flags (1)
+			id3v2.seek(SeekFrom::Start(16))?;
+			id3v2.write_u8(5)?;
+			id3v2.write_all(&encoded_crc)?;
 		}
-
-		// Skip 16 bytes
-		//
-		// Normal ID3v2 header (10)
-		// Extended header (6)
-		id3v2.seek(SeekFrom::Start(16))?;
-
-		let tag_contents = &id3v2.get_ref()[content_start_idx..];
-		let encoded_crc = calculate_crc(tag_contents);
-
-		id3v2.write_u8(5)?;
-		id3v2.write_all(&encoded_crc)?;
 	}
 
-	if has_footer {
-		log::trace!("Footer requested, not padding tag");
-
+	if flags.footer {
 		id3v2.seek(SeekFrom::Start(3))?;
 
 		let mut header_without_identifier = [0; 7];
@@ -181,20 +245,8 @@ pub(super) fn create_tag<'a, I: Iterator<Item = Frame<'a>> + 'a>(
 		// The footer is the same as the header, but with the identifier reversed
 		id3v2.write_all(b"3DI")?;
 		id3v2.write_all(&header_without_identifier)?;
-
-		return Ok(id3v2.into_inner());
-	}
-
-	if padding_len == 0 {
-		log::trace!("No padding requested, writing tag as-is");
-		return Ok(id3v2.into_inner());
 	}
 
-	log::trace!("Padding tag with {} bytes", padding_len);
-
-	id3v2.seek(SeekFrom::End(0))?;
-	id3v2.write_all(&try_vec![0; padding_len])?;
-
 	Ok(id3v2.into_inner())
 }
 
@@ -213,17 +265,35 @@ fn create_tag_header(flags: Id3v2TagFlags, is_id3v23: bool) -> Result<(Cursor<Ve
 
 	let extended_header = flags.crc || flags.restrictions.is_some();
 
-	let tag_flags = if is_id3v23 {
+	let mut tag_flags = if is_id3v23 {
 		flags.as_id3v23_byte()
 	} else {
 		flags.as_id3v24_byte()
 	};
 
+	if extended_header {
+		tag_flags |= 0x40;
+	}
+
 	header.write_u8(tag_flags)?;
 	header.write_u32::<BigEndian>(0)?;
 
 	let mut extended_header_size = 0;
-	if extended_header {
+	if extended_header && is_id3v23 {
+		// Structure of an ID3v2.3 extended header:
+		//
+		// Size (4) (Excluding itself, this will *always* be 10 since we only support the CRC)
+		// Flags (2)
+		// Padding size (4)
+		// CRC (4)
+		//
+		// The padding size and CRC are written once the tag is complete
+		header.write_u32::<BigEndian>(10)?;
+		header.write_u16::<BigEndian>(0x8000)?;
+		header.write_all(&[0; 8])?;
+
+		extended_header_size = 14;
+	} else if extended_header {
 		// Structure of extended header:
 		//
 		// Size (4)
@@ -266,13 +336,17 @@ fn create_tag_header(flags: Id3v2TagFlags, is_id3v23: bool) -> Result<(Cursor<Ve
 }
 
 // https://github.com/rstemmer/id3edit/blob/0246f3dc1a7a80a64461eeeb7b9ee88379003eb1/encoding/crc.c#L6:6
-fn calculate_crc(content: &[u8]) -> [u8; 5] {
-	let crc: u32 = content
+fn crc32(content: &[u8]) -> u32 {
+	content
 		.iter()
 		.fold(!0, |crc, octet| {
 			(crc >> 8) ^ crc_32_table()[(((crc & 0xFF) ^ u32::from(*octet)) & 0xFF) as usize]
 		})
-		.not();
+		.not()
+}
+
+fn calculate_crc(content: &[u8]) -> [u8; 5] {
+	let crc = crc32(content);
 
 	// The CRC-32 is stored as an 35 bit synchsafe integer, leaving the upper
 	// four bits always zeroed.
@@ -309,8 +383,12 @@ mod tests {
 		let mut writer = Vec::new();
 		tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
 
+		// The extended header flag
+		assert_eq!(writer[5] & 0x40, 0x40);
+
+		// The CRC covers the frames and the padding
 		let crc_content = &writer[16..22];
-		assert_eq!(crc_content, &[5, 0x06, 0x35, 0x69, 0x7D, 0x14]);
+		assert_eq!(crc_content, &[5, 0x09, 0x07, 0x78, 0x0E, 0x00]);
 
 		// Get rid of the size byte
 		let crc_content = &crc_content[1..];
@@ -323,6 +401,31 @@ mod tests {
 			unsynch_crc |= u32::from(b) << ((4 - i) * 7);
 		}
 
-		assert_eq!(unsynch_crc, 0x66BA_7E94);
+		assert_eq!(unsynch_crc, 0x90FE_0700);
+	}
+
+	#[test_log::test]
+	fn id3v23_write_crc32() {
+		let mut tag = Id3v2Tag::default();
+		tag.set_artist(String::from("Foo artist"));
+
+		let flags = Id3v2TagFlags {
+			crc: true,
+			..Id3v2TagFlags::default()
+		};
+		tag.set_flags(flags);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer, WriteOptions::default().use_id3v23(true))
+			.unwrap();
+
+		assert_eq!(writer[5] & 0x40, 0x40);
+
+		// Size (10), CRC flag, padding size (1024)
+		assert_eq!(&writer[10..20], &[0, 0, 0, 10, 0x80, 0, 0, 0, 4, 0]);
+
+		// Only the frames are covered by the CRC in ID3v2.3
+		let crc = u32::from_be_bytes(writer[20..24].try_into().unwrap());
+		assert_eq!(crc, super::crc32(&writer[24..writer.len() - 1024]));
 	}
 }
@@ -0,0 +1,154 @@
+// The `INST` and `MARK` chunks, used by samplers to store loop points and playback parameters.
+//
+// http://www-mmsp.ece.mcgill.ca/Documents/AudioFormats/AIFF/Docs/AIFF-1.3.pdf (page 15)
+
+use crate::error::Result;
+use crate::macros::{decode_err, try_vec};
+
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const INST_SIZE: usize = 20;
+
+/// An AIFF `INST` chunk
+///
+/// This describes how the audio should be mapped onto a sampler's keyboard, and how it should loop.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentChunk {
+	/// The MIDI note (0-127) that plays the audio at its original pitch
+	pub base_note: u8,
+	/// The pitch adjustment, in cents (-50 to 50)
+	pub detune: i8,
+	/// The lowest MIDI note (0-127) the audio should be played for
+	pub low_note: u8,
+	/// The highest MIDI note (0-127) the audio should be played for
+	pub high_note: u8,
+	/// The lowest MIDI velocity (1-127) the audio should be played for
+	pub low_velocity: u8,
+	/// The highest MIDI velocity (1-127) the audio should be played for
+	pub high_velocity: u8,
+	/// The volume adjustment, in decibels
+	pub gain: i16,
+	/// The loop played while the note is held
+	pub sustain_loop: InstrumentLoop,
+	/// The loop played once the note is released
+	pub release_loop: InstrumentLoop,
+}
+
+impl InstrumentChunk {
+	pub(crate) fn parse(content: &[u8]) -> Result<Self> {
+		if content.len() < INST_SIZE {
+			decode_err!(@BAIL Aiff, "Invalid \"INST\" chunk size");
+		}
+
+		let reader = &mut &content[..];
+
+		Ok(Self {
+			base_note: reader.read_u8()?,
+			detune: reader.read_i8()?,
+			low_note: reader.read_u8()?,
+			high_note: reader.read_u8()?,
+			low_velocity: reader.read_u8()?,
+			high_velocity: reader.read_u8()?,
+			gain: reader.read_i16::<BigEndian>()?,
+			sustain_loop: InstrumentLoop::parse(reader)?,
+			release_loop: InstrumentLoop::parse(reader)?,
+		})
+	}
+
+	// Loops only reference markers by ID, so their positions can only be filled in once the `MARK`
+	// chunk (which may appear anywhere in the file) has been read
+	pub(crate) fn resolve_loops(&mut self, markers: &[Marker]) {
+		self.sustain_loop.resolve(markers);
+		self.release_loop.resolve(markers);
+	}
+}
+
+/// A loop within an [`InstrumentChunk`]
+///
+/// Unlike WAV, the loop points are not stored directly. They refer to markers in the file's `MARK` chunk,
+/// which are resolved to sample frame positions when the file is read.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentLoop {
+	/// How the loop is played
+	///
+	/// * 0: No looping
+	/// * 1: Forward
+	/// * 2: Alternating (forward, then backward)
+	pub play_mode: u16,
+	/// The ID of the marker at the start of the loop
+	pub begin_marker_id: u16,
+	/// The ID of the marker at the end of the loop
+	pub end_marker_id: u16,
+	/// The sample frame position of the marker at the start of the loop, if it exists
+	pub begin_position: Option<u32>,
+	/// The sample frame position of the marker at the end of the loop, if it exists
+	pub end_position: Option<u32>,
+}
+
+impl InstrumentLoop {
+	fn parse(reader: &mut &[u8]) -> Result<Self> {
+		Ok(Self {
+			play_mode: reader.read_u16::<BigEndian>()?,
+			begin_marker_id: reader.read_u16::<BigEndian>()?,
+			end_marker_id: reader.read_u16::<BigEndian>()?,
+			begin_position: None,
+			end_position: None,
+		})
+	}
+
+	fn resolve(&mut self, markers: &[Marker]) {
+		let position = |id| {
+			markers
+				.iter()
+				.find(|marker| marker.id == id)
+				.map(|marker| marker.position)
+		};
+
+		self.begin_position = position(self.begin_marker_id);
+		self.end_position = position(self.end_marker_id);
+	}
+}
+
+/// A marker from an AIFF `MARK` chunk
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Marker {
+	/// The marker's ID, referenced by [`InstrumentLoop`]s
+	pub id: u16,
+	/// The sample frame the marker points to
+	pub position: u32,
+	/// The marker's name
+	pub name: String,
+}
+
+impl Marker {
+	pub(crate) fn parse_chunk(content: &[u8]) -> Result<Vec<Self>> {
+		let reader = &mut &content[..];
+
+		let marker_count = reader.read_u16::<BigEndian>()?;
+
+		let mut markers = Vec::new();
+		for _ in 0..marker_count {
+			let id = reader.read_u16::<BigEndian>()?;
+			let position = reader.read_u32::<BigEndian>()?;
+
+			let name_len = reader.read_u8()?;
+			let mut name = try_vec![0; name_len as usize];
+			reader.read_exact(&mut name)?;
+
+			// The count byte and text are padded to an even length
+			if name_len % 2 == 0 {
+				let _ = reader.read_u8()?;
+			}
+
+			markers.push(Self {
+				id,
+				position,
+				name: String::from_utf8_lossy(&name).into_owned(),
+			});
+		}
+
+		Ok(markers)
+	}
+}
@@ -1,5 +1,6 @@
 //! AIFF specific items
 
+mod instrument;
 mod properties;
 mod read;
 pub(crate) mod tag;
@@ -10,6 +11,7 @@ use lofty_attr::LoftyFile;
 
 // Exports
 
+pub use instrument::{InstrumentChunk, InstrumentLoop, Marker};
 pub use properties::{AiffCompressionType, AiffProperties};
 pub use tag::{AiffTextChunks, Comment};
 
@@ -24,6 +26,58 @@ pub struct AiffFile {
 	/// An ID3v2 tag
 	#[lofty(tag_type = "Id3v2")]
 	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	pub(crate) instrument_chunk: Option<InstrumentChunk>,
+	pub(crate) markers: Vec<Marker>,
 	/// The file's audio properties
 	pub(crate) properties: AiffProperties,
 }
+
+impl AiffFile {
+	/// Returns the file's `INST` chunk, holding the sampler playback parameters and loops
+	///
+	/// NOTE: This is only read if [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()) is enabled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::aiff::AiffFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/full_test.aiff")?;
+	/// let aiff_file = AiffFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// if let Some(instrument_chunk) = aiff_file.instrument_chunk() {
+	/// 	println!("Base note: {}", instrument_chunk.base_note);
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn instrument_chunk(&self) -> Option<&InstrumentChunk> {
+		self.instrument_chunk.as_ref()
+	}
+
+	/// Returns the markers from the file's `MARK` chunk
+	///
+	/// NOTE: These are only read if [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()) is enabled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::aiff::AiffFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/full_test.aiff")?;
+	/// let aiff_file = AiffFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// for marker in aiff_file.markers() {
+	/// 	println!("{} (#{}): {}", marker.name, marker.id, marker.position);
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn markers(&self) -> &[Marker] {
+		&self.markers
+	}
+}
@@ -111,6 +111,8 @@ impl From<AiffProperties> for FileProperties {
 			bit_depth: Some(value.sample_size as u8),
 			channels: Some(value.channels as u8),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -1,7 +1,8 @@
 use super::AiffFile;
+use super::instrument::{InstrumentChunk, Marker};
 use super::properties::AiffProperties;
 use super::tag::{AiffTextChunks, Comment};
-use crate::config::ParseOptions;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::iff::chunk::Chunks;
@@ -64,6 +65,8 @@ where
 	let mut comments = Vec::new();
 
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
+	let mut instrument_chunk: Option<InstrumentChunk> = None;
+	let mut markers = Vec::new();
 
 	let mut chunks = Chunks::<BigEndian>::new(file_len);
 
@@ -132,10 +135,34 @@ where
 			b"(c) " if text_chunks.copyright.is_none() && parse_options.read_tags => {
 				text_chunks.copyright = Some(chunks.read_pstring(data, None)?);
 			},
+			b"INST" if instrument_chunk.is_none() && parse_options.read_tags => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				match InstrumentChunk::parse(&content) {
+					Ok(chunk) => instrument_chunk = Some(chunk),
+					Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+					Err(_) => log::warn!("Ignoring invalid \"INST\" chunk"),
+				}
+			},
+			b"MARK" if markers.is_empty() && parse_options.read_tags => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				match Marker::parse_chunk(&content) {
+					Ok(chunk_markers) => markers = chunk_markers,
+					Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+					Err(_) => log::warn!("Ignoring invalid \"MARK\" chunk"),
+				}
+			},
 			_ => chunks.skip(data)?,
 		}
 	}
 
+	if let Some(instrument_chunk) = instrument_chunk.as_mut() {
+		instrument_chunk.resolve_loops(&markers);
+	}
+
 	if !annotations.is_empty() {
 		text_chunks.annotations = Some(annotations);
 	}
@@ -178,5 +205,7 @@ where
 			_ => Some(text_chunks),
 		},
 		id3v2_tag,
+		instrument_chunk,
+		markers,
 	})
 }
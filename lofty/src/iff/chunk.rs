@@ -18,6 +18,8 @@ where
 	pub fourcc: [u8; 4],
 	pub size: u32,
 	remaining_size: u64,
+	// The real size of an RF64 `data` chunk, which won't fit in `size`
+	rf64_data_size: Option<u64>,
 	_phantom: PhantomData<B>,
 }
 
@@ -28,10 +30,24 @@ impl<B: ByteOrder> Chunks<B> {
 			fourcc: [0; 4],
 			size: 0,
 			remaining_size: file_size,
+			rf64_data_size: None,
 			_phantom: PhantomData,
 		}
 	}
 
+	/// Use the size from an RF64 `ds64` chunk for the `data` chunk
+	pub fn set_rf64_data_size(&mut self, data_size: u64) {
+		self.rf64_data_size = Some(data_size);
+	}
+
+	/// The size of the current chunk, accounting for RF64 `data` chunks
+	pub fn actual_size(&self) -> u64 {
+		match self.rf64_data_size {
+			Some(data_size) if self.size == u32::MAX && &self.fourcc == b"data" => data_size,
+			_ => u64::from(self.size),
+		}
+	}
+
 	pub fn next<R>(&mut self, data: &mut R) -> Result<bool>
 	where
 		R: Read,
@@ -125,10 +141,12 @@ impl<B: ByteOrder> Chunks<B> {
 	where
 		R: Read + Seek,
 	{
-		data.seek(SeekFrom::Current(i64::from(self.size)))?;
+		let size = self.actual_size();
+
+		data.seek(SeekFrom::Current(size as i64))?;
 		self.correct_position(data)?;
 
-		self.remaining_size = self.remaining_size.saturating_sub(u64::from(self.size));
+		self.remaining_size = self.remaining_size.saturating_sub(size);
 
 		Ok(())
 	}
@@ -140,7 +158,7 @@ impl<B: ByteOrder> Chunks<B> {
 		// Chunks are expected to start on even boundaries, and are padded
 		// with a 0 if necessary. This is NOT the null terminator of the value,
 		// and it is NOT included in the chunk's size
-		if self.size % 2 != 0 {
+		if self.actual_size() % 2 != 0 {
 			data.seek(SeekFrom::Current(1))?;
 			self.remaining_size = self.remaining_size.saturating_sub(1);
 		}
@@ -2,6 +2,8 @@
 
 mod properties;
 pub(crate) mod read;
+pub(crate) mod rf64;
+mod sampler;
 pub(crate) mod tag;
 
 use crate::id3::v2::tag::Id3v2Tag;
@@ -10,9 +12,12 @@ use lofty_attr::LoftyFile;
 
 // Exports
 pub use crate::iff::wav::properties::{WavFormat, WavProperties};
+pub use sampler::{InstrumentChunk, SampleChunk, SampleLoop};
 pub use tag::RiffInfoList;
 
 /// A WAV file
+///
+/// This also covers RF64 and BW64 files, which extend WAV with 64-bit sizes.
 #[derive(LoftyFile)]
 #[lofty(read_fn = "read::read_from")]
 #[lofty(internal_write_module_do_not_use_anywhere_else)]
@@ -23,6 +28,43 @@ pub struct WavFile {
 	/// An ID3v2 tag
 	#[lofty(tag_type = "Id3v2")]
 	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	pub(crate) sample_chunk: Option<SampleChunk>,
+	pub(crate) instrument_chunk: Option<InstrumentChunk>,
 	/// The file's audio properties
 	pub(crate) properties: WavProperties,
 }
+
+impl WavFile {
+	/// Returns the file's `smpl` chunk, holding the sampler loop points
+	///
+	/// NOTE: This is only read if [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()) is enabled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::wav::WavFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/wav_format_pcm.wav")?;
+	/// let wav_file = WavFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// if let Some(sample_chunk) = wav_file.sample_chunk() {
+	/// 	for sample_loop in &sample_chunk.loops {
+	/// 		println!("Loop from {} to {}", sample_loop.start, sample_loop.end);
+	/// 	}
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn sample_chunk(&self) -> Option<&SampleChunk> {
+		self.sample_chunk.as_ref()
+	}
+
+	/// Returns the file's `inst` chunk, holding the sampler playback parameters
+	///
+	/// NOTE: This is only read if [`ParseOptions::read_tags`](crate::config::ParseOptions::read_tags()) is enabled.
+	pub fn instrument_chunk(&self) -> Option<&InstrumentChunk> {
+		self.instrument_chunk.as_ref()
+	}
+}
@@ -60,6 +60,8 @@ impl From<WavProperties> for FileProperties {
 			bit_depth: Some(bit_depth),
 			channels: Some(channels),
 			channel_mask,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -170,8 +172,8 @@ where
 
 pub(super) fn read_properties(
 	fmt: &mut &[u8],
-	mut total_samples: u32,
-	stream_len: u32,
+	mut total_samples: u64,
+	stream_len: u64,
 	file_length: u64,
 ) -> Result<WavProperties> {
 	if fmt.len() < 16 {
@@ -220,7 +222,7 @@ pub(super) fn read_properties(
 	}
 
 	if bits_per_sample > 0 && (total_samples == 0 || pcm) {
-		total_samples = stream_len / (u32::from(channels) * u32::from(bits_per_sample / 8));
+		total_samples = stream_len / (u64::from(channels) * u64::from(bits_per_sample / 8));
 	}
 
 	let mut duration = Duration::ZERO;
@@ -233,19 +235,19 @@ pub(super) fn read_properties(
 	if sample_rate > 0 && total_samples > 0 {
 		log::debug!("Calculating duration and bitrate from total samples");
 
-		let length = (u64::from(total_samples) * 1000).div_round(u64::from(sample_rate));
+		let length = (total_samples * 1000).div_round(u64::from(sample_rate));
 		duration = Duration::from_millis(length);
 		if length > 0 {
 			overall_bitrate = (file_length * 8).div_round(length) as u32;
 			if audio_bitrate == 0 {
 				log::warn!("Estimating audio bitrate from stream length");
-				audio_bitrate = (u64::from(stream_len) * 8).div_round(length) as u32;
+				audio_bitrate = (stream_len * 8).div_round(length) as u32;
 			}
 		}
 	} else if stream_len > 0 && bytes_per_second > 0 {
 		log::debug!("Calculating duration and bitrate from stream length/byte rate");
 
-		let length = (u64::from(stream_len) * 1000).div_round(u64::from(bytes_per_second));
+		let length = (stream_len * 1000).div_round(u64::from(bytes_per_second));
 		duration = Duration::from_millis(length);
 		if length > 0 {
 			overall_bitrate = (file_length * 8).div_round(length) as u32;
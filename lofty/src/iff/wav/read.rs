@@ -1,25 +1,55 @@
 use super::WavFile;
 use super::properties::WavProperties;
+use super::rf64::{Ds64, is_rf64};
+use super::sampler::{InstrumentChunk, SampleChunk};
 use super::tag::RiffInfoList;
-use crate::config::ParseOptions;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::iff::chunk::Chunks;
 use crate::macros::{decode_err, err};
 
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Read, Seek, SeekFrom, Write};
 
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+pub(crate) struct WavHeader {
+	// The size of the RIFF chunk, taken from the `ds64` chunk for RF64 files
+	pub(crate) riff_size: u64,
+	pub(crate) ds64: Option<Ds64>,
+}
+
+impl WavHeader {
+	pub(crate) fn write_riff_size<W>(&self, writer: &mut W, riff_size: u64) -> Result<()>
+	where
+		W: Write + Seek,
+	{
+		if self.ds64.is_some() {
+			return Ds64::write_riff_size(writer, riff_size);
+		}
+
+		let Ok(riff_size) = u32::try_from(riff_size) else {
+			err!(TooMuchData);
+		};
+
+		writer.seek(SeekFrom::Start(4))?;
+		writer.write_u32::<LittleEndian>(riff_size)?;
+		Ok(())
+	}
+}
 
 // Verifies that the stream is a WAV file and returns the stream length
-pub(crate) fn verify_wav<T>(data: &mut T) -> Result<u32>
+//
+// For RF64 files, this will also read the `ds64` chunk.
+pub(crate) fn verify_wav<T>(data: &mut T) -> Result<WavHeader>
 where
 	T: Read + Seek,
 {
 	let mut id = [0; 12];
 	data.read_exact(&mut id)?;
 
-	if &id[..4] != b"RIFF" {
+	let rf64 = is_rf64(&id[..4]);
+	if &id[..4] != b"RIFF" && !rf64 {
 		decode_err!(@BAIL Wav, "WAV file doesn't contain a RIFF chunk");
 	}
 
@@ -27,29 +57,50 @@ where
 		decode_err!(@BAIL Wav, "Found RIFF file, format is not WAVE");
 	}
 
+	if rf64 {
+		let ds64 = Ds64::read(data)?;
+
+		log::debug!("File verified to be RF64");
+		return Ok(WavHeader {
+			riff_size: ds64.riff_size,
+			ds64: Some(ds64),
+		});
+	}
+
 	log::debug!("File verified to be WAV");
-	Ok(u32::from_le_bytes(id[4..8].try_into().unwrap()))
+	Ok(WavHeader {
+		riff_size: u64::from(u32::from_le_bytes(id[4..8].try_into().unwrap())),
+		ds64: None,
+	})
 }
 
 pub(super) fn read_from<R>(data: &mut R, parse_options: ParseOptions) -> Result<WavFile>
 where
 	R: Read + Seek,
 {
-	verify_wav(data)?;
+	let header = verify_wav(data)?;
 
 	let current_pos = data.stream_position()?;
 	let file_len = data.seek(SeekFrom::End(0))?;
 
 	data.seek(SeekFrom::Start(current_pos))?;
 
-	let mut stream_len = 0_u32;
-	let mut total_samples = 0_u32;
+	let mut stream_len = 0_u64;
+	let mut total_samples = 0_u64;
 	let mut fmt = Vec::new();
 
 	let mut riff_info = RiffInfoList::default();
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
+	let mut sample_chunk = None;
+	let mut instrument_chunk = None;
 
 	let mut chunks = Chunks::<LittleEndian>::new(file_len);
+	if let Some(ds64) = header.ds64 {
+		chunks.set_rf64_data_size(ds64.data_size);
+
+		// The `fact` chunk can't hold the sample count either
+		total_samples = ds64.sample_count;
+	}
 
 	while let Ok(true) = chunks.next(data) {
 		match &chunks.fourcc {
@@ -62,14 +113,14 @@ where
 			},
 			b"fact" if parse_options.read_properties => {
 				if total_samples == 0 {
-					total_samples = data.read_u32::<LittleEndian>()?;
+					total_samples = u64::from(data.read_u32::<LittleEndian>()?);
 				} else {
 					data.seek(SeekFrom::Current(4))?;
 				}
 			},
 			b"data" if parse_options.read_properties => {
 				if stream_len == 0 {
-					stream_len += chunks.size
+					stream_len += chunks.actual_size()
 				}
 
 				chunks.skip(data)?;
@@ -122,6 +173,26 @@ where
 				}
 				id3v2_tag = Some(tag);
 			},
+			b"smpl" if parse_options.read_tags && sample_chunk.is_none() => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				match SampleChunk::parse(&content) {
+					Ok(chunk) => sample_chunk = Some(chunk),
+					Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+					Err(_) => log::warn!("Ignoring invalid \"smpl\" chunk"),
+				}
+			},
+			b"inst" if parse_options.read_tags && instrument_chunk.is_none() => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				match InstrumentChunk::parse(&content) {
+					Ok(chunk) => instrument_chunk = Some(chunk),
+					Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+					Err(_) => log::warn!("Ignoring invalid \"inst\" chunk"),
+				}
+			},
 			_ => chunks.skip(data)?,
 		}
 	}
@@ -138,5 +209,7 @@ where
 		properties,
 		riff_info_tag: (!riff_info.items.is_empty()).then_some(riff_info),
 		id3v2_tag,
+		sample_chunk,
+		instrument_chunk,
 	})
 }
@@ -0,0 +1,72 @@
+// RF64 and BW64 are WAV files with 64-bit sizes.
+//
+// The RIFF chunk and `data` chunk sizes are set to `0xFFFFFFFF`, and the real sizes are stored in a
+// `ds64` chunk, which must be the first chunk in the file.
+//
+// https://tech.ebu.ch/docs/tech/tech3306v1_1.pdf
+
+use crate::error::Result;
+use crate::macros::decode_err;
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+const DS64_MIN_SIZE: u32 = 28;
+
+// "RF64", size, "WAVE", "ds64", size
+const DS64_RIFF_SIZE_OFFSET: u64 = 20;
+
+pub(crate) fn is_rf64(fourcc: &[u8]) -> bool {
+	fourcc == b"RF64" || fourcc == b"BW64"
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Ds64 {
+	pub(crate) riff_size: u64,
+	pub(crate) data_size: u64,
+	pub(crate) sample_count: u64,
+}
+
+impl Ds64 {
+	// Expects the reader to be positioned directly after the "RF64....WAVE" header
+	pub(crate) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		let mut id = [0; 4];
+		reader.read_exact(&mut id)?;
+
+		if &id != b"ds64" {
+			decode_err!(@BAIL Wav, "RF64 file doesn't start with a \"ds64\" chunk");
+		}
+
+		let size = reader.read_u32::<LittleEndian>()?;
+		if size < DS64_MIN_SIZE {
+			decode_err!(@BAIL Wav, "Invalid \"ds64\" chunk size");
+		}
+
+		let riff_size = reader.read_u64::<LittleEndian>()?;
+		let data_size = reader.read_u64::<LittleEndian>()?;
+		let sample_count = reader.read_u64::<LittleEndian>()?;
+
+		// Skip the chunk size table, we only need the `data` chunk's size
+		let remaining = u64::from(size - 24) + u64::from(size % 2);
+		reader.seek(SeekFrom::Current(remaining as i64))?;
+
+		Ok(Self {
+			riff_size,
+			data_size,
+			sample_count,
+		})
+	}
+
+	pub(crate) fn write_riff_size<W>(writer: &mut W, riff_size: u64) -> Result<()>
+	where
+		W: Write + Seek,
+	{
+		writer.seek(SeekFrom::Start(DS64_RIFF_SIZE_OFFSET))?;
+		writer.write_u64::<LittleEndian>(riff_size)?;
+		Ok(())
+	}
+}
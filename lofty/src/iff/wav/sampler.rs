@@ -0,0 +1,152 @@
+// The `smpl` and `inst` chunks, used by samplers to store loop points and playback parameters.
+//
+// https://www.recordingblogs.com/wiki/sample-chunk-of-a-wave-file
+// https://www.recordingblogs.com/wiki/instrument-chunk-of-a-wave-file
+
+use crate::error::Result;
+use crate::macros::decode_err;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+const SMPL_MIN_SIZE: usize = 36;
+const SMPL_LOOP_SIZE: usize = 24;
+const INST_SIZE: usize = 7;
+
+/// A WAV `smpl` chunk
+///
+/// This describes how the audio should be played back by a sampler.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct SampleChunk {
+	/// The MIDI Manufacturers Association code of the intended sampler, or `0`
+	pub manufacturer: u32,
+	/// The product code of the intended sampler, or `0`
+	pub product: u32,
+	/// The duration of a single sample, in nanoseconds
+	pub sample_period: u32,
+	/// The MIDI note (0-127) that plays the audio at its original pitch
+	pub midi_unity_note: u32,
+	/// The fraction of a semitone above [`SampleChunk::midi_unity_note`], where `0x80000000` is half a semitone
+	pub midi_pitch_fraction: u32,
+	/// The SMPTE format (0, 24, 25, 29, or 30) of [`SampleChunk::smpte_offset`]
+	pub smpte_format: u32,
+	/// The SMPTE time of the first sample, packed as hours, minutes, seconds, and frames
+	pub smpte_offset: u32,
+	/// The loops within the audio
+	pub loops: Vec<SampleLoop>,
+	/// Sampler specific data, following the loops
+	pub sampler_data: Vec<u8>,
+}
+
+impl SampleChunk {
+	pub(crate) fn parse(content: &[u8]) -> Result<Self> {
+		if content.len() < SMPL_MIN_SIZE {
+			decode_err!(@BAIL Wav, "Invalid \"smpl\" chunk size");
+		}
+
+		let reader = &mut &content[..];
+
+		let manufacturer = reader.read_u32::<LittleEndian>()?;
+		let product = reader.read_u32::<LittleEndian>()?;
+		let sample_period = reader.read_u32::<LittleEndian>()?;
+		let midi_unity_note = reader.read_u32::<LittleEndian>()?;
+		let midi_pitch_fraction = reader.read_u32::<LittleEndian>()?;
+		let smpte_format = reader.read_u32::<LittleEndian>()?;
+		let smpte_offset = reader.read_u32::<LittleEndian>()?;
+		let loop_count = reader.read_u32::<LittleEndian>()? as usize;
+		let sampler_data_size = reader.read_u32::<LittleEndian>()? as usize;
+
+		if loop_count.saturating_mul(SMPL_LOOP_SIZE) > reader.len() {
+			decode_err!(@BAIL Wav, "\"smpl\" chunk has more loops than it can hold");
+		}
+
+		let mut loops = Vec::with_capacity(loop_count);
+		for _ in 0..loop_count {
+			loops.push(SampleLoop {
+				cue_point_id: reader.read_u32::<LittleEndian>()?,
+				loop_type: reader.read_u32::<LittleEndian>()?,
+				start: reader.read_u32::<LittleEndian>()?,
+				end: reader.read_u32::<LittleEndian>()?,
+				fraction: reader.read_u32::<LittleEndian>()?,
+				play_count: reader.read_u32::<LittleEndian>()?,
+			});
+		}
+
+		if sampler_data_size > reader.len() {
+			decode_err!(@BAIL Wav, "\"smpl\" chunk has an invalid sampler data size");
+		}
+
+		Ok(Self {
+			manufacturer,
+			product,
+			sample_period,
+			midi_unity_note,
+			midi_pitch_fraction,
+			smpte_format,
+			smpte_offset,
+			loops,
+			sampler_data: reader[..sampler_data_size].to_vec(),
+		})
+	}
+}
+
+/// A loop within a [`SampleChunk`]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SampleLoop {
+	/// An ID to associate the loop with a cue point
+	pub cue_point_id: u32,
+	/// How the loop is played
+	///
+	/// * 0: Forward
+	/// * 1: Alternating (forward, then backward)
+	/// * 2: Backward
+	///
+	/// Other values are sampler or manufacturer specific.
+	pub loop_type: u32,
+	/// The offset of the first sample in the loop, in samples
+	pub start: u32,
+	/// The offset of the last sample in the loop, in samples
+	pub end: u32,
+	/// A fraction of a sample at which to loop, where `0x80000000` is half a sample
+	pub fraction: u32,
+	/// The number of times to play the loop, where `0` means infinitely
+	pub play_count: u32,
+}
+
+/// A WAV `inst` chunk
+///
+/// This describes how the audio should be mapped onto a sampler's keyboard.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentChunk {
+	/// The MIDI note (0-127) that plays the audio at its original pitch
+	pub unshifted_note: u8,
+	/// The pitch adjustment, in cents (-50 to 50)
+	pub fine_tune: i8,
+	/// The volume adjustment, in decibels
+	pub gain: i8,
+	/// The lowest MIDI note (0-127) the audio should be played for
+	pub low_note: u8,
+	/// The highest MIDI note (0-127) the audio should be played for
+	pub high_note: u8,
+	/// The lowest MIDI velocity (1-127) the audio should be played for
+	pub low_velocity: u8,
+	/// The highest MIDI velocity (1-127) the audio should be played for
+	pub high_velocity: u8,
+}
+
+impl InstrumentChunk {
+	pub(crate) fn parse(content: &[u8]) -> Result<Self> {
+		if content.len() < INST_SIZE {
+			decode_err!(@BAIL Wav, "Invalid \"inst\" chunk size");
+		}
+
+		Ok(Self {
+			unshifted_note: content[0],
+			fine_tune: content[1] as i8,
+			gain: content[2] as i8,
+			low_note: content[3],
+			high_note: content[4],
+			low_velocity: content[5],
+			high_velocity: content[6],
+		})
+	}
+}
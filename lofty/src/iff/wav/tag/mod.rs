@@ -9,6 +9,7 @@ use crate::tag::{
 	try_parse_timestamp,
 };
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::text::parse_number;
 
 use std::borrow::Cow;
 use std::io::Write;
@@ -128,7 +129,7 @@ impl Accessor for RiffInfoList {
 
 	fn track(&self) -> Option<u32> {
 		if let Some(item) = self.get("IPRT") {
-			return item.parse::<u32>().ok();
+			return parse_number(item);
 		}
 
 		None
@@ -144,7 +145,7 @@ impl Accessor for RiffInfoList {
 
 	fn track_total(&self) -> Option<u32> {
 		if let Some(item) = self.get("IFRM") {
-			return item.parse::<u32>().ok();
+			return parse_number(item);
 		}
 
 		None
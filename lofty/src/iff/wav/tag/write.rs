@@ -8,7 +8,7 @@ use crate::util::io::{FileLike, Length, Truncate};
 
 use std::io::{Cursor, Read, Seek, SeekFrom};
 
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::LittleEndian;
 
 const RIFF_CHUNK_HEADER_SIZE: usize = 8;
 
@@ -23,7 +23,8 @@ where
 	LoftyError: From<<F as Length>::Error>,
 	I: Iterator<Item = (&'a str, &'a str)>,
 {
-	let mut stream_length = verify_wav(file)?;
+	let header = verify_wav(file)?;
+	let mut stream_length = header.riff_size;
 
 	let mut riff_info_bytes = Vec::new();
 	create_riff_info(&mut tag.items, &mut riff_info_bytes)?;
@@ -33,14 +34,18 @@ where
 	let mut file_bytes = Cursor::new(Vec::new());
 	file.read_to_end(file_bytes.get_mut())?;
 
-	if file_bytes.get_ref().len() < (stream_length as usize + RIFF_CHUNK_HEADER_SIZE) {
+	if (file_bytes.get_ref().len() as u64) < (stream_length + RIFF_CHUNK_HEADER_SIZE as u64) {
 		err!(SizeMismatch);
 	}
 
 	// The first chunk format is RIFF....WAVE
 	file_bytes.seek(SeekFrom::Start(12))?;
 
-	let Some(info_list_size) = find_info_list(&mut file_bytes, u64::from(stream_length - 4))?
+	let Some(info_list_size) = find_info_list(
+		&mut file_bytes,
+		stream_length.saturating_sub(4),
+		header.ds64.map(|ds64| ds64.data_size),
+	)?
 	else {
 		// Simply append the info list to the end of the file and update the file size
 		file_bytes.rewind()?;
@@ -53,10 +58,8 @@ where
 			.get_mut()
 			.splice(tag_position..tag_position, riff_info_bytes.iter().copied());
 
-		let len = (riff_info_bytes.len() + tag_position - 8) as u32;
-
-		file_bytes.seek(SeekFrom::Start(4))?;
-		file_bytes.write_u32::<LittleEndian>(len)?;
+		let len = (riff_info_bytes.len() + tag_position - 8) as u64;
+		header.write_riff_size(&mut file_bytes, len)?;
 
 		file.rewind()?;
 		file.truncate(0)?;
@@ -70,18 +73,16 @@ where
 	let info_list_start = file_bytes.seek(SeekFrom::Current(-12))? as usize;
 	let info_list_end = info_list_start + RIFF_CHUNK_HEADER_SIZE + info_list_size as usize;
 
-	stream_length -= info_list_end as u32 - info_list_start as u32;
+	stream_length -= (info_list_end - info_list_start) as u64;
 
-	let new_tag_len = riff_info_bytes.len() as u32;
+	let new_tag_len = riff_info_bytes.len() as u64;
 	let _ = file_bytes
 		.get_mut()
 		.splice(info_list_start..info_list_end, riff_info_bytes);
 
 	stream_length += new_tag_len;
 
-	let _ = file_bytes
-		.get_mut()
-		.splice(4..8, stream_length.to_le_bytes());
+	header.write_riff_size(&mut file_bytes, stream_length)?;
 
 	file.rewind()?;
 	file.truncate(0)?;
@@ -90,13 +91,20 @@ where
 	Ok(())
 }
 
-fn find_info_list<R>(data: &mut R, file_size: u64) -> Result<Option<u32>>
+fn find_info_list<R>(
+	data: &mut R,
+	file_size: u64,
+	rf64_data_size: Option<u64>,
+) -> Result<Option<u32>>
 where
 	R: Read + Seek,
 {
 	let mut info = None;
 
 	let mut chunks = Chunks::<LittleEndian>::new(file_size);
+	if let Some(data_size) = rf64_data_size {
+		chunks.set_rf64_data_size(data_size);
+	}
 
 	while let Ok(true) = chunks.next(data) {
 		if &chunks.fourcc == b"LIST" {
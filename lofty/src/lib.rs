@@ -128,7 +128,11 @@ pub mod musepack;
 pub mod ogg;
 pub mod wavpack;
 
-pub use crate::probe::{read_from, read_from_path};
+pub use crate::probe::{
+	read_cover_art, read_from, read_from_bytes, read_from_path, write_to_bytes,
+};
+
+pub use crate::properties::total_duration;
 
 pub use util::text::TextEncoding;
 
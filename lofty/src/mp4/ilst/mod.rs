@@ -19,6 +19,8 @@ use crate::tag::{
 };
 use crate::util::flag_item;
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::musical::{is_valid_initial_key, parse_integer_bpm};
+use crate::util::text::trim_number_padding;
 use advisory_rating::AdvisoryRating;
 use atom::{Atom, AtomData};
 use data_type::DataType;
@@ -36,6 +38,7 @@ const GENRE: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9gen");
 const COMMENT: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9cmt");
 const ADVISORY_RATING: AtomIdent<'_> = AtomIdent::Fourcc(*b"rtng");
 const COVR: AtomIdent<'_> = AtomIdent::Fourcc(*b"covr");
+const BPM: AtomIdent<'_> = AtomIdent::Fourcc(*b"tmpo");
 
 macro_rules! impl_accessor {
 	($($name:ident => $const:ident;)+) => {
@@ -695,6 +698,21 @@ impl SplitTag for Ilst {
 					};
 
 					match data {
+						// Podcast keywords are stored as a single comma-separated list
+						AtomData::UTF8(text) | AtomData::UTF16(text)
+							if key == ItemKey::PodcastKeywords =>
+						{
+							for keyword in text.split(',').map(str::trim) {
+								if !keyword.is_empty() {
+									tag.items.push(TagItem::new(
+										key,
+										ItemValue::Text(keyword.to_owned()),
+									));
+								}
+							}
+
+							return false; // Atom consumed
+						},
 						AtomData::UTF8(text) | AtomData::UTF16(text) => {
 							tag_item = TagItem::new(key, ItemValue::Text(std::mem::take(text)));
 						},
@@ -710,6 +728,22 @@ impl SplitTag for Ilst {
 						.push(std::mem::replace(picture, Picture::EMPTY));
 					return false; // Atom consumed
 				},
+				// BPM is stored as an integer, rather than text
+				bpm @ (AtomData::SignedInteger(_) | AtomData::UnsignedInteger(_))
+					if *ident == BPM =>
+				{
+					let bpm = match bpm {
+						AtomData::SignedInteger(int) => i64::from(*int),
+						AtomData::UnsignedInteger(uint) => i64::from(*uint),
+						_ => unreachable!(),
+					};
+
+					if bpm <= 0 {
+						return true; // Keep atom
+					}
+
+					tag_item = TagItem::new(ItemKey::IntegerBpm, ItemValue::Text(bpm.to_string()));
+				},
 				// We have to special case track/disc numbers since they are stored together
 				AtomData::Unknown {
 					code: DataType::Reserved,
@@ -770,7 +804,7 @@ impl MergeTag for SplitTagRemainder {
 
 	fn merge_tag(self, tag: Tag) -> Self::Merged {
 		fn convert_to_uint(space: &mut Option<u16>, cont: &str) {
-			if let Ok(num) = cont.parse::<u16>() {
+			if let Ok(num) = trim_number_padding(cont).parse::<u16>() {
 				*space = Some(num);
 			}
 		}
@@ -799,6 +833,12 @@ impl MergeTag for SplitTagRemainder {
 		let mut tracks: (Option<u16>, Option<u16>) = (None, None);
 		let mut discs: (Option<u16>, Option<u16>) = (None, None);
 
+		let mut podcast_keywords = Vec::new();
+
+		// A decimal BPM is only used for `tmpo` if there is no integer BPM
+		let mut integer_bpm: Option<u16> = None;
+		let mut bpm: Option<u16> = None;
+
 		for item in tag.items {
 			let key = item.item_key;
 
@@ -812,6 +852,18 @@ impl MergeTag for SplitTagRemainder {
 					ItemKey::TrackTotal => convert_to_uint(&mut tracks.1, text.as_str()),
 					ItemKey::DiscNumber => convert_to_uint(&mut discs.0, text.as_str()),
 					ItemKey::DiscTotal => convert_to_uint(&mut discs.1, text.as_str()),
+					ItemKey::PodcastKeywords => podcast_keywords.push(text),
+					ItemKey::IntegerBpm => {
+						let Some(value) = parse_integer_bpm(&text) else {
+							log::warn!("BPM is not a positive number: {text}, discarding");
+							continue;
+						};
+
+						integer_bpm.get_or_insert(value);
+					},
+					ItemKey::InitialKey if !is_valid_initial_key(&text) => {
+						log::warn!("Initial key is not a musical key: {text}, discarding");
+					},
 					ItemKey::FlagCompilation | ItemKey::FlagPodcast => {
 						let Some(data) = flag_item(text.as_str()) else {
 							continue;
@@ -845,10 +897,16 @@ impl MergeTag for SplitTagRemainder {
 							))),
 						})
 					},
-					_ => merged.atoms.push(Atom {
-						ident: ident.into_owned(),
-						data: AtomDataStorage::Single(AtomData::UTF8(text)),
-					}),
+					_ => {
+						if key == ItemKey::Bpm && bpm.is_none() {
+							bpm = parse_integer_bpm(&text);
+						}
+
+						merged.atoms.push(Atom {
+							ident: ident.into_owned(),
+							data: AtomDataStorage::Single(AtomData::UTF8(text)),
+						})
+					},
 				}
 			}
 		}
@@ -867,6 +925,20 @@ impl MergeTag for SplitTagRemainder {
 		create_int_pair(&mut merged, *b"trkn", tracks);
 		create_int_pair(&mut merged, *b"disk", discs);
 
+		if let Some(bpm) = integer_bpm.or(bpm) {
+			merged.replace_atom(Atom {
+				ident: BPM,
+				data: AtomDataStorage::Single(AtomData::SignedInteger(i32::from(bpm))),
+			})
+		}
+
+		if !podcast_keywords.is_empty() {
+			merged.atoms.push(Atom {
+				ident: AtomIdent::Fourcc(*b"keyw"),
+				data: AtomDataStorage::Single(AtomData::UTF8(podcast_keywords.join(","))),
+			})
+		}
+
 		merged
 	}
 }
@@ -1417,6 +1489,88 @@ mod tests {
 		);
 	}
 
+	#[test_log::test]
+	fn podcast_roundtrip() {
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.insert_text(ItemKey::FlagPodcast, "1".to_owned());
+		tag.insert_text(ItemKey::PodcastGlobalUniqueId, "episode-guid".to_owned());
+		tag.insert_text(ItemKey::PodcastUrl, "https://example.com/feed".to_owned());
+		tag.push(TagItem::new(
+			ItemKey::PodcastKeywords,
+			ItemValue::Text("foo".to_owned()),
+		));
+		tag.push(TagItem::new(
+			ItemKey::PodcastKeywords,
+			ItemValue::Text("bar".to_owned()),
+		));
+
+		let ilst: Ilst = tag.into();
+		verify_atom(&ilst, *b"pcst", &AtomData::Bool(true));
+		verify_atom(&ilst, *b"keyw", &AtomData::UTF8(String::from("foo,bar")));
+
+		let mut tag_bytes = Vec::new();
+		ilst.dump_to(&mut tag_bytes, WriteOptions::default())
+			.unwrap();
+
+		tag_bytes.drain(..8); // Remove the ilst identifier and size for `read_ilst`
+
+		let ilst_re_read = read_ilst_raw(
+			&tag_bytes[..],
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		);
+		assert_eq!(ilst, ilst_re_read);
+
+		let tag: Tag = ilst_re_read.into();
+		assert_eq!(tag.get_string(ItemKey::FlagPodcast), Some("1"));
+		assert_eq!(
+			tag.get_string(ItemKey::PodcastGlobalUniqueId),
+			Some("episode-guid")
+		);
+		assert_eq!(
+			tag.get_string(ItemKey::PodcastUrl),
+			Some("https://example.com/feed")
+		);
+		assert_eq!(
+			tag.get_strings(ItemKey::PodcastKeywords)
+				.collect::<Vec<_>>(),
+			["foo", "bar"]
+		);
+	}
+
+	#[test_log::test]
+	fn podcast_atoms_with_implicit_type() {
+		fn implicit_atom(fourcc: &[u8; 4], content: &[u8]) -> Vec<u8> {
+			let data_len = 16 + content.len() as u32;
+
+			let mut atom = Vec::new();
+			atom.extend((data_len + 8).to_be_bytes());
+			atom.extend(fourcc);
+			atom.extend(data_len.to_be_bytes());
+			atom.extend(b"data");
+			atom.extend([0; 8]); // Type indicator (implicit) and locale
+			atom.extend(content);
+			atom
+		}
+
+		let mut ilst_bytes = implicit_atom(b"purl", b"https://example.com/feed");
+		ilst_bytes.extend(implicit_atom(b"egid", b"episode-guid"));
+
+		let ilst = read_ilst_raw(
+			&ilst_bytes,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		);
+		verify_atom(
+			&ilst,
+			*b"purl",
+			&AtomData::UTF8(String::from("https://example.com/feed")),
+		);
+		verify_atom(
+			&ilst,
+			*b"egid",
+			&AtomData::UTF8(String::from("episode-guid")),
+		);
+	}
+
 	#[test_log::test]
 	fn special_items_roundtrip() {
 		let mut tag = Ilst::new();
@@ -129,6 +129,22 @@ where
 
 					continue;
 				},
+				// iTunes writes these with the implicit type (0), but they're always UTF-8 strings
+				b"purl" | b"egid" => {
+					if let Some(mut atom_data) =
+						parse_data_inner(&mut ilst_reader, parsing_mode, &atom)?
+					{
+						for (code, _) in &mut atom_data {
+							if *code == DataType::Reserved {
+								*code = DataType::Utf8;
+							}
+						}
+
+						push_atom_data(parsing_mode, &mut tag, atom, atom_data)?;
+					}
+
+					continue;
+				},
 				b"cpil" | b"hdvd" | b"pcst" | b"pgap" | b"shwm" => {
 					if let Some(atom_data) =
 						parse_data_inner(&mut ilst_reader, parsing_mode, &atom)?
@@ -180,6 +196,19 @@ fn parse_data<R>(
 where
 	R: Read + Seek,
 {
+	if let Some(atom_data) = parse_data_inner(reader, parsing_mode, &atom_info)? {
+		push_atom_data(parsing_mode, tag, atom_info, atom_data)?;
+	}
+
+	Ok(())
+}
+
+fn push_atom_data(
+	parsing_mode: ParsingMode,
+	tag: &mut Ilst,
+	atom_info: AtomInfo,
+	mut atom_data: Vec<(DataType, Vec<u8>)>,
+) -> Result<()> {
 	let handle_error = |err: LoftyError, parsing_mode: ParsingMode| -> Result<()> {
 		match parsing_mode {
 			ParsingMode::Strict => Err(err),
@@ -190,39 +219,37 @@ where
 		}
 	};
 
-	if let Some(mut atom_data) = parse_data_inner(reader, parsing_mode, &atom_info)? {
-		// Most atoms we encounter are only going to have 1 value, so store them as such
-		if atom_data.len() == 1 {
-			let (flags, content) = atom_data.remove(0);
-			let data = match interpret_atom_content(flags, content) {
-				Ok(data) => data,
-				Err(err) => return handle_error(err, parsing_mode),
-			};
-
-			tag.atoms.push(Atom {
-				ident: atom_info.ident,
-				data: AtomDataStorage::Single(data),
-			});
-
-			return Ok(());
-		}
-
-		let mut data = Vec::new();
-		for (flags, content) in atom_data {
-			let value = match interpret_atom_content(flags, content) {
-				Ok(data) => data,
-				Err(err) => return handle_error(err, parsing_mode),
-			};
-
-			data.push(value);
-		}
+	// Most atoms we encounter are only going to have 1 value, so store them as such
+	if atom_data.len() == 1 {
+		let (flags, content) = atom_data.remove(0);
+		let data = match interpret_atom_content(flags, content) {
+			Ok(data) => data,
+			Err(err) => return handle_error(err, parsing_mode),
+		};
 
 		tag.atoms.push(Atom {
 			ident: atom_info.ident,
-			data: AtomDataStorage::Multiple(data),
+			data: AtomDataStorage::Single(data),
 		});
+
+		return Ok(());
 	}
 
+	let mut data = Vec::new();
+	for (flags, content) in atom_data {
+		let value = match interpret_atom_content(flags, content) {
+			Ok(data) => data,
+			Err(err) => return handle_error(err, parsing_mode),
+		};
+
+		data.push(value);
+	}
+
+	tag.atoms.push(Atom {
+		ident: atom_info.ident,
+		data: AtomDataStorage::Multiple(data),
+	});
+
 	Ok(())
 }
 
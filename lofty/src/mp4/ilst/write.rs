@@ -71,7 +71,7 @@ where
 	let mut write_handle = atom_writer.start_write();
 	write_handle.seek(SeekFrom::Start(moov_data_start))?;
 
-	let ilst = build_ilst(&mut tag.atoms)?;
+	let ilst = build_ilst(&mut tag.atoms, write_options)?;
 	let remove_tag = ilst.is_empty();
 
 	let udta = find_child_atom(
@@ -593,7 +593,10 @@ fn create_meta(writer: &AtomWriter, ilst: &[u8]) -> Result<()> {
 	Ok(())
 }
 
-pub(super) fn build_ilst<'a, I>(atoms: &mut dyn Iterator<Item = AtomRef<'a, I>>) -> Result<Vec<u8>>
+pub(super) fn build_ilst<'a, I>(
+	atoms: &mut dyn Iterator<Item = AtomRef<'a, I>>,
+	write_options: WriteOptions,
+) -> Result<Vec<u8>>
 where
 	I: IntoIterator<Item = &'a AtomData> + 'a,
 {
@@ -611,7 +614,24 @@ where
 	let mut write_handle = ilst_writer.start_write();
 	write_handle.seek(SeekFrom::End(0))?;
 
+	let mut atoms_written = false;
 	for atom in peek {
+		let mut data = atom.data.into_iter().collect::<Vec<_>>();
+		let value_count = data.len();
+
+		data.retain(|data| match data {
+			AtomData::Picture(picture) => write_options.is_picture_allowed(picture),
+			_ => true,
+		});
+
+		// All of the values were dropped pictures, no need to write an empty atom
+		if data.is_empty() && value_count > 0 {
+			continue;
+		}
+
+		// `tmpo` is a 16-bit integer, unlike the other integer atoms
+		let is_bpm = matches!(atom.ident, AtomIdent::Fourcc(ref fourcc) if fourcc == b"tmpo");
+
 		let start = write_handle.stream_position()?;
 
 		// Empty size, we get it later
@@ -622,7 +642,7 @@ where
 			AtomIdent::Freeform { mean, name } => write_freeform(&mean, &name, &mut write_handle)?,
 		}
 
-		write_atom_data(atom.data, &mut write_handle)?;
+		write_atom_data(data, is_bpm, &mut write_handle)?;
 
 		let end = write_handle.stream_position()?;
 
@@ -633,6 +653,13 @@ where
 		write_handle.write_atom_size(start, size, false)?;
 
 		write_handle.seek(SeekFrom::Start(end))?;
+		atoms_written = true;
+	}
+
+	// Every atom was filtered out, this is no different from an empty tag
+	if !atoms_written {
+		log::debug!("No atoms left to write, `ilst` will be removed");
+		return Ok(Vec::new());
 	}
 
 	let size = write_handle.len();
@@ -670,7 +697,7 @@ where
 	Ok(())
 }
 
-fn write_atom_data<'a, I>(data: I, writer: &mut AtomWriterCompanion<'_>) -> Result<()>
+fn write_atom_data<'a, I>(data: I, is_bpm: bool, writer: &mut AtomWriterCompanion<'_>) -> Result<()>
 where
 	I: IntoIterator<Item = &'a AtomData> + 'a,
 {
@@ -679,6 +706,9 @@ where
 			AtomData::UTF8(text) => write_data(DataType::Utf8, text.as_bytes(), writer)?,
 			AtomData::UTF16(text) => write_data(DataType::Utf16, text.as_bytes(), writer)?,
 			AtomData::Picture(pic) => write_picture(pic, writer)?,
+			AtomData::SignedInteger(int) if is_bpm && i16::try_from(*int).is_ok() => {
+				write_int(DataType::BeSignedInteger, int.to_be_bytes(), 2, writer)?
+			},
 			AtomData::SignedInteger(int) => write_signed_int(*int, writer)?,
 			AtomData::UnsignedInteger(uint) => write_unsigned_int(*uint, writer)?,
 			AtomData::Bool(b) => write_bool(*b, writer)?,
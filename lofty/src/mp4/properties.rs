@@ -1,4 +1,6 @@
 use super::atom_info::{AtomIdent, AtomInfo};
+use super::ilst::Ilst;
+use super::ilst::atom::AtomData;
 use super::read::{AtomReader, find_child_atom, skip_atom};
 use crate::config::ParsingMode;
 use crate::error::{LoftyError, Result};
@@ -7,6 +9,7 @@ use crate::properties::FileProperties;
 use crate::util::alloc::VecFallibleCapacity;
 use crate::util::math::RoundedDivision;
 
+use std::borrow::Cow;
 use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::time::Duration;
 
@@ -144,6 +147,8 @@ pub struct Mp4Properties {
 	pub(crate) bit_depth: Option<u8>,
 	pub(crate) channels: u8,
 	pub(crate) drm_protected: bool,
+	pub(crate) encoder_delay: Option<u32>,
+	pub(crate) encoder_padding: Option<u32>,
 }
 
 impl From<Mp4Properties> for FileProperties {
@@ -156,6 +161,8 @@ impl From<Mp4Properties> for FileProperties {
 			bit_depth: input.bit_depth,
 			channels: Some(input.channels),
 			channel_mask: None,
+			encoder_delay: input.encoder_delay,
+			encoder_padding: input.encoder_padding,
 		}
 	}
 }
@@ -206,9 +213,55 @@ impl Mp4Properties {
 	}
 
 	/// Whether or not the file is DRM protected
+	///
+	/// This is determined by the presence of an encrypted sample entry (`drms`, `drmi`, or `enca`).
+	/// The audio properties of an encrypted stream are unavailable, but the tags can still be read.
+	///
+	/// With [`ParsingMode::Strict`], encountering an encrypted sample entry will instead
+	/// error with [`ErrorKind::EncryptedStream`](crate::error::ErrorKind::EncryptedStream).
 	pub fn is_drm_protected(&self) -> bool {
 		self.drm_protected
 	}
+
+	/// The encoder delay in samples, from the `iTunSMPB` atom
+	pub fn encoder_delay(&self) -> Option<u32> {
+		self.encoder_delay
+	}
+
+	/// The encoder padding in samples, from the `iTunSMPB` atom
+	pub fn encoder_padding(&self) -> Option<u32> {
+		self.encoder_padding
+	}
+}
+
+// The iTunes gapless info, stored as space separated hex numbers:
+//
+// " 00000000 00000840 000001CA 00000000003F31F6 ..."
+//
+// The second and third numbers are the encoder delay and padding
+pub(super) fn read_itunsmpb(ilst: &Ilst, properties: &mut Mp4Properties) {
+	let itunsmpb = AtomIdent::Freeform {
+		mean: Cow::Borrowed("com.apple.iTunes"),
+		name: Cow::Borrowed("iTunSMPB"),
+	};
+
+	let Some(AtomData::UTF8(value)) = ilst.get(&itunsmpb).and_then(|atom| atom.data().next())
+	else {
+		return;
+	};
+
+	let mut fields = value
+		.split_whitespace()
+		.skip(1)
+		.map(|field| u32::from_str_radix(field, 16));
+
+	match (fields.next(), fields.next()) {
+		(Some(Ok(delay)), Some(Ok(padding))) => {
+			properties.encoder_delay = Some(delay);
+			properties.encoder_padding = Some(padding);
+		},
+		_ => log::warn!("Invalid `iTunSMPB` value: {value:?}"),
+	}
 }
 
 struct AudioTrak {
@@ -421,7 +474,11 @@ impl Minf {
 	}
 }
 
-fn read_stsd<R>(reader: &mut AtomReader<R>, properties: &mut Mp4Properties) -> Result<()>
+fn read_stsd<R>(
+	reader: &mut AtomReader<R>,
+	properties: &mut Mp4Properties,
+	parse_mode: ParsingMode,
+) -> Result<()>
 where
 	R: Read + Seek,
 {
@@ -449,7 +506,18 @@ where
 			// TODO: wave (https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/QTFFChap3/qtff3.html#//apple_ref/doc/uid/TP40000939-CH205-134202)
 
 			// Special case to detect encrypted files
-			b"drms" => {
+			//
+			// * drms/drmi: Apple FairPlay protected audio/video
+			// * enca: Common Encryption protected audio
+			b"drms" | b"drmi" | b"enca" => {
+				if parse_mode == ParsingMode::Strict {
+					err!(EncryptedStream);
+				}
+
+				log::warn!(
+					"Found encrypted sample entry: {:?}",
+					fourcc.escape_ascii().to_string()
+				);
 				properties.drm_protected = true;
 				skip_atom(reader, atom.extended, atom.len)?;
 				continue;
@@ -511,7 +579,7 @@ where
 	// `stsd` contains the majority of the audio properties
 	let mut cursor = Cursor::new(&*stsd_data);
 	let mut stsd_reader = AtomReader::new(&mut cursor, parse_mode)?;
-	read_stsd(&mut stsd_reader, &mut properties)?;
+	read_stsd(&mut stsd_reader, &mut properties, parse_mode)?;
 
 	// We do the mdat check up here, so we have access to the entire file
 	if duration > 0 {
@@ -325,11 +325,44 @@ pub(super) enum VbrHeaderType {
 	Vbri,
 }
 
+// The LAME extension of the Xing header
+//
+// http://gabriel.mp3-tech.org/mp3infotag.html
+#[derive(Copy, Clone)]
+pub(super) struct LameHeader {
+	pub encoder_delay: u16,
+	pub encoder_padding: u16,
+}
+
+impl LameHeader {
+	// Encoder version (9), revision (1), lowpass (1), replay gain (8), flags (1), bitrate (1)
+	const DELAY_OFFSET: usize = 21;
+
+	fn read(reader: &mut &[u8]) -> Option<Self> {
+		if reader.len() < Self::DELAY_OFFSET + 3 {
+			return None;
+		}
+
+		// FFmpeg writes the same header, using its own encoder name
+		if !matches!(&reader[..4], b"LAME" | b"Lavc" | b"Lavf") {
+			return None;
+		}
+
+		let delay_padding = &reader[Self::DELAY_OFFSET..Self::DELAY_OFFSET + 3];
+		Some(Self {
+			encoder_delay: (u16::from(delay_padding[0]) << 4) | (u16::from(delay_padding[1]) >> 4),
+			encoder_padding: (u16::from(delay_padding[1] & 0x0F) << 8)
+				| u16::from(delay_padding[2]),
+		})
+	}
+}
+
 #[derive(Copy, Clone)]
 pub(super) struct VbrHeader {
 	pub ty: VbrHeaderType,
 	pub frames: u32,
 	pub size: u32,
+	pub lame: Option<LameHeader>,
 }
 
 impl VbrHeader {
@@ -364,7 +397,25 @@ impl VbrHeader {
 					_ => unreachable!(),
 				};
 
-				Ok(Some(Self { ty, frames, size }))
+				// Skip the optional TOC (100) and quality indicator (4)
+				let mut optional_fields_len = 0;
+				if flags[3] & 0x04 == 0x04 {
+					optional_fields_len += 100;
+				}
+				if flags[3] & 0x08 == 0x08 {
+					optional_fields_len += 4;
+				}
+
+				let lame = reader
+					.get(optional_fields_len..)
+					.and_then(|mut lame_header| LameHeader::read(&mut lame_header));
+
+				Ok(Some(Self {
+					ty,
+					frames,
+					size,
+					lame,
+				}))
 			},
 			b"VBRI" => {
 				if reader_len < 32 {
@@ -384,6 +435,7 @@ impl VbrHeader {
 					ty: VbrHeaderType::Vbri,
 					frames,
 					size,
+					lame: None,
 				}))
 			},
 			_ => Ok(None),
@@ -8,6 +8,7 @@ pub use header::{ChannelMode, Emphasis, Layer, MpegVersion};
 pub use properties::MpegProperties;
 
 use crate::ape::tag::ApeTag;
+use crate::file::tag_space::TagSpace;
 use crate::id3::v1::tag::Id3v1Tag;
 use crate::id3::v2::tag::Id3v2Tag;
 
@@ -29,4 +30,5 @@ pub struct MpegFile {
 	pub(crate) ape_tag: Option<ApeTag>,
 	/// The file's audio properties
 	pub(crate) properties: MpegProperties,
+	pub(crate) tag_space: Option<TagSpace>,
 }
@@ -23,6 +23,9 @@ pub struct MpegProperties {
 	pub(crate) copyright: bool,
 	pub(crate) original: bool,
 	pub(crate) emphasis: Option<Emphasis>,
+	pub(crate) vbr: bool,
+	pub(crate) encoder_delay: Option<u16>,
+	pub(crate) encoder_padding: Option<u16>,
 }
 
 impl From<MpegProperties> for FileProperties {
@@ -40,6 +43,9 @@ impl From<MpegProperties> for FileProperties {
 			emphasis: _,
 			mode_extension: _,
 			original: _,
+			vbr: _,
+			encoder_delay,
+			encoder_padding,
 		} = input;
 		let channel_mask = match channel_mode {
 			ChannelMode::SingleChannel => Some(ChannelMask::mono()),
@@ -54,6 +60,8 @@ impl From<MpegProperties> for FileProperties {
 			bit_depth: None,
 			channels: Some(channels),
 			channel_mask,
+			encoder_delay: encoder_delay.map(u32::from),
+			encoder_padding: encoder_padding.map(u32::from),
 		}
 	}
 }
@@ -118,6 +126,29 @@ impl MpegProperties {
 	pub fn emphasis(&self) -> Option<Emphasis> {
 		self.emphasis
 	}
+
+	/// Whether the stream is variable bitrate
+	///
+	/// This is determined by the presence of a Xing or VBRI header. Streams without one (or with a
+	/// LAME "Info" header) are assumed to be constant bitrate.
+	///
+	/// When the header is present, the duration is calculated from its frame count rather than
+	/// estimated from the bitrate of the first frame.
+	pub fn is_vbr(&self) -> bool {
+		self.vbr
+	}
+
+	/// The encoder delay in samples, from the LAME header
+	///
+	/// This does not include the decoder delay.
+	pub fn encoder_delay(&self) -> Option<u16> {
+		self.encoder_delay
+	}
+
+	/// The encoder padding in samples, from the LAME header
+	pub fn encoder_padding(&self) -> Option<u16> {
+		self.encoder_padding
+	}
 }
 
 pub(super) fn read_properties<R>(
@@ -141,6 +172,14 @@ where
 	properties.copyright = first_frame_header.copyright;
 	properties.original = first_frame_header.original;
 	properties.emphasis = first_frame_header.emphasis;
+	properties.vbr = matches!(
+		vbr_header.map(|h| h.ty),
+		Some(VbrHeaderType::Xing | VbrHeaderType::Vbri)
+	);
+	if let Some(lame) = vbr_header.and_then(|h| h.lame) {
+		properties.encoder_delay = Some(lame.encoder_delay);
+		properties.encoder_padding = Some(lame.encoder_padding);
+	}
 	properties.sample_rate = first_frame_header.sample_rate;
 	properties.channels = if first_frame_header.channel_mode == ChannelMode::SingleChannel {
 		1
@@ -3,12 +3,14 @@ use super::{MpegFile, MpegProperties};
 use crate::ape::header::read_ape_header;
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
+use crate::file::tag_space::TagSpace;
 use crate::id3::v2::header::Id3v2Header;
 use crate::id3::v2::read::parse_id3v2;
 use crate::id3::{FindId3v2Config, ID3FindResults, find_id3v1, find_lyrics3v2};
 use crate::io::SeekStreamLen;
 use crate::macros::{decode_err, err};
 use crate::mpeg::header::HEADER_MASK;
+use crate::tag::TagType;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -38,11 +40,19 @@ where
 			// We will check again after finding the frame sync, in case the tag is buried in junk.
 			[b'I', b'D', b'3', ..] => {
 				// Seek back to read the tag in full
-				reader.seek(SeekFrom::Current(-4))?;
+				let tag_start = reader.seek(SeekFrom::Current(-4))?;
 
 				let header = Id3v2Header::parse(reader)?;
 				let skip_footer = header.flags.footer;
 
+				// Only a tag at the very start of the file can be written over in place
+				if tag_start == 0 && parse_options.read_tags {
+					file.tag_space = Some(TagSpace {
+						tag_type: TagType::Id3v2,
+						size: u64::from(header.full_tag_size()),
+					});
+				}
+
 				if parse_options.read_tags {
 					let id3v2 = parse_id3v2(reader, header, parse_options)?;
 					if let Some(existing_tag) = &mut file.id3v2_tag {
@@ -55,7 +65,7 @@ where
 					}
 					file.id3v2_tag = Some(id3v2);
 				} else {
-					reader.seek(SeekFrom::Current(i64::from(header.size)))?;
+					reader.seek(SeekFrom::Current(i64::from(header.content_size())))?;
 				}
 
 				// Skip over the footer
@@ -194,8 +204,9 @@ where
 		let xing_header_location = first_frame_offset + u64::from(first_frame_header.data_start);
 		reader.seek(SeekFrom::Start(xing_header_location))?;
 
-		let mut xing_reader = [0; 32];
-		reader.read_exact(&mut xing_reader)?;
+		// Large enough for a Xing header with all optional fields, followed by a LAME header
+		let mut xing_reader = Vec::with_capacity(192);
+		reader.by_ref().take(192).read_to_end(&mut xing_reader)?;
 
 		let xing_header = VbrHeader::read(&mut &xing_reader[..])?;
 
@@ -35,6 +35,8 @@ impl From<MpcSv4to6Properties> for FileProperties {
 			bit_depth: None,
 			channels: Some(input.channels),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -142,6 +142,8 @@ impl From<MpcSv7Properties> for FileProperties {
 			bit_depth: None,
 			channels: Some(input.channels),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
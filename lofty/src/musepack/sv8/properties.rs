@@ -34,6 +34,8 @@ impl From<MpcSv8Properties> for FileProperties {
 			bit_depth: None,
 			channels: Some(input.stream_header.channels),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -0,0 +1,316 @@
+use super::tag::{VorbisComments, VorbisCommentsRef};
+use super::write::{OGGFormat, create_metadata_packet};
+use crate::config::WriteOptions;
+use crate::error::{FileEncodingError, Result};
+use crate::file::FileType;
+
+use std::borrow::Cow;
+
+use ogg_pager::{CONTAINS_FIRST_PAGE_OF_BITSTREAM, CONTAINS_LAST_PAGE_OF_BITSTREAM, paginate};
+
+/// Builder for an OGG file
+///
+/// This handles the paging, lacing, and CRCs of a complete OGG stream, given its header packets
+/// and raw audio packets. This is mostly useful for generating test files or synthetic streams.
+///
+/// None of the packets are validated, the identification header, setup header, and audio packets are
+/// written as is.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::file::FileType;
+/// use lofty::ogg::{OggBuilder, VorbisComments};
+/// use lofty::tag::Accessor;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// let mut identification_header = b"OpusHead".to_vec();
+/// identification_header.push(1); // Version
+/// identification_header.push(2); // Channel count
+/// identification_header.extend(312_u16.to_le_bytes()); // Pre-skip
+/// identification_header.extend(48000_u32.to_le_bytes()); // Input sample rate
+/// identification_header.extend(0_u16.to_le_bytes()); // Output gain
+/// identification_header.push(0); // Channel mapping family
+///
+/// let mut comments = VorbisComments::new();
+/// comments.set_vendor(String::from("Lofty"));
+/// comments.set_title(String::from("Foo title"));
+///
+/// let file = OggBuilder::new(FileType::Opus)
+/// 	.id_header(identification_header)
+/// 	.comments(comments)
+/// 	.audio_packets(vec![vec![0; 64]; 50])
+/// 	.samples_per_packet(960)
+/// 	.build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OggBuilder {
+	file_type: FileType,
+	stream_serial: u32,
+	identification_header: Option<Vec<u8>>,
+	comments: VorbisComments,
+	setup_header: Option<Vec<u8>>,
+	audio_packets: Vec<Vec<u8>>,
+	samples_per_packet: u64,
+}
+
+impl OggBuilder {
+	/// Create a new `OggBuilder`
+	///
+	/// `file_type` must be one of [`FileType::Opus`], [`FileType::Vorbis`], or [`FileType::Speex`].
+	pub fn new(file_type: FileType) -> Self {
+		Self {
+			file_type,
+			stream_serial: 0,
+			identification_header: None,
+			comments: VorbisComments::default(),
+			setup_header: None,
+			audio_packets: Vec::new(),
+			samples_per_packet: 0,
+		}
+	}
+
+	/// Set the stream serial number
+	///
+	/// The default is `0`.
+	pub fn stream_serial(mut self, stream_serial: u32) -> Self {
+		self.stream_serial = stream_serial;
+		self
+	}
+
+	/// Set the identification header packet
+	///
+	/// This packet is required, and must include its signature (e.g. `OpusHead`).
+	pub fn id_header(mut self, identification_header: Vec<u8>) -> Self {
+		self.identification_header = Some(identification_header);
+		self
+	}
+
+	/// Set the [`VorbisComments`] to write
+	///
+	/// The default is an empty tag, with no vendor string.
+	pub fn comments(mut self, comments: VorbisComments) -> Self {
+		self.comments = comments;
+		self
+	}
+
+	/// Set the setup header packet
+	///
+	/// This packet is required for [`FileType::Vorbis`], and ignored for all other formats.
+	pub fn setup_header(mut self, setup_header: Vec<u8>) -> Self {
+		self.setup_header = Some(setup_header);
+		self
+	}
+
+	/// Append audio packets
+	///
+	/// Each packet will start on a new page.
+	pub fn audio_packets(mut self, audio_packets: impl IntoIterator<Item = Vec<u8>>) -> Self {
+		self.audio_packets.extend(audio_packets);
+		self
+	}
+
+	/// Set the number of samples in each audio packet
+	///
+	/// This is used to assign the granule positions, and is in the format's granule units (for
+	/// example, Opus granule positions are always in 48kHz samples). The default is `0`.
+	pub fn samples_per_packet(mut self, samples_per_packet: u64) -> Self {
+		self.samples_per_packet = samples_per_packet;
+		self
+	}
+
+	/// Construct the OGG stream
+	///
+	/// # Errors
+	///
+	/// * The file type is not an OGG format
+	/// * No identification header was provided
+	/// * No setup header was provided for a [`FileType::Vorbis`] stream
+	/// * The comments are too large to write
+	pub fn build(self) -> Result<Vec<u8>> {
+		let format = match self.file_type {
+			FileType::Opus | FileType::Vorbis | FileType::Speex => {
+				OGGFormat::from_filetype(self.file_type).0
+			},
+			_ => {
+				return Err(FileEncodingError::new(
+					self.file_type,
+					"File type is not an OGG format",
+				)
+				.into());
+			},
+		};
+
+		let Some(identification_header) = self.identification_header else {
+			return Err(FileEncodingError::new(
+				self.file_type,
+				"OGG streams require an identification header",
+			)
+			.into());
+		};
+
+		let mut comments_ref = VorbisCommentsRef {
+			vendor: Cow::Borrowed(self.comments.vendor.as_str()),
+			items: self
+				.comments
+				.items
+				.iter()
+				.map(|(k, v)| (k.as_str(), v.as_str())),
+			pictures: self.comments.pictures.iter().map(|(p, i)| (p, *i)),
+		};
+
+		let comment_packet = create_metadata_packet(
+			&mut comments_ref,
+			format.comment_signature().unwrap_or_default(),
+			format == OGGFormat::Vorbis,
+			WriteOptions::default(),
+		)?;
+
+		let mut header_packets = vec![comment_packet.as_slice()];
+		if format == OGGFormat::Vorbis {
+			let Some(setup_header) = &self.setup_header else {
+				return Err(FileEncodingError::new(
+					FileType::Vorbis,
+					"Vorbis streams require a setup header",
+				)
+				.into());
+			};
+
+			header_packets.push(setup_header.as_slice());
+		}
+
+		// The identification header must be alone on the first page
+		let mut pages = paginate(
+			[identification_header.as_slice()],
+			self.stream_serial,
+			0,
+			CONTAINS_FIRST_PAGE_OF_BITSTREAM,
+		)?;
+
+		let header_flags = if self.audio_packets.is_empty() {
+			CONTAINS_LAST_PAGE_OF_BITSTREAM
+		} else {
+			0
+		};
+		pages.extend(paginate(
+			header_packets,
+			self.stream_serial,
+			0,
+			header_flags,
+		)?);
+
+		let mut granule_position = 0_u64;
+		let audio_packet_count = self.audio_packets.len();
+		for (idx, packet) in self.audio_packets.iter().enumerate() {
+			granule_position = granule_position.saturating_add(self.samples_per_packet);
+
+			let flags = if idx + 1 == audio_packet_count {
+				CONTAINS_LAST_PAGE_OF_BITSTREAM
+			} else {
+				0
+			};
+
+			pages.extend(paginate(
+				[packet.as_slice()],
+				self.stream_serial,
+				granule_position,
+				flags,
+			)?);
+		}
+
+		let mut file = Vec::new();
+		for (sequence_number, mut page) in pages.into_iter().enumerate() {
+			page.header_mut().sequence_number = sequence_number as u32;
+			page.gen_crc();
+			file.extend(page.as_bytes());
+		}
+
+		Ok(file)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::{ParseOptions, ParsingMode};
+	use crate::file::{AudioFile, FileType};
+	use crate::ogg::{OggBuilder, OpusFile, VorbisComments, VorbisFile};
+	use crate::tag::Accessor;
+
+	use std::io::Cursor;
+
+	fn comments() -> VorbisComments {
+		let mut comments = VorbisComments::new();
+		comments.set_vendor(String::from("Lofty"));
+		comments.set_artist(String::from("Foo artist"));
+		comments.set_title(String::from("Bar title"));
+		comments
+	}
+
+	#[test_log::test]
+	fn build_opus() {
+		let mut identification_header = b"OpusHead".to_vec();
+		identification_header.extend([1, 2]);
+		identification_header.extend(312_u16.to_le_bytes());
+		identification_header.extend(48000_u32.to_le_bytes());
+		identification_header.extend([0, 0, 0]);
+
+		let file = OggBuilder::new(FileType::Opus)
+			.stream_serial(1234)
+			.id_header(identification_header)
+			.comments(comments())
+			.audio_packets(vec![vec![0; 100]; 50])
+			.samples_per_packet(960)
+			.build()
+			.unwrap();
+
+		let opus_file = OpusFile::read_from(
+			&mut Cursor::new(file),
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		)
+		.unwrap();
+
+		assert_eq!(opus_file.vorbis_comments(), &comments());
+		assert_eq!(opus_file.properties().channels(), 2);
+		assert_eq!(opus_file.properties().input_sample_rate(), 48000);
+		// (50 * 960) - 312 samples at 48kHz
+		assert_eq!(opus_file.properties().duration().as_millis(), 994);
+	}
+
+	#[test_log::test]
+	fn build_vorbis() {
+		let mut identification_header = b"\x01vorbis".to_vec();
+		identification_header.extend(0_u32.to_le_bytes());
+		identification_header.push(1);
+		identification_header.extend(44100_u32.to_le_bytes());
+		identification_header.extend(0_i32.to_le_bytes());
+		identification_header.extend(128_000_i32.to_le_bytes());
+		identification_header.extend(0_i32.to_le_bytes());
+		identification_header.extend([0xB8, 1]);
+
+		let builder = OggBuilder::new(FileType::Vorbis)
+			.id_header(identification_header)
+			.comments(comments())
+			.audio_packets(vec![vec![0; 100]; 10])
+			.samples_per_packet(4410);
+
+		// The setup header is mandatory
+		assert!(builder.clone().build().is_err());
+
+		let file = builder
+			.setup_header(b"\x05vorbis".to_vec())
+			.build()
+			.unwrap();
+
+		let vorbis_file = VorbisFile::read_from(
+			&mut Cursor::new(file),
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		)
+		.unwrap();
+
+		assert_eq!(vorbis_file.vorbis_comments(), &comments());
+		assert_eq!(vorbis_file.properties().channels(), 1);
+		assert_eq!(vorbis_file.properties().sample_rate(), 44100);
+		assert_eq!(vorbis_file.properties().duration().as_millis(), 1000);
+	}
+}
@@ -0,0 +1,304 @@
+// The Vorbis comment chapter extension
+//
+// https://wiki.xiph.org/Chapter_Extension
+
+use crate::ogg::VorbisComments;
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+const CHAPTER_PREFIX: &str = "CHAPTER";
+const CHAPTER_NAME_SUFFIX: &str = "NAME";
+
+/// A chapter stored in [`VorbisComments`]
+///
+/// Chapters are stored as pairs of `CHAPTERxxx` and `CHAPTERxxxNAME` comments, where `xxx` is a zero-padded
+/// index. The `CHAPTERxxx` comment holds the start time of the chapter in the format `HH:MM:SS.mmm`.
+///
+/// See [`VorbisComments::chapters`] and [`VorbisComments::set_chapters`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Chapter {
+	/// The start time of the chapter
+	pub start: Duration,
+	/// The name of the chapter
+	pub name: Option<String>,
+}
+
+impl Chapter {
+	/// Create a new `Chapter`
+	pub fn new(start: Duration, name: Option<String>) -> Self {
+		Self { start, name }
+	}
+}
+
+impl VorbisComments {
+	/// Gets all chapters
+	///
+	/// The chapters are returned in the order of their index. Any chapter with a missing or invalid start
+	/// time is skipped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisComments;
+	/// use std::time::Duration;
+	///
+	/// let mut tag = VorbisComments::default();
+	/// tag.push(String::from("CHAPTER001"), String::from("00:00:00.000"));
+	/// tag.push(String::from("CHAPTER001NAME"), String::from("Intro"));
+	/// tag.push(String::from("CHAPTER002"), String::from("00:01:30.500"));
+	///
+	/// let chapters = tag.chapters();
+	/// assert_eq!(chapters.len(), 2);
+	/// assert_eq!(chapters[0].name.as_deref(), Some("Intro"));
+	/// assert_eq!(chapters[1].start, Duration::from_millis(90_500));
+	/// assert_eq!(chapters[1].name, None);
+	/// ```
+	pub fn chapters(&self) -> Vec<Chapter> {
+		let mut entries: BTreeMap<u32, (Option<&str>, Option<&str>)> = BTreeMap::new();
+		for (key, value) in &self.items {
+			let Some((index, is_name)) = parse_chapter_key(key) else {
+				continue;
+			};
+
+			let entry = entries.entry(index).or_default();
+			if is_name {
+				entry.1 = Some(value.as_str());
+			} else {
+				entry.0 = Some(value.as_str());
+			}
+		}
+
+		let mut chapters = Vec::with_capacity(entries.len());
+		for (index, (start, name)) in entries {
+			let Some(start) = start else {
+				log::warn!("Chapter {index} has no start time, skipping");
+				continue;
+			};
+
+			let Some(start) = parse_timestamp(start) else {
+				log::warn!("Chapter {index} has an invalid start time: {start:?}, skipping");
+				continue;
+			};
+
+			chapters.push(Chapter {
+				start,
+				name: name.map(str::to_owned),
+			});
+		}
+
+		chapters
+	}
+
+	/// Replaces all chapters
+	///
+	/// This will remove all existing `CHAPTERxxx` and `CHAPTERxxxNAME` comments, and write `chapters` in
+	/// order, starting at index `001`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::{Chapter, VorbisComments};
+	/// use std::time::Duration;
+	///
+	/// let mut tag = VorbisComments::default();
+	/// tag.set_chapters([
+	/// 	Chapter::new(Duration::ZERO, Some(String::from("Intro"))),
+	/// 	Chapter::new(Duration::from_secs(3723), None),
+	/// ]);
+	///
+	/// assert_eq!(tag.get("CHAPTER001"), Some("00:00:00.000"));
+	/// assert_eq!(tag.get("CHAPTER001NAME"), Some("Intro"));
+	/// assert_eq!(tag.get("CHAPTER002"), Some("01:02:03.000"));
+	/// assert_eq!(tag.get("CHAPTER002NAME"), None);
+	/// ```
+	pub fn set_chapters(&mut self, chapters: impl IntoIterator<Item = Chapter>) {
+		self.remove_chapters();
+
+		for (index, chapter) in (1..).zip(chapters) {
+			self.items.push((
+				format!("{CHAPTER_PREFIX}{index:03}"),
+				format_timestamp(chapter.start),
+			));
+
+			if let Some(name) = chapter.name {
+				self.items.push((
+					format!("{CHAPTER_PREFIX}{index:03}{CHAPTER_NAME_SUFFIX}"),
+					name,
+				));
+			}
+		}
+	}
+
+	/// Removes all chapters
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::{Chapter, VorbisComments};
+	/// use lofty::tag::TagExt;
+	///
+	/// let mut tag = VorbisComments::default();
+	/// tag.set_chapters([Chapter::default()]);
+	/// assert!(!tag.is_empty());
+	///
+	/// tag.remove_chapters();
+	/// assert!(tag.is_empty());
+	/// ```
+	pub fn remove_chapters(&mut self) {
+		self.items
+			.retain(|(key, _)| parse_chapter_key(key).is_none());
+	}
+}
+
+// Returns the chapter index, and whether the key is for the chapter name
+fn parse_chapter_key(key: &str) -> Option<(u32, bool)> {
+	if key.len() <= CHAPTER_PREFIX.len()
+		|| !key.is_char_boundary(CHAPTER_PREFIX.len())
+		|| !key[..CHAPTER_PREFIX.len()].eq_ignore_ascii_case(CHAPTER_PREFIX)
+	{
+		return None;
+	}
+
+	let rest = &key[CHAPTER_PREFIX.len()..];
+	let digits_end = rest
+		.bytes()
+		.position(|b| !b.is_ascii_digit())
+		.unwrap_or(rest.len());
+	if digits_end == 0 {
+		return None;
+	}
+
+	let (digits, suffix) = rest.split_at(digits_end);
+	let is_name = match suffix {
+		"" => false,
+		_ if suffix.eq_ignore_ascii_case(CHAPTER_NAME_SUFFIX) => true,
+		_ => return None,
+	};
+
+	Some((digits.parse().ok()?, is_name))
+}
+
+// Parses a `HH:MM:SS.mmm` timestamp, the fractional part being optional
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+	let mut parts = timestamp.trim().splitn(3, ':');
+	let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+
+	let (seconds, fraction) = match seconds.split_once('.') {
+		Some((seconds, fraction)) => (seconds, Some(fraction)),
+		None => (seconds, None),
+	};
+
+	let parse_part = |part: &str| -> Option<u64> {
+		if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+			return None;
+		}
+
+		part.parse().ok()
+	};
+
+	let hours = parse_part(hours)?;
+	let minutes = parse_part(minutes)?;
+	let seconds = parse_part(seconds)?;
+	if minutes >= 60 || seconds >= 60 {
+		return None;
+	}
+
+	let mut nanos = 0;
+	if let Some(fraction) = fraction {
+		parse_part(fraction)?;
+
+		// Only nanosecond precision is possible, anything beyond that is dropped
+		for (place, digit) in fraction.bytes().take(9).enumerate() {
+			nanos += u32::from(digit - b'0') * 10_u32.pow(8 - place as u32);
+		}
+	}
+
+	let total_seconds = hours
+		.checked_mul(3600)?
+		.checked_add(minutes * 60)?
+		.checked_add(seconds)?;
+	Some(Duration::new(total_seconds, nanos))
+}
+
+fn format_timestamp(timestamp: Duration) -> String {
+	let total_seconds = timestamp.as_secs();
+	format!(
+		"{:02}:{:02}:{:02}.{:03}",
+		total_seconds / 3600,
+		(total_seconds / 60) % 60,
+		total_seconds % 60,
+		timestamp.subsec_millis()
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{format_timestamp, parse_chapter_key, parse_timestamp};
+	use crate::ogg::VorbisComments;
+
+	use std::time::Duration;
+
+	#[test_log::test]
+	fn chapter_keys() {
+		assert_eq!(parse_chapter_key("CHAPTER001"), Some((1, false)));
+		assert_eq!(parse_chapter_key("chapter012name"), Some((12, true)));
+		assert_eq!(parse_chapter_key("CHAPTER1000NAME"), Some((1000, true)));
+
+		for invalid in [
+			"CHAPTER",
+			"CHAPTERNAME",
+			"CHAPTER001URL",
+			"CHAPTERS",
+			"TITLE",
+		] {
+			assert_eq!(parse_chapter_key(invalid), None, "{invalid:?}");
+		}
+	}
+
+	#[test_log::test]
+	fn timestamps() {
+		assert_eq!(parse_timestamp("00:00:00.000"), Some(Duration::ZERO));
+		assert_eq!(
+			parse_timestamp("01:02:03.5"),
+			Some(Duration::from_millis(3_723_500))
+		);
+		assert_eq!(
+			parse_timestamp("100:00:00"),
+			Some(Duration::from_secs(360_000))
+		);
+
+		for invalid in [
+			"",
+			"00:00",
+			"00:60:00.000",
+			"00:00:-1.000",
+			"aa:bb:cc",
+			"00:00:00.",
+		] {
+			assert_eq!(parse_timestamp(invalid), None, "{invalid:?}");
+		}
+
+		assert_eq!(
+			format_timestamp(Duration::from_millis(3_723_500)),
+			"01:02:03.500"
+		);
+	}
+
+	#[test_log::test]
+	fn invalid_chapters_skipped() {
+		let mut tag = VorbisComments::default();
+		tag.push(String::from("CHAPTER002"), String::from("invalid"));
+		tag.push(
+			String::from("CHAPTER002NAME"),
+			String::from("Invalid start"),
+		);
+		tag.push(String::from("CHAPTER003NAME"), String::from("No start"));
+		tag.push(String::from("CHAPTER001"), String::from("00:00:10.000"));
+
+		let chapters = tag.chapters();
+		assert_eq!(chapters.len(), 1);
+		assert_eq!(chapters[0].start, Duration::from_secs(10));
+		assert_eq!(chapters[0].name, None);
+	}
+}
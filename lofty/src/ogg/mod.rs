@@ -3,6 +3,8 @@
 //! ## File notes
 //!
 //! The only supported tag format is [`VorbisComments`]
+mod builder;
+mod chapters;
 pub(crate) mod constants;
 pub(crate) mod opus;
 mod picture_storage;
@@ -21,14 +23,20 @@ use ogg_pager::{Page, PageHeader};
 
 // Exports
 
+pub use builder::OggBuilder;
+pub use chapters::Chapter;
 pub use opus::OpusFile;
+pub use opus::gapless::OpusGaplessInfo;
+pub use opus::loudness::OpusLoudnessInfo;
 pub use opus::properties::OpusProperties;
 pub use picture_storage::OggPictureStorage;
 pub use speex::SpeexFile;
 pub use speex::properties::SpeexProperties;
 pub use tag::VorbisComments;
 pub use vorbis::VorbisFile;
+pub use vorbis::fragmentation::FragmentationStats;
 pub use vorbis::properties::VorbisProperties;
+pub use write::{rewrite_comment_header, update_comment_header_in_place};
 
 fn verify_signature(content: &[u8], sig: &[u8]) -> Result<()> {
 	let sig_len = sig.len();
@@ -40,10 +48,97 @@ fn verify_signature(content: &[u8], sig: &[u8]) -> Result<()> {
 	Ok(())
 }
 
-fn find_last_page<R>(data: &mut R) -> Result<Page>
+const CAPTURE_PATTERN: &[u8] = b"OggS";
+
+// Searches `content` for the start of a page
+fn find_capture_pattern(content: &[u8]) -> Option<usize> {
+	content
+		.windows(CAPTURE_PATTERN.len())
+		.position(|window| window == CAPTURE_PATTERN)
+}
+
+// Searches `content` for the start of the last page
+fn rfind_capture_pattern(content: &[u8]) -> Option<usize> {
+	content
+		.windows(CAPTURE_PATTERN.len())
+		.rposition(|window| window == CAPTURE_PATTERN)
+}
+
+// Searches for the first page, which may be preceded by up to `max_junk_bytes` of junk
+//
+// The reader will be left at the start of the page. If no page is found, it will be left at its
+// original position.
+fn find_first_page<R>(data: &mut R, max_junk_bytes: usize) -> Result<()>
 where
 	R: Read + Seek,
 {
+	let start = data.stream_position()?;
+
+	let mut content = Vec::new();
+	data.by_ref()
+		.take(max_junk_bytes as u64 + CAPTURE_PATTERN.len() as u64)
+		.read_to_end(&mut content)?;
+
+	let junk_len = find_capture_pattern(&content).unwrap_or(0);
+	if junk_len > 0 {
+		log::warn!("OGG: Skipping {junk_len} bytes of junk before the first page");
+	}
+
+	data.seek(SeekFrom::Start(start + junk_len as u64))?;
+	Ok(())
+}
+
+// Searches backward from the end of the stream for the last page, up to `max_search` bytes
+//
+// The search will never go before the current position. If no page is found, this falls back to
+// walking the page headers forward from the current position.
+fn find_last_page<R>(data: &mut R, max_search: u64) -> Result<Page>
+where
+	R: Read + Seek,
+{
+	const CHUNK_SIZE: u64 = 64 * 1024;
+
+	let search_start = data.stream_position()?;
+	let file_len = data.seek(SeekFrom::End(0))?;
+	let search_limit = file_len.saturating_sub(max_search).max(search_start);
+
+	let mut chunk = Vec::new();
+	let mut chunk_end = file_len;
+	while chunk_end > search_limit {
+		let chunk_start = chunk_end.saturating_sub(CHUNK_SIZE).max(search_limit);
+
+		// Overlap the previous chunk, in case it split a capture pattern
+		let read_end = (chunk_end + CAPTURE_PATTERN.len() as u64 - 1).min(file_len);
+
+		chunk.clear();
+		data.seek(SeekFrom::Start(chunk_start))?;
+		data.by_ref()
+			.take(read_end - chunk_start)
+			.read_to_end(&mut chunk)?;
+
+		let mut remaining = chunk.as_slice();
+		while let Some(pos) = rfind_capture_pattern(remaining) {
+			data.seek(SeekFrom::Start(chunk_start + pos as u64))?;
+
+			// The pattern may appear in the middle of a page, so make sure this is a real one
+			if let Ok(mut page) = Page::read(data) {
+				let checksum = page.header().checksum();
+				page.gen_crc();
+				if page.header().checksum() == checksum {
+					return Ok(page);
+				}
+			}
+
+			remaining = &chunk[..pos + CAPTURE_PATTERN.len() - 1];
+		}
+
+		chunk_end = chunk_start;
+	}
+
+	log::debug!("OGG: Last page not found within {max_search} bytes, walking the stream instead");
+
+	data.seek(SeekFrom::Start(search_start))?;
+
 	let mut last_page_header = PageHeader::read(data)?;
 	data.seek(SeekFrom::Current(last_page_header.content_size() as i64))?;
 
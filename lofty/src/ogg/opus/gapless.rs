@@ -0,0 +1,88 @@
+/// Sample-accurate trimming information for an Opus file
+///
+/// Opus streams are decoded in whole packets, so the decoded audio is usually longer than the original.
+/// To play the file gaplessly, a player has to discard:
+///
+/// * The first [`OpusGaplessInfo::pre_skip`] samples, the encoder delay
+/// * The last [`OpusGaplessInfo::trailing_padding`] samples, which pad out the final packet
+///
+/// What remains are the [`OpusGaplessInfo::total_samples`] samples of the original audio.
+///
+/// All values are in 48 kHz samples (per channel), regardless of the input sample rate. See
+/// [RFC 7845 Section 4].
+///
+/// [RFC 7845 Section 4]: https://datatracker.ietf.org/doc/html/rfc7845#section-4
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct OpusGaplessInfo {
+	pub(crate) pre_skip: u16,
+	pub(crate) total_samples: u64,
+	pub(crate) trailing_padding: u64,
+}
+
+impl OpusGaplessInfo {
+	/// The number of samples to discard from the start of the decoded audio
+	///
+	/// See [`OpusProperties::pre_skip`](crate::ogg::OpusProperties::pre_skip)
+	pub fn pre_skip(&self) -> u16 {
+		self.pre_skip
+	}
+
+	/// The number of samples left to play after trimming
+	///
+	/// This is the granule position of the last page, minus the starting granule position and the
+	/// pre-skip.
+	pub fn total_samples(&self) -> u64 {
+		self.total_samples
+	}
+
+	/// The number of samples to discard from the end of the decoded audio
+	///
+	/// This is the number of samples decoded past the granule position of the last page, relative
+	/// to the starting granule position.
+	pub fn trailing_padding(&self) -> u64 {
+		self.trailing_padding
+	}
+}
+
+// The number of 48 kHz samples a packet decodes to
+//
+// https://datatracker.ietf.org/doc/html/rfc6716#section-3.1
+pub(super) fn packet_samples(packet: &[u8]) -> Option<u64> {
+	let &toc = packet.first()?;
+
+	// The frame size is determined by the mode (SILK, Hybrid, or CELT) and bandwidth
+	let config = toc >> 3;
+	let frame_size = match config {
+		0..=11 => [480, 960, 1920, 2880][usize::from(config % 4)],
+		12..=15 => [480, 960][usize::from(config % 2)],
+		_ => [120, 240, 480, 960][usize::from(config % 4)],
+	};
+
+	let frame_count = match toc & 0b11 {
+		0 => 1,
+		1 | 2 => 2,
+		// An arbitrary number of frames, stored in the following byte
+		_ => u64::from(packet.get(1)? & 0b0011_1111),
+	};
+
+	Some(frame_size * frame_count)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::packet_samples;
+
+	#[test_log::test]
+	fn samples_per_packet() {
+		// SILK NB, 10ms, 1 frame
+		assert_eq!(packet_samples(&[0b0000_0000]), Some(480));
+		// Hybrid FB, 20ms, 2 frames
+		assert_eq!(packet_samples(&[(15 << 3) | 1]), Some(1920));
+		// CELT FB, 2.5ms, 3 frames
+		assert_eq!(packet_samples(&[(28 << 3) | 3, 3]), Some(360));
+
+		assert_eq!(packet_samples(&[]), None);
+		// Missing frame count byte
+		assert_eq!(packet_samples(&[(31 << 3) | 3]), None);
+	}
+}
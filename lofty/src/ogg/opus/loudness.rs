@@ -0,0 +1,254 @@
+use crate::ogg::VorbisComments;
+
+pub(super) const R128_TRACK_GAIN: &str = "R128_TRACK_GAIN";
+pub(super) const R128_ALBUM_GAIN: &str = "R128_ALBUM_GAIN";
+
+/// Loudness normalization information for an Opus file
+///
+/// Opus stores gain in two places:
+///
+/// * The identification header's output gain, which decoders apply unconditionally
+/// * The `R128_TRACK_GAIN` and `R128_ALBUM_GAIN` comments, which are applied on top of the output gain
+///   when normalizing to -23 LUFS ([EBU R128])
+///
+/// All of these are Q7.8 fixed point numbers in dB, see [RFC 7845 Section 5.2.1].
+///
+/// [EBU R128]: https://tech.ebu.ch/publications/r128
+/// [RFC 7845 Section 5.2.1]: https://datatracker.ietf.org/doc/html/rfc7845#section-5.2.1
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct OpusLoudnessInfo {
+	pub(crate) output_gain: i16,
+	pub(crate) track_gain: Option<i16>,
+	pub(crate) album_gain: Option<i16>,
+}
+
+impl OpusLoudnessInfo {
+	pub(super) fn new(output_gain: i16, vorbis_comments: &VorbisComments) -> Self {
+		Self {
+			output_gain,
+			track_gain: parse_r128_gain(vorbis_comments, R128_TRACK_GAIN),
+			album_gain: parse_r128_gain(vorbis_comments, R128_ALBUM_GAIN),
+		}
+	}
+
+	/// The identification header's output gain, in Q7.8 dB
+	///
+	/// See [`OpusProperties::output_gain`](crate::ogg::OpusProperties::output_gain)
+	pub fn output_gain(&self) -> i16 {
+		self.output_gain
+	}
+
+	/// The `R128_TRACK_GAIN` comment, in Q7.8 dB
+	///
+	/// This is relative to the output gain. This will be `None` if the comment is missing or invalid.
+	pub fn track_gain(&self) -> Option<i16> {
+		self.track_gain
+	}
+
+	/// The `R128_ALBUM_GAIN` comment, in Q7.8 dB
+	///
+	/// This is relative to the output gain. This will be `None` if the comment is missing or invalid.
+	pub fn album_gain(&self) -> Option<i16> {
+		self.album_gain
+	}
+
+	/// The output gain in dB
+	pub fn output_gain_db(&self) -> f64 {
+		q7_8_to_db(i32::from(self.output_gain))
+	}
+
+	/// The total gain in dB to apply for track normalization
+	///
+	/// This is the output gain plus the `R128_TRACK_GAIN`. If there is no track gain, this is
+	/// the same as [`OpusLoudnessInfo::output_gain_db`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::ogg::OpusFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/full_test.opus")?;
+	/// let opus_file = OpusFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// let loudness = opus_file.loudness_info();
+	///
+	/// // This file has no R128 tags, so only the output gain applies
+	/// assert_eq!(loudness.track_gain(), None);
+	/// let gain_db = loudness.total_track_gain_db();
+	/// # assert!(gain_db.abs() < f64::EPSILON);
+	/// # Ok(()) }
+	/// ```
+	pub fn total_track_gain_db(&self) -> f64 {
+		self.total_gain_db(self.track_gain)
+	}
+
+	/// The total gain in dB to apply for album normalization
+	///
+	/// This is the output gain plus the `R128_ALBUM_GAIN`. If there is no album gain, this is
+	/// the same as [`OpusLoudnessInfo::output_gain_db`].
+	pub fn total_album_gain_db(&self) -> f64 {
+		self.total_gain_db(self.album_gain)
+	}
+
+	fn total_gain_db(&self, r128_gain: Option<i16>) -> f64 {
+		// Summed in Q7.8 to stay exact, the result can exceed the range of an `i16`
+		let total = i32::from(self.output_gain) + i32::from(r128_gain.unwrap_or(0));
+		q7_8_to_db(total)
+	}
+}
+
+fn q7_8_to_db(value: i32) -> f64 {
+	f64::from(value) / 256.0
+}
+
+// The R128 gain needed to reach a total of `total_gain_db`, given the header's output gain
+pub(super) fn r128_gain_for_total(output_gain: i16, total_gain_db: f64) -> i16 {
+	let total = (total_gain_db * 256.0).round() as i32;
+	let r128_gain = total.saturating_sub(i32::from(output_gain));
+
+	match i16::try_from(r128_gain) {
+		Ok(gain) => gain,
+		Err(_) => {
+			log::warn!("A total gain of {total_gain_db} dB can't be represented, clamping");
+			r128_gain.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16
+		},
+	}
+}
+
+fn parse_r128_gain(vorbis_comments: &VorbisComments, key: &str) -> Option<i16> {
+	let value = vorbis_comments.get(key)?;
+	match value.trim().parse::<i16>() {
+		Ok(gain) => Some(gain),
+		Err(_) => {
+			log::warn!("Invalid `{key}` value: {value:?}");
+			None
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::{ParseOptions, WriteOptions};
+	use crate::file::{AudioFile, FileType};
+	use crate::ogg::{OggBuilder, OpusFile, VorbisComments};
+	use crate::tag::TagExt;
+
+	use std::io::{Cursor, Seek};
+
+	fn opus_file_bytes(output_gain: i16, comments: VorbisComments) -> Vec<u8> {
+		let mut identification_header = b"OpusHead".to_vec();
+		identification_header.extend([1, 2]);
+		identification_header.extend(312_u16.to_le_bytes());
+		identification_header.extend(48000_u32.to_le_bytes());
+		identification_header.extend(output_gain.to_le_bytes());
+		identification_header.push(0);
+
+		OggBuilder::new(FileType::Opus)
+			.id_header(identification_header)
+			.comments(comments)
+			.audio_packets(vec![vec![0; 100]; 10])
+			.samples_per_packet(960)
+			.build()
+			.unwrap()
+	}
+
+	fn opus_file(output_gain: i16, comments: VorbisComments) -> OpusFile {
+		let file = opus_file_bytes(output_gain, comments);
+		OpusFile::read_from(&mut Cursor::new(file), ParseOptions::new()).unwrap()
+	}
+
+	#[test_log::test]
+	fn combined_gain() {
+		let mut comments = VorbisComments::default();
+		// -6.5 dB
+		comments.insert(String::from("R128_TRACK_GAIN"), String::from("-1664"));
+		// +1.25 dB
+		comments.insert(String::from("R128_ALBUM_GAIN"), String::from("320"));
+
+		// +3 dB
+		let opus_file = opus_file(768, comments);
+		assert_eq!(opus_file.properties().output_gain(), 768);
+
+		let loudness = opus_file.loudness_info();
+		assert_eq!(loudness.output_gain(), 768);
+		assert_eq!(loudness.track_gain(), Some(-1664));
+		assert_eq!(loudness.album_gain(), Some(320));
+
+		assert!((loudness.output_gain_db() - 3.0).abs() < f64::EPSILON);
+		assert!((loudness.total_track_gain_db() - -3.5).abs() < f64::EPSILON);
+		assert!((loudness.total_album_gain_db() - 4.25).abs() < f64::EPSILON);
+	}
+
+	#[test_log::test]
+	fn combined_gain_exceeds_q7_8() {
+		let mut comments = VorbisComments::default();
+		comments.insert(String::from("R128_TRACK_GAIN"), String::from("32767"));
+		comments.insert(String::from("R128_ALBUM_GAIN"), String::from("invalid"));
+
+		let loudness = opus_file(32767, comments).loudness_info();
+		assert_eq!(loudness.album_gain(), None);
+
+		// 65534 / 256, which can't be represented in Q7.8
+		assert!((loudness.total_track_gain_db() - 255.992_187_5).abs() < f64::EPSILON);
+
+		// Falls back to just the output gain
+		assert!((loudness.total_album_gain_db() - loudness.output_gain_db()).abs() < f64::EPSILON);
+	}
+
+	#[test_log::test]
+	fn set_total_gain_with_output_gain() {
+		// +3 dB
+		let mut file = Cursor::new(opus_file_bytes(768, VorbisComments::default()));
+		let mut opus_file = OpusFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+		// The output gain is accounted for, rather than applied twice
+		opus_file.set_total_track_gain_db(-2.0);
+		opus_file.set_total_album_gain_db(1.5);
+
+		let loudness = opus_file.loudness_info();
+		assert_eq!(loudness.track_gain(), Some(-1280));
+		assert_eq!(loudness.album_gain(), Some(-384));
+		assert!((loudness.total_track_gain_db() - -2.0).abs() < f64::EPSILON);
+		assert!((loudness.total_album_gain_db() - 1.5).abs() < f64::EPSILON);
+
+		// Bake -2 dB into the header, keeping the same total track gain
+		file.rewind().unwrap();
+		opus_file.write_output_gain(&mut file, -512).unwrap();
+		opus_file.set_total_track_gain_db(-2.0);
+		assert_eq!(opus_file.loudness_info().track_gain(), Some(0));
+
+		file.rewind().unwrap();
+		opus_file
+			.vorbis_comments()
+			.save_to(&mut file, WriteOptions::default())
+			.unwrap();
+
+		// The page checksum was updated
+		file.rewind().unwrap();
+		let mut page = ogg_pager::Page::read(&mut file).unwrap();
+		let checksum = page.header().checksum();
+		page.gen_crc();
+		assert_eq!(page.header().checksum(), checksum);
+
+		file.rewind().unwrap();
+		let opus_file = OpusFile::read_from(&mut file, ParseOptions::new()).unwrap();
+		assert_eq!(opus_file.properties().output_gain(), -512);
+
+		let loudness = opus_file.loudness_info();
+		assert_eq!(loudness.track_gain(), Some(0));
+		assert_eq!(loudness.album_gain(), Some(-384));
+		assert!((loudness.total_track_gain_db() - -2.0).abs() < f64::EPSILON);
+		// The album gain wasn't reset, so it shifted with the output gain
+		assert!((loudness.total_album_gain_db() - -3.5).abs() < f64::EPSILON);
+	}
+
+	#[test_log::test]
+	fn set_total_gain_clamps() {
+		let mut opus_file = opus_file(-32768, VorbisComments::default());
+		opus_file.set_total_track_gain_db(200.0);
+		assert_eq!(opus_file.loudness_info().track_gain(), Some(i16::MAX));
+	}
+}
@@ -1,13 +1,22 @@
+pub(super) mod gapless;
+pub(super) mod loudness;
 pub(super) mod properties;
 
 use super::find_last_page;
 use super::tag::VorbisComments;
-use crate::config::ParseOptions;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
+use crate::file::tag_space::TagSpace;
+use crate::macros::decode_err;
 use crate::ogg::constants::{OPUSHEAD, OPUSTAGS};
+use crate::util::io::SeekStreamLen;
+use gapless::OpusGaplessInfo;
+use loudness::{OpusLoudnessInfo, R128_ALBUM_GAIN, R128_TRACK_GAIN};
 use properties::OpusProperties;
 
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use ogg_pager::Page;
 
 use lofty_attr::LoftyFile;
 
@@ -22,9 +31,270 @@ pub struct OpusFile {
 	pub(crate) vorbis_comments_tag: VorbisComments,
 	/// The file's audio properties
 	pub(crate) properties: OpusProperties,
+	pub(crate) stream_offset: u64,
+	pub(crate) tag_space: Option<TagSpace>,
 }
 
 impl OpusFile {
+	/// The offset of the stream's first page
+	///
+	/// This is `0`, unless the stream was preceded by junk. See [`ParseOptions::max_junk_bytes()`].
+	pub fn stream_offset(&self) -> u64 {
+		self.stream_offset
+	}
+
+	/// Returns the file's loudness normalization information
+	///
+	/// This combines the identification header's output gain with the `R128_TRACK_GAIN` and
+	/// `R128_ALBUM_GAIN` comments. See [`OpusLoudnessInfo`].
+	///
+	/// NOTE: The output gain is read alongside the audio properties, so it will be `0` if
+	///       [`ParseOptions::read_properties()`] is disabled.
+	pub fn loudness_info(&self) -> OpusLoudnessInfo {
+		OpusLoudnessInfo::new(self.properties.output_gain, &self.vorbis_comments_tag)
+	}
+
+	/// Sets the `R128_TRACK_GAIN` comment, so the total track gain becomes `gain_db`
+	///
+	/// Decoders always apply the identification header's output gain, and the comment is applied
+	/// on top of it. If the file already has a non-zero output gain, it is subtracted here rather
+	/// than applied twice. See [`OpusLoudnessInfo::total_track_gain_db`].
+	///
+	/// A gain that can't be stored relative to the output gain is clamped.
+	///
+	/// NOTE: The output gain is read alongside the audio properties, so it is assumed to be `0` if
+	///       [`ParseOptions::read_properties()`] was disabled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::ogg::OpusFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut reader = std::fs::File::open("tests/files/assets/minimal/full_test.opus")?;
+	/// let mut opus_file = OpusFile::read_from(&mut reader, ParseOptions::new())?;
+	///
+	/// opus_file.set_total_track_gain_db(-6.5);
+	///
+	/// let gain_db = opus_file.loudness_info().total_track_gain_db();
+	/// # assert!((gain_db - -6.5).abs() < f64::EPSILON);
+	/// # Ok(()) }
+	/// ```
+	pub fn set_total_track_gain_db(&mut self, gain_db: f64) {
+		self.set_r128_gain(R128_TRACK_GAIN, gain_db);
+	}
+
+	/// Sets the `R128_ALBUM_GAIN` comment, so the total album gain becomes `gain_db`
+	///
+	/// See [`OpusFile::set_total_track_gain_db`].
+	pub fn set_total_album_gain_db(&mut self, gain_db: f64) {
+		self.set_r128_gain(R128_ALBUM_GAIN, gain_db);
+	}
+
+	fn set_r128_gain(&mut self, key: &str, gain_db: f64) {
+		let output_gain = self.properties.output_gain;
+		if output_gain != 0 {
+			log::warn!(
+				"File has an output gain of {} dB, setting `{key}` relative to it",
+				f64::from(output_gain) / 256.0
+			);
+		}
+
+		let gain = loudness::r128_gain_for_total(output_gain, gain_db);
+		self.vorbis_comments_tag
+			.insert(String::from(key), gain.to_string());
+	}
+
+	/// Writes a new output gain to the identification header, in Q7.8 dB
+	///
+	/// `file` is expected to be the file this was read from. Only the first page is rewritten, so
+	/// this can be done in place.
+	///
+	/// NOTE: The R128 comments are relative to the output gain, so changing it shifts the total track
+	///       and album gains as well. To keep them, reset them with [`OpusFile::set_total_track_gain_db`]
+	///       and [`OpusFile::set_total_album_gain_db`] afterward, and save the tag.
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with an Opus identification header
+	/// * [`std::io::Error`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::ogg::OpusFile;
+	/// use std::io::Seek;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.opus";
+	/// let mut file = std::fs::OpenOptions::new().read(true).write(true).open(path)?;
+	/// let mut opus_file = OpusFile::read_from(&mut file, ParseOptions::new())?;
+	///
+	/// // Keep the current loudness, while baking a +3 dB gain into the header
+	/// let track_gain = opus_file.loudness_info().total_track_gain_db();
+	///
+	/// file.rewind()?;
+	/// opus_file.write_output_gain(&mut file, 768)?;
+	/// opus_file.set_total_track_gain_db(track_gain);
+	/// # Ok(()) }
+	/// ```
+	pub fn write_output_gain<F>(&mut self, file: &mut F, output_gain: i16) -> Result<()>
+	where
+		F: Read + Write + Seek,
+	{
+		// "OpusHead", version, channel count, pre-skip, input sample rate
+		const OUTPUT_GAIN_OFFSET: usize = 16;
+		// "OggS", version, header type, granule position, stream serial, sequence number
+		const CHECKSUM_OFFSET: usize = 22;
+
+		let page = Page::read(file)?;
+		if !page.content().starts_with(OPUSHEAD) || page.content().len() < OUTPUT_GAIN_OFFSET + 2 {
+			decode_err!(@BAIL Opus, "File does not start with an identification header");
+		}
+
+		let mut page_bytes = page.as_bytes();
+
+		let gain_pos = page_bytes.len() - page.content().len() + OUTPUT_GAIN_OFFSET;
+		page_bytes[gain_pos..gain_pos + 2].copy_from_slice(&output_gain.to_le_bytes());
+
+		// The checksum is computed with its own field zeroed
+		page_bytes[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 4].fill(0);
+		let checksum = ogg_pager::crc32(&page_bytes);
+		page_bytes[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 4].copy_from_slice(&checksum.to_le_bytes());
+
+		file.seek(SeekFrom::Start(page.header().start))?;
+		file.write_all(&page_bytes)?;
+
+		self.properties.output_gain = output_gain;
+		Ok(())
+	}
+
+	/// Extracts the raw audio packets from an Opus stream
+	///
+	/// The packets are reassembled from the pages of the stream, excluding the identification and
+	/// comment headers. Each item is a single Opus packet, ready to be handed to a decoder.
+	///
+	/// NOTE: Pages belonging to other logical streams are skipped, as is an incomplete packet at
+	///       the end of the stream.
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with an Opus identification header
+	/// * A page is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::OpusFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.opus";
+	/// let mut reader = std::fs::File::open(path)?;
+	///
+	/// let packets = OpusFile::extract_packets(&mut reader)?;
+	/// assert_eq!(packets.len(), 72);
+	/// # Ok(()) }
+	/// ```
+	pub fn extract_packets<R>(reader: &mut R) -> Result<Vec<Vec<u8>>>
+	where
+		R: Read + Seek,
+	{
+		read_audio_packets(reader).map(|audio_packets| audio_packets.packets)
+	}
+
+	/// Reads the information needed to trim the decoded audio for gapless playback
+	///
+	/// This reads the entire stream, counting the samples the audio packets decode to. Any samples
+	/// past the granule position of the last page (skipping pages where no packet ends, which have
+	/// a granule position of `-1`) are [`OpusGaplessInfo::trailing_padding`].
+	///
+	/// Streams don't have to start at a granule position of `0`, so all granule positions are taken
+	/// relative to the starting granule position, derived from the first audio page.
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with an Opus identification header
+	/// * A page is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::OpusFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.opus";
+	/// let mut reader = std::fs::File::open(path)?;
+	///
+	/// let gapless_info = OpusFile::gapless_info(&mut reader)?;
+	/// assert_eq!(gapless_info.pre_skip(), 312);
+	///
+	/// // Only play back the original audio
+	/// let start = u64::from(gapless_info.pre_skip());
+	/// let end = start + gapless_info.total_samples();
+	/// # assert!(end > start);
+	/// # Ok(()) }
+	/// ```
+	pub fn gapless_info<R>(reader: &mut R) -> Result<OpusGaplessInfo>
+	where
+		R: Read + Seek,
+	{
+		let audio_packets = read_audio_packets(reader)?;
+
+		let pre_skip = properties::read_identification_header(
+			&audio_packets.identification_header,
+			ParsingMode::BestAttempt,
+		)?
+		.pre_skip;
+
+		let mut decoded_samples = 0_u64;
+		for packet in &audio_packets.packets {
+			match gapless::packet_samples(packet) {
+				Some(samples) => decoded_samples += samples,
+				None => {
+					log::warn!("Opus: Encountered an invalid audio packet, assuming it is empty")
+				},
+			}
+		}
+
+		// https://datatracker.ietf.org/doc/html/rfc7845#section-4.3
+		//
+		// The stream doesn't have to start at a granule position of 0. The starting position is
+		// the granule position of the first audio page, minus the samples of the packets ending on it.
+		let start_granule_position =
+			audio_packets
+				.first_audio_page
+				.map_or(0, |(granule_position, packet_count)| {
+					let samples = audio_packets.packets[..packet_count]
+						.iter()
+						.filter_map(|packet| gapless::packet_samples(packet))
+						.sum::<u64>();
+
+					// Only the last page is allowed to end early, in which case the stream starts at 0
+					granule_position.saturating_sub(samples)
+				});
+
+		let last_granule_position = audio_packets
+			.last_granule_position
+			.unwrap_or(0)
+			.saturating_sub(start_granule_position);
+		if last_granule_position > decoded_samples {
+			log::warn!(
+				"Opus: The last granule position ({last_granule_position}) exceeds the number of \
+				 decoded samples ({decoded_samples})"
+			);
+		}
+
+		Ok(OpusGaplessInfo {
+			pre_skip,
+			total_samples: last_granule_position.saturating_sub(u64::from(pre_skip)),
+			trailing_padding: decoded_samples.saturating_sub(last_granule_position),
+		})
+	}
+
 	fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<Self>
 	where
 		R: Read + Seek,
@@ -32,14 +302,112 @@ impl OpusFile {
 		let file_information =
 			super::read::read_from(reader, OPUSHEAD, OPUSTAGS, 2, parse_options)?;
 
+		let tag_space = super::read::comment_header_space(&file_information);
+
 		Ok(Self {
 			properties: if parse_options.read_properties {
-				properties::read_properties(reader, &file_information.1, &file_information.2)?
+				properties::read_properties(
+					reader,
+					&file_information.1,
+					&file_information.2,
+					parse_options.parsing_mode,
+					parse_options.max_last_page_search,
+				)?
 			} else {
 				OpusProperties::default()
 			},
 			// A metadata packet is mandatory in Opus
 			vorbis_comments_tag: file_information.0.unwrap_or_default(),
+			stream_offset: file_information.1.start,
+			tag_space,
 		})
 	}
 }
+
+struct AudioPackets {
+	identification_header: Vec<u8>,
+	packets: Vec<Vec<u8>>,
+	// The granule position of the first page that an audio packet ends on, and the number of audio
+	// packets ending on it
+	first_audio_page: Option<(u64, usize)>,
+	// The granule position of the last page that a packet ends on
+	last_granule_position: Option<u64>,
+}
+
+// Reassembles the audio packets of the stream, see `OpusFile::extract_packets`
+fn read_audio_packets<R>(reader: &mut R) -> Result<AudioPackets>
+where
+	R: Read + Seek,
+{
+	// The identification and comment headers
+	const HEADER_PACKET_COUNT: usize = 2;
+
+	let stream_len = reader.stream_len_hack()?;
+
+	let first_page = Page::read(reader)?;
+	if !first_page.content().starts_with(OPUSHEAD) {
+		decode_err!(@BAIL Opus, "Stream does not start with an identification header");
+	}
+
+	let stream_serial = first_page.header().stream_serial;
+
+	let mut packets = Vec::new();
+	let mut current_packet = Vec::new();
+	let mut first_audio_page = None;
+	let mut last_granule_position = None;
+	let mut page = first_page;
+	loop {
+		if page.header().stream_serial == stream_serial {
+			// A granule position of -1 means that no packet ends on the page
+			let granule_position = page.header().abgp;
+			if granule_position != u64::MAX {
+				last_granule_position = Some(granule_position);
+			}
+
+			let packets_before_page = packets.len().max(HEADER_PACKET_COUNT);
+
+			let mut content = page.content();
+			for &lacing_value in page.header().segments() {
+				let (segment, rest) = content.split_at(usize::from(lacing_value));
+				current_packet.extend_from_slice(segment);
+				content = rest;
+
+				// A lacing value < 255 marks the end of a packet
+				if lacing_value < 255 {
+					packets.push(core::mem::take(&mut current_packet));
+				}
+			}
+
+			if first_audio_page.is_none() && packets.len() > packets_before_page {
+				first_audio_page = Some((granule_position, packets.len() - packets_before_page));
+			}
+		} else {
+			log::debug!(
+				"Skipping page belonging to stream {}",
+				page.header().stream_serial
+			);
+		}
+
+		if reader.stream_position()? >= stream_len {
+			break;
+		}
+
+		page = Page::read(reader)?;
+	}
+
+	if !current_packet.is_empty() {
+		log::warn!("Stream ends with an incomplete packet, discarding");
+	}
+
+	if packets.len() < HEADER_PACKET_COUNT {
+		decode_err!(@BAIL Opus, "Stream is missing a comment header");
+	}
+
+	let audio_packets = packets.split_off(HEADER_PACKET_COUNT);
+	Ok(AudioPackets {
+		identification_header: packets.swap_remove(0),
+		packets: audio_packets,
+		first_audio_page,
+		last_granule_position,
+	})
+}
@@ -1,6 +1,8 @@
 use super::find_last_page;
-use crate::error::Result;
-use crate::macros::decode_err;
+use crate::config::ParsingMode;
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::macros::{decode_err, try_vec};
 use crate::properties::{ChannelMask, FileProperties};
 use crate::util::math::RoundedDivision;
 
@@ -21,6 +23,8 @@ pub struct OpusProperties {
 	pub(crate) channel_mask: ChannelMask,
 	pub(crate) version: u8,
 	pub(crate) input_sample_rate: u32,
+	pub(crate) output_gain: i16,
+	pub(crate) pre_skip: u16,
 }
 
 impl From<OpusProperties> for FileProperties {
@@ -37,6 +41,8 @@ impl From<OpusProperties> for FileProperties {
 			} else {
 				Some(input.channel_mask)
 			},
+			encoder_delay: Some(u32::from(input.pre_skip)),
+			encoder_padding: None,
 		}
 	}
 }
@@ -76,47 +82,39 @@ impl OpusProperties {
 	pub fn input_sample_rate(&self) -> u32 {
 		self.input_sample_rate
 	}
+
+	/// Output gain
+	///
+	/// This is the gain to apply when decoding, as a Q7.8 fixed point number in dB. To get the
+	/// gain in dB, divide by 256.
+	pub fn output_gain(&self) -> i16 {
+		self.output_gain
+	}
+
+	/// The number of samples (at 48kHz) to discard from the start of the stream when decoding
+	pub fn pre_skip(&self) -> u16 {
+		self.pre_skip
+	}
 }
 
 pub(in crate::ogg) fn read_properties<R>(
 	data: &mut R,
 	first_page_header: &PageHeader,
 	packets: &Packets,
+	parse_mode: ParsingMode,
+	max_last_page_search: u64,
 ) -> Result<OpusProperties>
 where
 	R: Read + Seek,
 {
-	let mut properties = OpusProperties::default();
-
 	// Safe to unwrap, it is impossible to get this far without
 	// an identification packet.
 	let identification_packet = packets.get(0).unwrap();
 
-	// Skip identification header
-	let identification_packet_reader = &mut &identification_packet[8..];
-
-	properties.version = identification_packet_reader.read_u8()?;
-	properties.channels = identification_packet_reader.read_u8()?;
-
-	let pre_skip = identification_packet_reader.read_u16::<LittleEndian>()?;
-
-	properties.input_sample_rate = identification_packet_reader.read_u32::<LittleEndian>()?;
-
-	let _output_gain = identification_packet_reader.read_u16::<LittleEndian>()?;
+	let mut properties = read_identification_header(identification_packet, parse_mode)?;
+	let pre_skip = properties.pre_skip;
 
-	let channel_mapping_family = identification_packet_reader.read_u8()?;
-
-	// https://datatracker.ietf.org/doc/html/rfc7845.html#section-5.1.1
-	if (channel_mapping_family == 0 && properties.channels > 2)
-		|| (channel_mapping_family == 1 && properties.channels > 8)
-	{
-		decode_err!(@BAIL Opus, "Invalid channel count for mapping family");
-	}
-
-	properties.channel_mask =
-		ChannelMask::from_opus_channels(properties.channels).expect("Channel count is valid");
-
-	let last_page = find_last_page(data);
+	let last_page = find_last_page(data, max_last_page_search);
 	let file_length = data.seek(SeekFrom::End(0))?;
 
 	if let Ok(last_page) = last_page {
@@ -152,3 +150,120 @@ where
 
 	Ok(properties)
 }
+
+// Reads the fields of the identification header, which make up the properties that don't depend
+// on the rest of the stream
+pub(super) fn read_identification_header(
+	identification_packet: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<OpusProperties> {
+	let mut properties = OpusProperties::default();
+
+	// Skip identification header
+	let identification_packet_reader = &mut &identification_packet[8..];
+
+	properties.version = identification_packet_reader.read_u8()?;
+	properties.channels = identification_packet_reader.read_u8()?;
+
+	properties.pre_skip = identification_packet_reader.read_u16::<LittleEndian>()?;
+
+	properties.input_sample_rate = identification_packet_reader.read_u32::<LittleEndian>()?;
+
+	properties.output_gain = identification_packet_reader.read_i16::<LittleEndian>()?;
+
+	let channel_mapping_family = identification_packet_reader.read_u8()?;
+
+	// https://datatracker.ietf.org/doc/html/rfc7845.html#section-5.1.1
+	if (channel_mapping_family == 0 && properties.channels > 2)
+		|| (channel_mapping_family == 1 && properties.channels > 8)
+	{
+		decode_err!(@BAIL Opus, "Invalid channel count for mapping family");
+	}
+
+	// Mapping family 0 has an implicit channel mapping, with no stream counts or table
+	let channel_mapping_issue = if channel_mapping_family == 0 {
+		(properties.channels == 0).then_some("Stream declares zero channels")
+	} else {
+		match read_channel_mapping_table(identification_packet_reader, properties.channels) {
+			Ok((stream_count, coupled_stream_count, channel_mapping)) => verify_channel_mapping(
+				properties.channels,
+				stream_count,
+				coupled_stream_count,
+				&channel_mapping,
+			),
+			Err(e) => {
+				if parse_mode == ParsingMode::Strict {
+					return Err(e);
+				}
+
+				log::warn!(
+					"Opus: Identification header is missing its channel mapping table, the channel \
+					 layout may be incorrect"
+				);
+				None
+			},
+		}
+	};
+
+	if let Some(issue) = channel_mapping_issue {
+		if parse_mode == ParsingMode::Strict {
+			return Err(FileDecodingError::new(FileType::Opus, issue).into());
+		}
+
+		log::warn!("Opus: {issue}, the channel layout may be incorrect");
+	}
+
+	properties.channel_mask =
+		ChannelMask::from_opus_channels(properties.channels).unwrap_or_default();
+
+	Ok(properties)
+}
+
+// Reads the stream count, coupled stream count, and channel mapping of a non-zero mapping family
+fn read_channel_mapping_table(reader: &mut &[u8], channels: u8) -> Result<(u8, u8, Vec<u8>)> {
+	let stream_count = reader.read_u8()?;
+	let coupled_stream_count = reader.read_u8()?;
+
+	let mut channel_mapping = try_vec![0; channels as usize];
+	reader.read_exact(&mut channel_mapping)?;
+
+	Ok((stream_count, coupled_stream_count, channel_mapping))
+}
+
+// https://datatracker.ietf.org/doc/html/rfc7845.html#section-5.1.1
+//
+// Returns a description of the first inconsistency found, if any
+fn verify_channel_mapping(
+	channels: u8,
+	stream_count: u8,
+	coupled_stream_count: u8,
+	channel_mapping: &[u8],
+) -> Option<&'static str> {
+	if channels == 0 {
+		return Some("Stream declares zero channels");
+	}
+
+	if stream_count == 0 {
+		return Some("Stream declares zero streams");
+	}
+
+	if coupled_stream_count > stream_count {
+		return Some("Coupled stream count exceeds the total stream count");
+	}
+
+	// Each coupled stream decodes to two channels, every other stream to one
+	let decoded_channels = u16::from(stream_count) + u16::from(coupled_stream_count);
+	if decoded_channels > 255 {
+		return Some("Stream and coupled stream counts exceed 255 channels");
+	}
+
+	// An index of 255 denotes a silent channel
+	if channel_mapping
+		.iter()
+		.any(|&index| index != 255 && u16::from(index) >= decoded_channels)
+	{
+		return Some("Channel mapping references a nonexistent decoded channel");
+	}
+
+	None
+}
@@ -1,5 +1,8 @@
+use crate::config::ParsingMode;
 use crate::error::Result;
+use crate::macros::err;
 use crate::picture::{Picture, PictureInformation, PictureType};
+use crate::tag::TagType;
 
 /// Defines methods for interacting with an item storing OGG pictures
 ///
@@ -43,6 +46,72 @@ pub trait OggPictureStorage: private::Sealed {
 		Ok(ret)
 	}
 
+	/// Inserts a [`Picture`], verifying that its [`PictureType`] can be represented
+	///
+	/// This is the same as [`OggPictureStorage::insert_picture`], except that picture types not allowed in
+	/// Vorbis comments (see [`PictureType::is_allowed_in`]) are handled according to `parsing_mode`:
+	///
+	/// * [`ParsingMode::Strict`]: The picture is rejected
+	/// * [`ParsingMode::BestAttempt`] and [`ParsingMode::Relaxed`]: The picture type is downgraded to [`PictureType::Other`]
+	///
+	/// # Errors
+	///
+	/// * `parsing_mode` is [`ParsingMode::Strict`], and the picture type is not allowed
+	/// * See [`PictureInformation::from_picture`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParsingMode;
+	/// use lofty::ogg::{OggPictureStorage, VorbisComments};
+	/// use lofty::picture::{MimeType, Picture, PictureInformation, PictureType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = VorbisComments::default();
+	///
+	/// let picture = Picture::unchecked(Vec::new())
+	/// 	.pic_type(PictureType::Undefined(42))
+	/// 	.mime_type(MimeType::Png)
+	/// 	.build();
+	///
+	/// assert!(
+	/// 	tag.insert_picture_checked(
+	/// 		picture.clone(),
+	/// 		Some(PictureInformation::default()),
+	/// 		ParsingMode::Strict
+	/// 	)
+	/// 	.is_err()
+	/// );
+	///
+	/// tag.insert_picture_checked(
+	/// 	picture,
+	/// 	Some(PictureInformation::default()),
+	/// 	ParsingMode::Relaxed,
+	/// )?;
+	/// assert_eq!(tag.pictures()[0].0.pic_type(), PictureType::Other);
+	/// # Ok(()) }
+	/// ```
+	fn insert_picture_checked(
+		&mut self,
+		mut picture: Picture,
+		information: Option<PictureInformation>,
+		parsing_mode: ParsingMode,
+	) -> Result<Option<(Picture, PictureInformation)>> {
+		if !picture.pic_type.is_allowed_in(TagType::VorbisComments) {
+			if parsing_mode == ParsingMode::Strict {
+				err!(UnsupportedPicture);
+			}
+
+			log::warn!(
+				"Picture type {:?} is not allowed in Vorbis comments, using `PictureType::Other`",
+				picture.pic_type
+			);
+			picture.pic_type = PictureType::Other;
+		}
+
+		self.insert_picture(picture, information)
+	}
+
 	/// Removes a certain [`PictureType`]
 	fn remove_picture_type(&mut self, picture_type: PictureType) {
 		self.pictures_mut()
@@ -1,11 +1,13 @@
+use super::constants::VORBIS_COMMENT_HEAD;
 use super::tag::VorbisComments;
-use super::verify_signature;
+use super::{find_first_page, verify_signature};
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{ErrorKind, LoftyError, Result};
+use crate::file::tag_space::TagSpace;
 use crate::macros::{decode_err, err, parse_mode_choice};
 use crate::picture::{MimeType, Picture, PictureInformation, PictureType};
-use crate::tag::Accessor;
-use crate::util::text::{utf8_decode, utf8_decode_str, utf16_decode};
+use crate::tag::{Accessor, TagType};
+use crate::util::text::{parse_number, utf8_decode, utf8_decode_str, utf16_decode};
 
 use std::borrow::Cow;
 use std::io::{Read, Seek, SeekFrom};
@@ -80,10 +82,15 @@ where
 		err!(SizeMismatch);
 	}
 
+	if number_of_items > parse_options.max_comment_count {
+		err!(TooMuchData);
+	}
+
 	let mut tag = VorbisComments {
 		vendor,
 		items: Vec::with_capacity(number_of_items as usize),
 		pictures: Vec::new(),
+		padding_len: 0,
 	};
 
 	for _ in 0..number_of_items {
@@ -187,8 +194,8 @@ where
 
 						// try to parse as current/total
 						let mut value_split = value.splitn(2, '/');
-						let current: Option<u32> = value_split.next().and_then(|b| b.parse().ok());
-						let total: Option<u32> = value_split.next().and_then(|b| b.parse().ok());
+						let current: Option<u32> = value_split.next().and_then(parse_number);
+						let total: Option<u32> = value_split.next().and_then(parse_number);
 
 						match key.as_str() {
 							"TRACKNUMBER" => {
@@ -270,6 +277,9 @@ where
 {
 	debug_assert!(packets_to_read >= 2);
 
+	// Tools may prepend junk to the stream (such as an ID3v2 tag)
+	find_first_page(data, parse_options.max_junk_bytes)?;
+
 	// TODO: Would be nice if we didn't have to read just to seek and reread immediately
 	let start = data.stream_position()?;
 	let first_page_header = PageHeader::read(data)?;
@@ -297,7 +307,42 @@ where
 	metadata_packet = &metadata_packet[comment_sig.len()..];
 
 	let reader = &mut metadata_packet;
-	let tag = read_comments(reader, reader.len() as u64, parse_options)?;
+	let mut tag = read_comments(reader, reader.len() as u64, parse_options)?;
+
+	// Anything left in the packet is padding, outside of the Vorbis framing bit
+	//
+	// https://xiph.org/vorbis/doc/Vorbis_I_spec.html#x1-810005
+	tag.padding_len = if comment_sig == VORBIS_COMMENT_HEAD {
+		reader.len().saturating_sub(1)
+	} else {
+		reader.len()
+	};
 
 	Ok((Some(tag), first_page_header, packets))
 }
+
+// The space taken up by the comment header that can be reused when writing, excluding the vendor
+// string, which is always retained
+//
+// The comment header can only be written over in place if the identification header is on a page
+// of its own.
+pub(crate) fn comment_header_space(file_information: &OGGTags) -> Option<TagSpace> {
+	let (Some(tag), first_page_header, packets) = file_information else {
+		return None;
+	};
+
+	let identification_packet_len = packets.get(0)?.len();
+	let packet_ends_on_page = first_page_header
+		.segments()
+		.last()
+		.is_some_and(|&segment| segment < 255);
+	if !packet_ends_on_page || first_page_header.content_size() != identification_packet_len {
+		return None;
+	}
+
+	let comment_packet = packets.get(1)?;
+	Some(TagSpace {
+		tag_type: TagType::VorbisComments,
+		size: comment_packet.len().saturating_sub(tag.vendor.len()) as u64,
+	})
+}
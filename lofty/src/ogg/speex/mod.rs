@@ -3,6 +3,7 @@ pub(super) mod properties;
 use super::tag::VorbisComments;
 use crate::config::ParseOptions;
 use crate::error::Result;
+use crate::file::tag_space::TagSpace;
 use crate::ogg::constants::SPEEXHEADER;
 use properties::SpeexProperties;
 
@@ -21,23 +22,41 @@ pub struct SpeexFile {
 	pub(crate) vorbis_comments_tag: VorbisComments,
 	/// The file's audio properties
 	pub(crate) properties: SpeexProperties,
+	pub(crate) stream_offset: u64,
+	pub(crate) tag_space: Option<TagSpace>,
 }
 
 impl SpeexFile {
+	/// The offset of the stream's first page
+	///
+	/// This is `0`, unless the stream was preceded by junk. See [`ParseOptions::max_junk_bytes()`].
+	pub fn stream_offset(&self) -> u64 {
+		self.stream_offset
+	}
+
 	fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<Self>
 	where
 		R: Read + Seek,
 	{
 		let file_information = super::read::read_from(reader, SPEEXHEADER, &[], 2, parse_options)?;
 
+		let tag_space = super::read::comment_header_space(&file_information);
+
 		Ok(Self {
 			properties: if parse_options.read_properties {
-				properties::read_properties(reader, &file_information.1, &file_information.2)?
+				properties::read_properties(
+					reader,
+					&file_information.1,
+					&file_information.2,
+					parse_options.max_last_page_search,
+				)?
 			} else {
 				SpeexProperties::default()
 			},
 			// A metadata packet is mandatory in Speex
 			vorbis_comments_tag: file_information.0.unwrap_or_default(),
+			stream_offset: file_information.1.start,
+			tag_space,
 		})
 	}
 }
@@ -10,6 +10,10 @@ use std::time::Duration;
 use byteorder::{LittleEndian, ReadBytesExt};
 use ogg_pager::{Packets, PageHeader};
 
+// A sanity limit for the number of extra headers to read. A corrupt count would otherwise have
+// us reading the entire stream into memory.
+const MAX_EXTRA_HEADERS: u32 = 64;
+
 /// A Speex file's audio properties
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 #[non_exhaustive]
@@ -35,6 +39,8 @@ impl From<SpeexProperties> for FileProperties {
 			bit_depth: None,
 			channels: Some(input.channels),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -90,6 +96,7 @@ pub(in crate::ogg) fn read_properties<R>(
 	data: &mut R,
 	first_page_header: &PageHeader,
 	packets: &Packets,
+	max_last_page_search: u64,
 ) -> Result<SpeexProperties>
 where
 	R: Read + Seek,
@@ -141,11 +148,32 @@ where
 
 	properties.vbr = identification_packet_reader.read_u32::<LittleEndian>()? == 1;
 
-	let last_page = find_last_page(data);
+	// The number of frames per packet
+	let _frames_per_packet = identification_packet_reader.read_u32::<LittleEndian>()?;
+
+	// The number of additional headers following the comment header
+	let extra_headers = identification_packet_reader.read_u32::<LittleEndian>()?;
+
+	// The stream length is the entire file minus the two mandatory metadata packets, and any
+	// extra headers that follow them
+	let mut metadata_packets_length = packets.iter().take(2).map(<[u8]>::len).sum::<usize>();
+
+	if extra_headers > MAX_EXTRA_HEADERS {
+		log::warn!("Speex: Stream declares {extra_headers} extra headers, ignoring");
+	} else if extra_headers > 0 {
+		// The reader is positioned directly after the comment header, which must end its page
+		match Packets::read_count(data, extra_headers as isize) {
+			Ok(extra_header_packets) if extra_header_packets.len() == extra_headers as usize => {
+				metadata_packets_length +=
+					extra_header_packets.iter().map(<[u8]>::len).sum::<usize>();
+			},
+			_ => log::warn!("Speex: Unable to read {extra_headers} extra header(s), ignoring"),
+		}
+	}
+
+	let last_page = find_last_page(data, max_last_page_search);
 	let file_length = data.seek(SeekFrom::End(0))?;
 
-	// The stream length is the entire file minus the two mandatory metadata packets
-	let metadata_packets_length = packets.iter().take(2).map(<[u8]>::len).sum::<usize>();
 	let stream_length = file_length.saturating_sub(metadata_packets_length as u64);
 
 	// This is used for bitrate calculation, it should be the length in
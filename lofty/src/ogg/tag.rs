@@ -13,6 +13,8 @@ use crate::tag::{
 };
 use crate::util::flag_item;
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::musical::is_valid_initial_key;
+use crate::util::text::parse_number;
 
 use std::borrow::Cow;
 use std::io::Write;
@@ -66,7 +68,7 @@ macro_rules! impl_accessor {
 /// When converting [`Picture`]s, they will first be passed through [`PictureInformation::from_picture()`].
 /// If the information is available, it will be used. Otherwise, the picture will be stored with zeroed out
 /// [`PictureInformation`].
-#[derive(Default, PartialEq, Eq, Debug, Clone)]
+#[derive(Default, Eq, Debug, Clone)]
 #[tag(
 	description = "Vorbis comments",
 	supported_formats(Flac, Opus, Speex, Vorbis)
@@ -78,6 +80,15 @@ pub struct VorbisComments {
 	pub(crate) items: Vec<(String, String)>,
 	/// A collection of all pictures
 	pub(crate) pictures: Vec<(Picture, PictureInformation)>,
+	/// The number of bytes following the comments in the original comment header
+	pub(crate) padding_len: usize,
+}
+
+impl PartialEq for VorbisComments {
+	fn eq(&self, other: &Self) -> bool {
+		// The padding belongs to the file the tag was read from, not the tag itself
+		self.vendor == other.vendor && self.items == other.items && self.pictures == other.pictures
+	}
 }
 
 impl VorbisComments {
@@ -125,6 +136,29 @@ impl VorbisComments {
 		self.vendor = vendor
 	}
 
+	/// Returns the size of the padding following the comments, in bytes
+	///
+	/// This is the number of bytes left in the comment header after the last comment, when the tag was
+	/// read from an OGG file. A tag with no items is not necessarily byte-minimal, as it may still
+	/// carry padding.
+	///
+	/// NOTES:
+	///
+	/// * This will always be `0` for tags that were not read from an OGG file (e.g. FLAC or newly created tags)
+	/// * Padding is **not** preserved when writing, so writing a tag will remove it
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisComments;
+	///
+	/// let vorbis_comments = VorbisComments::new();
+	/// assert_eq!(vorbis_comments.padding_len(), 0);
+	/// ```
+	pub fn padding_len(&self) -> usize {
+		self.padding_len
+	}
+
 	/// Get all items
 	///
 	/// Returns an [`Iterator`] over the stored key/value pairs.
@@ -146,6 +180,35 @@ impl VorbisComments {
 		self.items.iter().map(|(k, v)| (k.as_str(), v.as_str()))
 	}
 
+	/// Get all items mutably
+	///
+	/// Returns an [`Iterator`] over the stored key/value pairs, allowing the values to be edited in place.
+	///
+	/// NOTE: The keys cannot be modified through this iterator.
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisComments;
+	///
+	/// let mut vorbis_comments = VorbisComments::default();
+	///
+	/// vorbis_comments.push(String::from("ARTIST"), String::from("Foo artist"));
+	/// vorbis_comments.push(String::from("TITLE"), String::from("Bar title"));
+	///
+	/// for (key, value) in vorbis_comments.iter_mut() {
+	/// 	if key == "ARTIST" {
+	/// 		value.push_str(" (Remastered)");
+	/// 	}
+	/// }
+	///
+	/// let mut items = vorbis_comments.items();
+	///
+	/// assert_eq!(items.next(), Some(("ARTIST", "Foo artist (Remastered)")));
+	/// assert_eq!(items.next(), Some(("TITLE", "Bar title")));
+	/// ```
+	pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = (&str, &mut String)> {
+		self.items.iter_mut().map(|(k, v)| (k.as_str(), v))
+	}
+
 	/// Consume all items
 	///
 	/// Returns an [`Iterator`] with the stored key/value pairs.
@@ -341,7 +404,7 @@ impl Accessor for VorbisComments {
 			.get("TRACKNUMBER")
 			.map_or_else(|| self.get("TRACKNUM"), Some)
 		{
-			return item.parse::<u32>().ok();
+			return parse_number(item);
 		}
 
 		None
@@ -362,7 +425,7 @@ impl Accessor for VorbisComments {
 			.get("TRACKTOTAL")
 			.map_or_else(|| self.get("TOTALTRACKS"), Some)
 		{
-			return item.parse::<u32>().ok();
+			return parse_number(item);
 		}
 
 		None
@@ -380,7 +443,7 @@ impl Accessor for VorbisComments {
 
 	fn disk(&self) -> Option<u32> {
 		if let Some(item) = self.get("DISCNUMBER") {
-			return item.parse::<u32>().ok();
+			return parse_number(item);
 		}
 
 		None
@@ -399,7 +462,7 @@ impl Accessor for VorbisComments {
 			.get("DISCTOTAL")
 			.map_or_else(|| self.get("TOTALDISCS"), Some)
 		{
-			return item.parse::<u32>().ok();
+			return parse_number(item);
 		}
 
 		None
@@ -582,8 +645,10 @@ impl MergeTag for SplitTagRemainder {
 			merged.vendor = val;
 		}
 
+		let has_bpm = tag.get(ItemKey::Bpm).is_some();
+
 		for item in tag.items {
-			let item_key = item.item_key;
+			let mut item_key = item.item_key;
 			let item_value = item.item_value;
 
 			// Discard binary items, as they are not allowed in Vorbis comments
@@ -591,8 +656,18 @@ impl MergeTag for SplitTagRemainder {
 				continue;
 			};
 
+			// There is no integer BPM field, but it can take the place of a missing `BPM`
+			if item_key == ItemKey::IntegerBpm && !has_bpm {
+				item_key = ItemKey::Bpm;
+			}
+
+			if item_key == ItemKey::InitialKey && !is_valid_initial_key(&val) {
+				log::warn!("Initial key is not a musical key: {val}, discarding");
+				continue;
+			}
+
 			// Normalize flag items
-			if item_key == ItemKey::FlagCompilation {
+			if matches!(item_key, ItemKey::FlagCompilation | ItemKey::FlagPodcast) {
 				let Some(flag) = flag_item(&val) else {
 					continue;
 				};
@@ -665,7 +740,13 @@ where
 
 		// FLAC has its own special writing needs :)
 		if file_type == FileType::Flac {
-			return crate::flac::write::write_to_inner(file, self, write_options);
+			return crate::flac::write::write_to_inner(
+				file,
+				self,
+				&[],
+				crate::flac::write::BlockReplacement::Tags,
+				write_options,
+			);
 		}
 
 		let (format, header_packet_count) = OGGFormat::from_filetype(file_type);
@@ -676,9 +757,10 @@ where
 	pub(crate) fn dump_to<W: Write>(
 		&mut self,
 		writer: &mut W,
-		_write_options: WriteOptions,
+		write_options: WriteOptions,
 	) -> Result<()> {
-		let metadata_packet = super::write::create_metadata_packet(self, &[], false)?;
+		let metadata_packet =
+			super::write::create_metadata_packet(self, &[], false, write_options)?;
 		writer.write_all(&metadata_packet)?;
 		Ok(())
 	}
@@ -693,11 +775,26 @@ pub(crate) fn create_vorbis_comments_ref(
 ) {
 	let vendor = tag.get_string(ItemKey::EncoderSoftware).unwrap_or("");
 
-	let items = tag.items.iter().filter_map(|i| match i.value() {
-		ItemValue::Text(val) | ItemValue::Locator(val) => i
-			.key()
-			.map_key(TagType::VorbisComments)
-			.map(|key| (key, val.as_str())),
+	let has_bpm = tag.get(ItemKey::Bpm).is_some();
+
+	let items = tag.items.iter().filter_map(move |i| match i.value() {
+		ItemValue::Text(val) | ItemValue::Locator(val) => {
+			let mut item_key = i.key();
+
+			// An integer BPM can take the place of a missing `BPM`
+			if item_key == ItemKey::IntegerBpm && !has_bpm {
+				item_key = ItemKey::Bpm;
+			}
+
+			if item_key == ItemKey::InitialKey && !is_valid_initial_key(val) {
+				log::warn!("Initial key is not a musical key: {val}, discarding");
+				return None;
+			}
+
+			item_key
+				.map_key(TagType::VorbisComments)
+				.map(|key| (key, val.as_str()))
+		},
 		_ => None,
 	});
 
@@ -711,6 +808,7 @@ pub(crate) fn create_vorbis_comments_ref(
 #[cfg(test)]
 mod tests {
 	use crate::config::{ParseOptions, ParsingMode, WriteOptions};
+	use crate::error::ErrorKind;
 	use crate::ogg::{OggPictureStorage, VorbisComments};
 	use crate::picture::{MimeType, Picture, PictureType};
 	use crate::prelude::*;
@@ -903,6 +1001,65 @@ mod tests {
 		}
 	}
 
+	#[test_log::test]
+	fn invalid_bpm_and_initial_key() {
+		let mut vorbis_comments = VorbisComments::default();
+		vorbis_comments.insert(String::from("BPM"), String::from("fast"));
+		vorbis_comments.insert(String::from("INITIALKEY"), String::from("8A"));
+
+		let mut tag_bytes = Vec::new();
+		vorbis_comments
+			.dump_to(&mut tag_bytes, WriteOptions::default())
+			.unwrap();
+
+		let read = |parsing_mode| {
+			crate::ogg::read::read_comments(
+				&mut Cursor::new(&tag_bytes),
+				tag_bytes.len() as u64,
+				ParseOptions::new().parsing_mode(parsing_mode),
+			)
+		};
+
+		// The values are kept as is, regardless of the parsing mode
+		for parsing_mode in [
+			ParsingMode::Strict,
+			ParsingMode::BestAttempt,
+			ParsingMode::Relaxed,
+		] {
+			let read = read(parsing_mode).unwrap();
+			assert_eq!(read.get("BPM"), Some("fast"));
+			assert_eq!(read.get("INITIALKEY"), Some("8A"));
+		}
+
+		// Only invalid keys are discarded when converting
+		let mut tag: Tag = read(ParsingMode::BestAttempt).unwrap().into();
+		assert_eq!(
+			VorbisComments::from(tag.clone()).get("INITIALKEY"),
+			Some("8A")
+		);
+
+		tag.insert_text(ItemKey::InitialKey, String::from("H minor"));
+		assert_eq!(VorbisComments::from(tag).get("INITIALKEY"), None);
+	}
+
+	#[test_log::test]
+	fn podcast_roundtrip() {
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.insert_text(ItemKey::FlagPodcast, "true".to_owned());
+		tag.insert_text(ItemKey::PodcastGlobalUniqueId, "episode-guid".to_owned());
+
+		let vorbis_comments: VorbisComments = tag.into();
+		assert_eq!(vorbis_comments.get("PODCAST"), Some("1"));
+		assert_eq!(vorbis_comments.get("PODCASTID"), Some("episode-guid"));
+
+		let tag = Tag::from(vorbis_comments);
+		assert_eq!(tag.get_string(ItemKey::FlagPodcast), Some("1"));
+		assert_eq!(
+			tag.get_string(ItemKey::PodcastGlobalUniqueId),
+			Some("episode-guid")
+		);
+	}
+
 	#[test_log::test]
 	fn skip_reading_cover_art() {
 		let p = Picture::unchecked(std::iter::repeat_n(0, 50).collect::<Vec<u8>>())
@@ -932,6 +1089,41 @@ mod tests {
 		assert!(tag.artist().is_some());
 	}
 
+	#[test_log::test]
+	fn max_comment_count() {
+		fn comments_with_count(count: u32) -> Vec<u8> {
+			let mut bytes = Vec::new();
+			bytes.extend(0_u32.to_le_bytes()); // Vendor
+			bytes.extend(count.to_le_bytes());
+			for _ in 0..count {
+				bytes.extend(5_u32.to_le_bytes());
+				bytes.extend(b"A=foo");
+			}
+
+			bytes
+		}
+
+		fn read(bytes: &[u8], parse_options: ParseOptions) -> crate::error::Result<VorbisComments> {
+			crate::ogg::read::read_comments(&mut &bytes[..], bytes.len() as u64, parse_options)
+		}
+
+		// An absurd number of fields, though each of them actually exists
+		let bytes = comments_with_count(ParseOptions::DEFAULT_MAX_COMMENT_COUNT + 1);
+		let err = read(
+			&bytes,
+			ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+		)
+		.unwrap_err();
+		assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+		// Custom limit
+		let bytes = comments_with_count(10);
+		assert!(read(&bytes, ParseOptions::new().max_comment_count(9)).is_err());
+
+		let tag = read(&bytes, ParseOptions::new().max_comment_count(10)).unwrap();
+		assert_eq!(tag.len(), 10);
+	}
+
 	// case TRACKNUMBER=01/05 disable implicit_conversions
 	#[test_log::test]
 	fn issue_540_disable_implicit_conversions() {
@@ -1064,4 +1256,81 @@ mod tests {
 		assert_eq!(tag.get("DISCNUMBER"), Some("01"));
 		assert_eq!(tag.get("DISCTOTAL"), Some("05"));
 	}
+
+	#[test_log::test]
+	fn iter_mut_preserves_order() {
+		let mut tag = VorbisComments::default();
+		tag.push(String::from("ARTIST"), String::from("Foo artist"));
+		tag.push(String::from("TITLE"), String::from("Bar title"));
+		tag.push(String::from("ARTIST"), String::from("Baz artist"));
+
+		for (key, value) in tag.iter_mut() {
+			if key == "TITLE" {
+				*value = String::from("Qux title");
+			}
+		}
+
+		let items = tag.items().collect::<Vec<_>>();
+		assert_eq!(
+			items,
+			vec![
+				("ARTIST", "Foo artist"),
+				("TITLE", "Qux title"),
+				("ARTIST", "Baz artist"),
+			]
+		);
+	}
+
+	#[test_log::test]
+	fn opus_comment_header_padding() {
+		const PADDING_LEN: usize = 512;
+
+		let file = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+		let original =
+			crate::ogg::OpusFile::read_from(&mut Cursor::new(&file), ParseOptions::new()).unwrap();
+		assert_eq!(original.vorbis_comments().padding_len(), 0);
+
+		// Append some padding to the comment header
+		let mut reader = Cursor::new(&file);
+		let stream_serial = ogg_pager::PageHeader::read(&mut reader)
+			.unwrap()
+			.stream_serial;
+		reader.set_position(0);
+
+		let mut packets = ogg_pager::Packets::read_count(&mut reader, 2).unwrap();
+		let mut comment_header = packets.get(1).unwrap().to_vec();
+		comment_header.extend([0; PADDING_LEN]);
+		packets.set(1, comment_header);
+
+		let mut padded_file = Vec::new();
+		let pages_written = packets
+			.write_to(
+				&mut padded_file,
+				stream_serial,
+				0,
+				ogg_pager::CONTAINS_FIRST_PAGE_OF_BITSTREAM,
+			)
+			.unwrap() as u32;
+
+		let mut idx = 0;
+		while let Ok(mut page) = ogg_pager::Page::read(&mut reader) {
+			page.header_mut().sequence_number = pages_written + idx;
+			page.gen_crc();
+			padded_file.extend(page.as_bytes());
+
+			idx += 1;
+		}
+
+		let padded = crate::ogg::OpusFile::read_from(
+			&mut Cursor::new(padded_file),
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		)
+		.unwrap();
+
+		assert_eq!(padded.vorbis_comments().padding_len(), PADDING_LEN);
+
+		// The padding doesn't affect equality
+		assert_eq!(padded.vorbis_comments(), original.vorbis_comments());
+	}
 }
@@ -0,0 +1,110 @@
+use crate::error::Result;
+use crate::macros::decode_err;
+use crate::ogg::constants::VORBIS_IDENT_HEAD;
+use crate::util::io::SeekStreamLen;
+
+use std::io::{Read, Seek, SeekFrom};
+
+use ogg_pager::{Page, PageHeader};
+
+/// Packet fragmentation information for an OGG Vorbis stream
+///
+/// Packets that don't fit on a single page are continued on the next page. A stream with many small
+/// pages will split most of its packets, which wastes space on page headers and makes seeking slower.
+///
+/// See [`VorbisFile::fragmentation_stats`](crate::ogg::VorbisFile::fragmentation_stats)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct FragmentationStats {
+	pub(crate) page_count: u64,
+	pub(crate) packet_count: u64,
+	pub(crate) spanning_packet_count: u64,
+	pub(crate) total_packet_size: u64,
+}
+
+impl FragmentationStats {
+	/// The number of pages in the stream
+	pub fn page_count(&self) -> u64 {
+		self.page_count
+	}
+
+	/// The number of complete packets in the stream, including the header packets
+	pub fn packet_count(&self) -> u64 {
+		self.packet_count
+	}
+
+	/// The number of packets that span more than one page
+	pub fn spanning_packet_count(&self) -> u64 {
+		self.spanning_packet_count
+	}
+
+	/// The average size of a packet in bytes
+	///
+	/// This will be `0.0` if there are no complete packets.
+	pub fn average_packet_size(&self) -> f64 {
+		if self.packet_count == 0 {
+			return 0.0;
+		}
+
+		self.total_packet_size as f64 / self.packet_count as f64
+	}
+}
+
+pub(super) fn read_fragmentation_stats<R>(reader: &mut R) -> Result<FragmentationStats>
+where
+	R: Read + Seek,
+{
+	let stream_len = reader.stream_len_hack()?;
+
+	let first_page = Page::read(reader)?;
+	if !first_page.content().starts_with(VORBIS_IDENT_HEAD) {
+		decode_err!(@BAIL Vorbis, "Stream does not start with an identification header");
+	}
+
+	let stream_serial = first_page.header().stream_serial;
+
+	let mut stats = FragmentationStats::default();
+
+	let mut current_packet_size = 0_u64;
+	// The page the current packet started on, if a packet is in progress
+	let mut current_packet_start = None;
+
+	let mut header = first_page.header().clone();
+	loop {
+		if header.stream_serial == stream_serial {
+			let page_index = stats.page_count;
+			stats.page_count += 1;
+
+			for &lacing_value in header.segments() {
+				let packet_start = *current_packet_start.get_or_insert(page_index);
+				current_packet_size += u64::from(lacing_value);
+
+				// A lacing value < 255 marks the end of a packet
+				if lacing_value < 255 {
+					stats.packet_count += 1;
+					stats.total_packet_size += current_packet_size;
+					if packet_start != page_index {
+						stats.spanning_packet_count += 1;
+					}
+
+					current_packet_size = 0;
+					current_packet_start = None;
+				}
+			}
+		} else {
+			log::debug!("Skipping page belonging to stream {}", header.stream_serial);
+		}
+
+		if reader.stream_position()? >= stream_len {
+			break;
+		}
+
+		header = PageHeader::read(reader)?;
+		reader.seek(SeekFrom::Current(header.content_size() as i64))?;
+	}
+
+	if current_packet_start.is_some() {
+		log::warn!("Stream ends with an incomplete packet, it will not be counted");
+	}
+
+	Ok(stats)
+}
@@ -1,13 +1,20 @@
+pub(crate) mod fragmentation;
 pub(super) mod properties;
 
 use super::find_last_page;
 use super::tag::VorbisComments;
 use crate::config::ParseOptions;
 use crate::error::Result;
-use crate::ogg::constants::{VORBIS_COMMENT_HEAD, VORBIS_IDENT_HEAD};
+use crate::file::tag_space::TagSpace;
+use crate::macros::decode_err;
+use crate::ogg::FragmentationStats;
+use crate::ogg::constants::{VORBIS_COMMENT_HEAD, VORBIS_IDENT_HEAD, VORBIS_SETUP_HEAD};
+use crate::util::io::SeekStreamLen;
 use properties::VorbisProperties;
 
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
+
+use ogg_pager::{Packets, Page, PageHeader};
 
 use lofty_attr::LoftyFile;
 
@@ -22,9 +29,242 @@ pub struct VorbisFile {
 	pub(crate) vorbis_comments_tag: VorbisComments,
 	/// The file's audio properties
 	pub(crate) properties: VorbisProperties,
+	pub(crate) stream_offset: u64,
+	pub(crate) tag_space: Option<TagSpace>,
 }
 
 impl VorbisFile {
+	/// The offset of the stream's first page
+	///
+	/// This is `0`, unless the stream was preceded by junk. See [`ParseOptions::max_junk_bytes()`].
+	pub fn stream_offset(&self) -> u64 {
+		self.stream_offset
+	}
+
+	/// Lists the byte offset and granule position of every audio page in a Vorbis stream
+	///
+	/// The header pages are skipped. For Vorbis, the granule position of a page is the total number
+	/// of samples decoded by the end of the last packet finishing on it. A granule position of `-1`
+	/// means no packet finishes on the page, and it can't be used for seeking.
+	///
+	/// NOTE: Pages belonging to other logical streams are skipped.
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with a Vorbis identification header
+	/// * A page header is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.ogg";
+	/// let mut reader = std::fs::File::open(path)?;
+	///
+	/// let granules = VorbisFile::page_granules(&mut reader)?;
+	/// for (offset, granule_position) in granules {
+	/// 	println!("Page at {offset} ends at sample {granule_position}");
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn page_granules<R>(reader: &mut R) -> Result<Vec<(u64, i64)>>
+	where
+		R: Read + Seek,
+	{
+		// The identification, comment, and setup headers
+		const HEADER_PACKET_COUNT: usize = 3;
+
+		let stream_len = reader.stream_len_hack()?;
+
+		let first_page = Page::read(reader)?;
+		if !first_page.content().starts_with(VORBIS_IDENT_HEAD) {
+			decode_err!(@BAIL Vorbis, "Stream does not start with an identification header");
+		}
+
+		let stream_serial = first_page.header().stream_serial;
+
+		let mut granules = Vec::new();
+		let mut packet_count = 0;
+		let mut header = first_page.header().clone();
+		loop {
+			if header.stream_serial == stream_serial {
+				if packet_count >= HEADER_PACKET_COUNT {
+					// -1 is stored as `u64::MAX`
+					granules.push((header.start, header.abgp as i64));
+				}
+
+				// A lacing value < 255 marks the end of a packet
+				packet_count += header
+					.segments()
+					.iter()
+					.filter(|&&lacing_value| lacing_value < 255)
+					.count();
+			} else {
+				log::debug!("Skipping page belonging to stream {}", header.stream_serial);
+			}
+
+			if reader.stream_position()? >= stream_len {
+				break;
+			}
+
+			header = PageHeader::read(reader)?;
+			reader.seek(SeekFrom::Current(header.content_size() as i64))?;
+		}
+
+		Ok(granules)
+	}
+
+	/// Counts the packets in a Vorbis stream that span multiple pages
+	///
+	/// NOTE: This requires walking every page header in the stream.
+	///
+	/// The header packets are included. Pages belonging to other logical streams are skipped.
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with a Vorbis identification header
+	/// * A page header is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.ogg";
+	/// let mut reader = std::fs::File::open(path)?;
+	///
+	/// let stats = VorbisFile::fragmentation_stats(&mut reader)?;
+	/// println!(
+	/// 	"{} of {} packets span multiple pages, averaging {} bytes per packet",
+	/// 	stats.spanning_packet_count(),
+	/// 	stats.packet_count(),
+	/// 	stats.average_packet_size()
+	/// );
+	/// # Ok(()) }
+	/// ```
+	pub fn fragmentation_stats<R>(reader: &mut R) -> Result<FragmentationStats>
+	where
+		R: Read + Seek,
+	{
+		fragmentation::read_fragmentation_stats(reader)
+	}
+
+	/// Reads the setup header packet of a Vorbis stream
+	///
+	/// The setup header holds the codebooks needed to decode the stream, and is commonly large
+	/// enough to span multiple pages. It is reassembled and returned verbatim, including its
+	/// packet type and signature.
+	///
+	/// This is useful for remuxing, where the header packets need to be copied as-is (Ex. when
+	/// building a Matroska `CodecPrivate`).
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with a Vorbis identification header
+	/// * The third packet isn't a Vorbis setup header
+	/// * A page header is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.ogg";
+	/// let mut reader = std::fs::File::open(path)?;
+	///
+	/// let setup_header = VorbisFile::setup_header(&mut reader)?;
+	/// assert!(setup_header.starts_with(b"\x05vorbis"));
+	/// # Ok(()) }
+	/// ```
+	pub fn setup_header<R>(reader: &mut R) -> Result<Vec<u8>>
+	where
+		R: Read + Seek,
+	{
+		// The identification, comment, and setup headers
+		let packets = Packets::read_count(reader, 3)?;
+
+		let Some(identification_header) = packets.get(0) else {
+			decode_err!(@BAIL Vorbis, "Expected identification packet");
+		};
+
+		if !identification_header.starts_with(VORBIS_IDENT_HEAD) {
+			decode_err!(@BAIL Vorbis, "Stream does not start with an identification header");
+		}
+
+		match packets.get(2) {
+			Some(setup_header) if setup_header.starts_with(VORBIS_SETUP_HEAD) => {
+				Ok(setup_header.to_vec())
+			},
+			_ => decode_err!(@BAIL Vorbis, "Expected setup header packet"),
+		}
+	}
+
+	/// Verifies the CRC checksum of every page in a Vorbis stream
+	///
+	/// Each page is read once, and its checksum is recomputed and compared against the stored value.
+	/// This includes pages belonging to other logical streams.
+	///
+	/// This returns the offsets of the pages with bad checksums, so an empty list means the stream is valid.
+	/// By default, this stops at the first bad page. With `report_all`, every page is checked.
+	///
+	/// # Errors
+	///
+	/// * The stream doesn't start with a Vorbis identification header
+	/// * A page header is invalid
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.ogg";
+	/// let mut reader = std::fs::File::open(path)?;
+	///
+	/// let bad_pages = VorbisFile::validate_all_crcs(&mut reader, false)?;
+	/// assert!(bad_pages.is_empty());
+	/// # Ok(()) }
+	/// ```
+	pub fn validate_all_crcs<R>(reader: &mut R, report_all: bool) -> Result<Vec<u64>>
+	where
+		R: Read + Seek,
+	{
+		let stream_len = reader.stream_len_hack()?;
+
+		let mut page = Page::read(reader)?;
+		if !page.content().starts_with(VORBIS_IDENT_HEAD) {
+			decode_err!(@BAIL Vorbis, "Stream does not start with an identification header");
+		}
+
+		let mut bad_pages = Vec::new();
+		loop {
+			let stored_checksum = page.header().checksum();
+			page.gen_crc();
+
+			if page.header().checksum() != stored_checksum {
+				let offset = page.header().start;
+				log::warn!("Page at offset {offset} has an invalid checksum");
+
+				bad_pages.push(offset);
+				if !report_all {
+					break;
+				}
+			}
+
+			if reader.stream_position()? >= stream_len {
+				break;
+			}
+
+			page = Page::read(reader)?;
+		}
+
+		Ok(bad_pages)
+	}
+
 	fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<Self>
 	where
 		R: Read + Seek,
@@ -37,14 +277,23 @@ impl VorbisFile {
 			parse_options,
 		)?;
 
+		let tag_space = super::read::comment_header_space(&file_information);
+
 		Ok(Self {
 			properties: if parse_options.read_properties {
-				properties::read_properties(reader, &file_information.1, &file_information.2)?
+				properties::read_properties(
+					reader,
+					&file_information.1,
+					&file_information.2,
+					parse_options.max_last_page_search,
+				)?
 			} else {
 				VorbisProperties::default()
 			},
 			// A metadata packet is mandatory in OGG Vorbis
 			vorbis_comments_tag: file_information.0.unwrap_or_default(),
+			stream_offset: file_information.1.start,
+			tag_space,
 		})
 	}
 }
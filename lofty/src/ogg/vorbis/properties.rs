@@ -34,6 +34,8 @@ impl From<VorbisProperties> for FileProperties {
 			bit_depth: None,
 			channels: Some(input.channels),
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -89,6 +91,7 @@ pub(in crate::ogg) fn read_properties<R>(
 	data: &mut R,
 	first_page_header: &PageHeader,
 	packets: &Packets,
+	max_last_page_search: u64,
 ) -> Result<VorbisProperties>
 where
 	R: Read + Seek,
@@ -110,7 +113,7 @@ where
 	properties.bitrate_nominal = first_page_content.read_i32::<LittleEndian>()?;
 	properties.bitrate_minimum = first_page_content.read_i32::<LittleEndian>()?;
 
-	let last_page = find_last_page(data);
+	let last_page = find_last_page(data, max_last_page_search);
 	let file_length = data.seek(SeekFrom::End(0))?;
 
 	// This is used for bitrate calculation, it should be the length in
@@ -1,19 +1,22 @@
-use super::verify_signature;
-use crate::config::WriteOptions;
-use crate::error::{LoftyError, Result};
+use super::{find_first_page, verify_signature};
+use crate::config::{ParseOptions, WriteOptions};
+use crate::error::{FileEncodingError, LoftyError, Result};
 use crate::file::FileType;
 use crate::macros::{decode_err, err, try_vec};
-use crate::ogg::constants::{OPUSTAGS, VORBIS_COMMENT_HEAD};
-use crate::ogg::tag::{VorbisCommentsRef, create_vorbis_comments_ref};
+use crate::ogg::constants::{
+	OPUSHEAD, OPUSTAGS, SPEEXHEADER, VORBIS_COMMENT_HEAD, VORBIS_IDENT_HEAD,
+};
+use crate::ogg::tag::{VorbisComments, VorbisCommentsRef, create_vorbis_comments_ref};
 use crate::picture::{Picture, PictureInformation};
 use crate::tag::{Tag, TagType};
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::borrow::Cow;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use ogg_pager::{CONTAINS_FIRST_PAGE_OF_BITSTREAM, Packets, Page, PageHeader};
+use ogg_pager::{CONTAINS_FIRST_PAGE_OF_BITSTREAM, PAGE_HEADER_SIZE, Packets, Page, PageHeader};
 
 #[derive(PartialEq, Copy, Clone)]
 pub(crate) enum OGGFormat {
@@ -80,7 +83,7 @@ pub(super) fn write<'a, F, II, IP>(
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
 	format: OGGFormat,
 	header_packet_count: isize,
-	_write_options: WriteOptions,
+	write_options: WriteOptions,
 ) -> Result<()>
 where
 	F: FileLike,
@@ -88,82 +91,458 @@ where
 	LoftyError: From<<F as Length>::Error>,
 	II: Iterator<Item = (&'a str, &'a str)>,
 	IP: Iterator<Item = (&'a Picture, PictureInformation)>,
+{
+	let stream_start = file.stream_position()?;
+
+	// A comment header that fits in the original one is written over it, so the audio isn't moved
+	let new_comment_packet = match read_header_pages_in_place(file) {
+		Ok((comment_pages, comment_packet, _)) => {
+			let new_comment_packet =
+				create_comment_packet(tag, format, Some(comment_packet.as_slice()), write_options)?;
+			if new_comment_packet.len() <= comment_packet.len() {
+				log::debug!("Writing OGG comment header in place");
+				write_comment_pages(
+					file,
+					comment_pages,
+					new_comment_packet,
+					comment_packet.len(),
+				)?;
+				return Ok(());
+			}
+
+			Some(new_comment_packet)
+		},
+		Err(e) => {
+			log::debug!("Unable to write the OGG comment header in place: {e}");
+			None
+		},
+	};
+
+	file.seek(SeekFrom::Start(stream_start))?;
+
+	let mut output = Vec::new();
+	rewrite_comment_header_inner(
+		file,
+		&mut output,
+		format,
+		header_packet_count,
+		|comment_packet| match new_comment_packet {
+			Some(new_comment_packet) => Ok(new_comment_packet),
+			None => create_comment_packet(tag, format, Some(comment_packet), write_options),
+		},
+	)?;
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&output)?;
+
+	Ok(())
+}
+
+/// Replace the comment header of an OGG stream
+///
+/// This reads an OGG Opus, Vorbis, or Speex stream from `src`, and writes it to `out` with its comment
+/// header replaced by `new_comments`. Only the header packets are repaginated.
+///
+/// The stream may be preceded by up to [`ParseOptions::DEFAULT_MAX_JUNK_BYTES`] of junk, which is
+/// copied to `out` as is.
+///
+/// If `new_vendor` is `None`, the stream's existing vendor string is retained, and the vendor
+/// of `new_comments` is ignored. This is what happens when saving a [`VorbisComments`] normally.
+///
+/// The audio pages are copied as is, unless the new comment header spans a different number of pages
+/// than the original. In that case, every following page needs its sequence number and checksum
+/// rewritten.
+///
+/// Returns `true` if the audio pages had to be rewritten.
+///
+/// # Errors
+///
+/// * `src` does not contain an OGG Opus, Vorbis, or Speex stream
+/// * The comment header is malformed
+/// * The new comments are too large to write
+/// * [`std::io::Error`]
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::ogg::{VorbisComments, rewrite_comment_header};
+/// use lofty::tag::Accessor;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let mut src = std::fs::File::open("tests/files/assets/minimal/full_test.opus")?;
+/// let mut comments = VorbisComments::new();
+/// comments.set_title(String::from("Foo title"));
+///
+/// let mut out = Vec::new();
+/// let audio_rewritten = rewrite_comment_header(&mut src, &mut out, &comments, None)?;
+///
+/// // The comment header is still small enough to fit on its original page
+/// assert!(!audio_rewritten);
+/// # Ok(()) }
+/// ```
+pub fn rewrite_comment_header<R, W>(
+	src: &mut R,
+	out: &mut W,
+	new_comments: &VorbisComments,
+	new_vendor: Option<&str>,
+) -> Result<bool>
+where
+	R: Read + Seek,
+	W: Write,
+{
+	let start = src.stream_position()?;
+	find_first_page(src, ParseOptions::DEFAULT_MAX_JUNK_BYTES)?;
+	let first_page = Page::read(src)?;
+	src.seek(SeekFrom::Start(start))?;
+
+	let file_type = file_type_from_identification_header(first_page.content())?;
+	let (format, header_packet_count) = OGGFormat::from_filetype(file_type);
+
+	let mut comments_ref = VorbisCommentsRef {
+		vendor: Cow::Borrowed(new_vendor.unwrap_or_default()),
+		items: new_comments
+			.items
+			.iter()
+			.map(|(k, v)| (k.as_str(), v.as_str())),
+		pictures: new_comments.pictures.iter().map(|(p, i)| (p, *i)),
+	};
+
+	rewrite_comment_header_inner(src, out, format, header_packet_count, |comment_packet| {
+		create_comment_packet(
+			&mut comments_ref,
+			format,
+			new_vendor.is_none().then_some(comment_packet),
+			WriteOptions::default(),
+		)
+	})
+}
+
+// Rewrites the stream, replacing the comment packet with the result of `new_comment_packet`, which
+// receives the original comment packet
+fn rewrite_comment_header_inner<R, W, P>(
+	src: &mut R,
+	out: &mut W,
+	format: OGGFormat,
+	header_packet_count: isize,
+	new_comment_packet: P,
+) -> Result<bool>
+where
+	R: Read + Seek,
+	W: Write,
+	P: FnOnce(&[u8]) -> Result<Vec<u8>>,
 {
 	// TODO: Would be nice if we didn't have to read just to seek and reread immediately
 
+	// Any junk preceding the stream is copied as is
+	let junk_start = src.stream_position()?;
+	find_first_page(src, ParseOptions::DEFAULT_MAX_JUNK_BYTES)?;
+
+	let start = src.stream_position()?;
+	if start > junk_start {
+		src.seek(SeekFrom::Start(junk_start))?;
+		std::io::copy(&mut src.by_ref().take(start - junk_start), out)?;
+	}
+
 	// Read the first page header to get the stream serial number
-	let start = file.stream_position()?;
-	let first_page_header = PageHeader::read(file)?;
+	let first_page_header = PageHeader::read(src)?;
 
 	let stream_serial = first_page_header.stream_serial;
 
-	file.seek(SeekFrom::Start(start))?;
-	let mut packets = Packets::read_count(file, header_packet_count)?;
-
-	let mut remaining_file_content = Vec::new();
-	file.read_to_end(&mut remaining_file_content)?;
+	src.seek(SeekFrom::Start(start))?;
+	let mut packets = Packets::read_count(src, header_packet_count)?;
 
 	let comment_packet = packets
 		.get(1)
 		.ok_or_else(|| decode_err!("OGG: Expected metadata packet"))?;
 
-	let comment_signature = format.comment_signature();
-	if let Some(comment_signature) = comment_signature {
+	if let Some(comment_signature) = format.comment_signature() {
 		verify_signature(comment_packet, comment_signature)?;
 	}
 
-	let comment_signature = comment_signature.unwrap_or_default();
-
-	// Retain the file's vendor string
-	let md_reader = &mut &comment_packet[comment_signature.len()..];
-
-	let vendor_len = md_reader.read_u32::<LittleEndian>()?;
-	let mut vendor = try_vec![0; vendor_len as usize];
-	md_reader.read_exact(&mut vendor)?;
-
-	let vendor_str;
-	match String::from_utf8(vendor) {
-		Ok(s) => vendor_str = Cow::Owned(s),
-		Err(_) => {
-			// TODO: Error on strict?
-			log::warn!("OGG vendor string is not valid UTF-8, not re-using");
-			vendor_str = Cow::Borrowed("");
-		},
-	}
-
-	tag.vendor = vendor_str;
-
-	let add_framing_bit = format == OGGFormat::Vorbis;
-	let new_metadata_packet = create_metadata_packet(tag, comment_signature, add_framing_bit)?;
+	let new_metadata_packet = new_comment_packet(comment_packet)?;
 
 	// Replace the old comment packet
 	packets.set(1, new_metadata_packet);
 
-	file.rewind()?;
-	file.truncate(0)?;
-
 	let pages_written =
-		packets.write_to(file, stream_serial, 0, CONTAINS_FIRST_PAGE_OF_BITSTREAM)? as u32;
+		packets.write_to(out, stream_serial, 0, CONTAINS_FIRST_PAGE_OF_BITSTREAM)? as u32;
+
+	// If the first audio page already follows our header pages, nothing downstream changes
+	let audio_start = src.stream_position()?;
+	let aligned = match PageHeader::read(src) {
+		Ok(header) => header.sequence_number == pages_written,
+		// No audio pages at all
+		Err(_) => true,
+	};
+
+	src.seek(SeekFrom::Start(audio_start))?;
+
+	if aligned {
+		std::io::copy(src, out)?;
+		return Ok(false);
+	}
 
 	// Correct all remaining page sequence numbers
-	let mut pages_reader = Cursor::new(&remaining_file_content[..]);
 	let mut idx = 0;
-	while let Ok(mut page) = Page::read(&mut pages_reader) {
+	while let Ok(mut page) = Page::read(src) {
 		let header = page.header_mut();
 		header.sequence_number = pages_written + idx;
 		page.gen_crc();
-		file.write_all(&page.as_bytes())?;
+		out.write_all(&page.as_bytes())?;
 
 		idx += 1;
 	}
 
-	Ok(())
+	Ok(true)
+}
+
+/// Update the comment header of an OGG stream in place
+///
+/// Unlike [`rewrite_comment_header`], this never moves any bytes of the stream. The new comment header
+/// is padded to the exact size of the original one, and laid out over the same pages, so only the pages
+/// holding the comment header are overwritten. Any packet sharing the last of those pages (such as
+/// the Vorbis setup header) is kept as is. This makes it safe to use on files that are still being
+/// appended to, and cheap to sync, as only the returned range needs to be copied.
+///
+/// The stream is expected to start at the current position of `file`, though it may be preceded
+/// by up to [`ParseOptions::DEFAULT_MAX_JUNK_BYTES`] of junk.
+///
+/// If `new_vendor` is `None`, the stream's existing vendor string is retained, and the vendor
+/// of `new_comments` is ignored.
+///
+/// Returns the range of bytes that were overwritten. Nothing outside of this range is modified.
+///
+/// # Errors
+///
+/// * `file` does not contain an OGG Opus, Vorbis, or Speex stream
+/// * The comment header is malformed
+/// * The identification header shares a page with the comment header
+/// * The new comments do not fit in the original comment header pages
+/// * [`std::io::Error`]
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::ogg::{VorbisComments, update_comment_header_in_place};
+/// use lofty::tag::Accessor;
+/// use std::io::Cursor;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let mut file = Cursor::new(std::fs::read("tests/files/assets/minimal/full_test.opus")?);
+/// let mut comments = VorbisComments::new();
+/// comments.set_title(String::from("Foo"));
+///
+/// let modified = update_comment_header_in_place(&mut file, &comments, None)?;
+/// println!("Only bytes {modified:?} need to be synced");
+/// # Ok(()) }
+/// ```
+pub fn update_comment_header_in_place<F>(
+	file: &mut F,
+	new_comments: &VorbisComments,
+	new_vendor: Option<&str>,
+) -> Result<Range<u64>>
+where
+	F: Read + Write + Seek,
+{
+	let (comment_pages, comment_packet, file_type) = read_header_pages_in_place(file)?;
+	let (format, _) = OGGFormat::from_filetype(file_type);
+
+	let mut comments_ref = VorbisCommentsRef {
+		vendor: Cow::Borrowed(new_vendor.unwrap_or_default()),
+		items: new_comments
+			.items
+			.iter()
+			.map(|(k, v)| (k.as_str(), v.as_str())),
+		pictures: new_comments.pictures.iter().map(|(p, i)| (p, *i)),
+	};
+
+	let new_comment_packet = create_comment_packet(
+		&mut comments_ref,
+		format,
+		new_vendor.is_none().then_some(comment_packet.as_slice()),
+		WriteOptions::default(),
+	)?;
+
+	if new_comment_packet.len() > comment_packet.len() {
+		return Err(FileEncodingError::new(
+			file_type,
+			"New comment header does not fit in the original comment header pages",
+		)
+		.into());
+	}
+
+	write_comment_pages(
+		file,
+		comment_pages,
+		new_comment_packet,
+		comment_packet.len(),
+	)
+}
+
+// Creates the new comment packet, retaining the vendor string of `original_comment_packet` if provided
+fn create_comment_packet<'a, II, IP>(
+	tag: &mut VorbisCommentsRef<'a, II, IP>,
+	format: OGGFormat,
+	original_comment_packet: Option<&[u8]>,
+	write_options: WriteOptions,
+) -> Result<Vec<u8>>
+where
+	II: Iterator<Item = (&'a str, &'a str)>,
+	IP: Iterator<Item = (&'a Picture, PictureInformation)>,
+{
+	let comment_signature = format.comment_signature().unwrap_or_default();
+
+	if let Some(original_comment_packet) = original_comment_packet {
+		tag.vendor = read_vendor(original_comment_packet, comment_signature)?;
+	}
+
+	let add_framing_bit = format == OGGFormat::Vorbis;
+	create_metadata_packet(tag, comment_signature, add_framing_bit, write_options)
+}
+
+// Writes `new_comment_packet` over the original comment header pages, returning the range of bytes
+// that were overwritten
+//
+// The packet must not be larger than the original one, which is `original_len` bytes.
+fn write_comment_pages<F>(
+	file: &mut F,
+	comment_pages: Vec<Page>,
+	mut new_comment_packet: Vec<u8>,
+	original_len: usize,
+) -> Result<Range<u64>>
+where
+	F: Write + Seek,
+{
+	// Readers ignore anything following the comments (or the framing bit, for Vorbis), so the packet
+	// can be padded to its original size. That way, every page keeps its original segment table.
+	new_comment_packet.resize(original_len, 0);
+
+	let start = comment_pages[0].header().start;
+	let end = comment_pages.last().expect("at least one page").end;
+
+	file.seek(SeekFrom::Start(start))?;
+
+	let mut remaining = new_comment_packet.as_slice();
+	for page in comment_pages {
+		let mut page_bytes = page.as_bytes();
+
+		// Only replace the comment header's share of the page, the last page may hold other packets
+		let content_start = PAGE_HEADER_SIZE + page.header().segments().len();
+		let (comment_part, rest) = remaining.split_at(remaining.len().min(page.content().len()));
+		page_bytes[content_start..content_start + comment_part.len()].copy_from_slice(comment_part);
+		remaining = rest;
+
+		let mut new_page = Page::read(&mut Cursor::new(page_bytes))?;
+		new_page.gen_crc();
+		file.write_all(&new_page.as_bytes())?;
+	}
+
+	Ok(start..end)
+}
+
+// Reads the identification and comment header pages, verifying that the comment header can be
+// rewritten in place
+//
+// Returns the comment header pages and the comment packet.
+fn read_header_pages_in_place<R>(file: &mut R) -> Result<(Vec<Page>, Vec<u8>, FileType)>
+where
+	R: Read + Seek,
+{
+	find_first_page(file, ParseOptions::DEFAULT_MAX_JUNK_BYTES)?;
+
+	let first_page = Page::read(file)?;
+	let file_type = file_type_from_identification_header(first_page.content())?;
+	let (format, _) = OGGFormat::from_filetype(file_type);
+
+	if packet_end(first_page.header().segments()) != Some(first_page.content().len()) {
+		return Err(FileEncodingError::new(
+			file_type,
+			"Identification header shares a page with the comment header",
+		)
+		.into());
+	}
+
+	// Collect the pages making up the comment header. The last one may also hold the start of the
+	// next packet.
+	let mut comment_pages = Vec::new();
+	let mut comment_packet = Vec::new();
+	loop {
+		let page = Page::read(file)?;
+		let packet_end = packet_end(page.header().segments());
+
+		let content = page.content();
+		comment_packet.extend_from_slice(&content[..packet_end.unwrap_or(content.len())]);
+		comment_pages.push(page);
+
+		if packet_end.is_some() {
+			break;
+		}
+	}
+
+	if let Some(comment_signature) = format.comment_signature() {
+		verify_signature(&comment_packet, comment_signature)?;
+	}
+
+	Ok((comment_pages, comment_packet, file_type))
+}
+
+// The size of the comment header that would be written for `tag`, excluding the vendor string
+pub(crate) fn comment_header_size(tag: &Tag, file_type: FileType) -> Result<u64> {
+	let (_, items, pictures) = create_vorbis_comments_ref(tag);
+
+	let mut comments_ref = VorbisCommentsRef {
+		vendor: Cow::Borrowed(""),
+		items,
+		pictures,
+	};
+
+	let (format, _) = OGGFormat::from_filetype(file_type);
+	let comment_packet =
+		create_comment_packet(&mut comments_ref, format, None, WriteOptions::default())?;
+
+	Ok(comment_packet.len() as u64)
+}
+
+fn file_type_from_identification_header(content: &[u8]) -> Result<FileType> {
+	match content {
+		content if content.starts_with(OPUSHEAD) => Ok(FileType::Opus),
+		content if content.starts_with(VORBIS_IDENT_HEAD) => Ok(FileType::Vorbis),
+		content if content.starts_with(SPEEXHEADER) => Ok(FileType::Speex),
+		_ => err!(UnknownFormat),
+	}
+}
+
+// Finds the size of the first packet (or part of one) to end on a page
+//
+// Returns `None` if no packet ends on the page.
+fn packet_end(segments: &[u8]) -> Option<usize> {
+	let end = segments.iter().position(|&size| size < 255)?;
+	Some(segments[..=end].iter().map(|&size| usize::from(size)).sum())
+}
+
+fn read_vendor(comment_packet: &[u8], comment_signature: &[u8]) -> Result<Cow<'static, str>> {
+	let md_reader = &mut &comment_packet[comment_signature.len()..];
+
+	let vendor_len = md_reader.read_u32::<LittleEndian>()?;
+	let mut vendor = try_vec![0; vendor_len as usize];
+	md_reader.read_exact(&mut vendor)?;
+
+	match String::from_utf8(vendor) {
+		Ok(s) => Ok(Cow::Owned(s)),
+		Err(_) => {
+			// TODO: Error on strict?
+			log::warn!("OGG vendor string is not valid UTF-8, not re-using");
+			Ok(Cow::Borrowed(""))
+		},
+	}
 }
 
 pub(super) fn create_metadata_packet<'a, II, IP>(
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
 	comment_signature: &[u8],
 	add_framing_bit: bool,
+	write_options: WriteOptions,
 ) -> Result<Vec<u8>>
 where
 	II: Iterator<Item = (&'a str, &'a str)>,
@@ -182,7 +561,12 @@ where
 
 	let mut count = 0;
 	create_comments(&mut new_comment_packet, &mut count, &mut tag.items)?;
-	create_pictures(&mut new_comment_packet, &mut count, &mut tag.pictures)?;
+	create_pictures(
+		&mut new_comment_packet,
+		&mut count,
+		&mut tag.pictures,
+		write_options,
+	)?;
 
 	// Seek back and write the item count
 	new_comment_packet.seek(SeekFrom::Start(item_count_pos))?;
@@ -229,10 +613,15 @@ fn create_pictures(
 	packet: &mut impl Write,
 	count: &mut u32,
 	pictures: &mut dyn Iterator<Item = (&Picture, PictureInformation)>,
+	write_options: WriteOptions,
 ) -> Result<()> {
 	const PICTURE_KEY: &str = "METADATA_BLOCK_PICTURE=";
 
 	for (pic, info) in pictures {
+		if !write_options.is_picture_allowed(pic) {
+			continue;
+		}
+
 		let picture = pic.as_flac_bytes(info, true);
 
 		let Ok(bytes_len) = u32::try_from(picture.len() + PICTURE_KEY.len()) else {
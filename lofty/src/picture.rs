@@ -3,13 +3,14 @@
 use crate::config::ParsingMode;
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::macros::err;
+use crate::tag::TagType;
 use crate::util::text::utf8_decode_str;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 use std::io::{Cursor, Read, Seek, SeekFrom};
 
-use byteorder::{BigEndian, ReadBytesExt as _};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt as _};
 use data_encoding::BASE64;
 
 /// Common picture item keys for APE
@@ -275,6 +276,36 @@ impl PictureType {
 			_ => Self::Undefined(0),
 		}
 	}
+
+	/// Whether the `PictureType` can be represented in a [`TagType`]
+	///
+	/// * ID3v2, APE, and Vorbis comments support all of the ID3v2 picture types
+	/// * MP4 pictures have no type, so only [`PictureType::Other`] is allowed
+	/// * ID3v1, RIFF INFO, and AIFF text chunks do not support pictures at all
+	///
+	/// [`PictureType::Undefined`] is never allowed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::picture::PictureType;
+	/// use lofty::tag::TagType;
+	///
+	/// assert!(PictureType::CoverFront.is_allowed_in(TagType::VorbisComments));
+	/// assert!(!PictureType::CoverFront.is_allowed_in(TagType::Mp4Ilst));
+	/// assert!(!PictureType::Undefined(42).is_allowed_in(TagType::Id3v2));
+	/// ```
+	pub fn is_allowed_in(&self, tag_type: TagType) -> bool {
+		if let Self::Undefined(_) = self {
+			return false;
+		}
+
+		match tag_type {
+			TagType::Id3v2 | TagType::Ape | TagType::VorbisComments => true,
+			TagType::Mp4Ilst => *self == Self::Other,
+			TagType::Id3v1 | TagType::RiffInfo | TagType::AiffText => false,
+		}
+	}
 }
 
 /// Information about a [`Picture`]
@@ -296,13 +327,14 @@ pub struct PictureInformation {
 impl PictureInformation {
 	/// Attempt to extract [`PictureInformation`] from a [`Picture`]
 	///
-	/// NOTE: This only supports PNG and JPEG images. If another image is provided,
+	/// NOTE: This only supports PNG, JPEG, and GIF images. If another image is provided,
 	/// the `PictureInformation` will be zeroed out.
 	///
 	/// # Errors
 	///
 	/// * `picture.data` is less than 8 bytes in length
-	/// * See [`PictureInformation::from_png`] and [`PictureInformation::from_jpeg`]
+	/// * See [`PictureInformation::from_png`], [`PictureInformation::from_jpeg`], and
+	///   [`PictureInformation::from_gif`]
 	pub fn from_picture(picture: &Picture) -> Result<Self> {
 		let reader = &mut &*picture.data;
 
@@ -313,6 +345,7 @@ impl PictureInformation {
 		match reader[..4] {
 			[0x89, b'P', b'N', b'G'] => Ok(Self::from_png(reader).unwrap_or_default()),
 			[0xFF, 0xD8, 0xFF, ..] => Ok(Self::from_jpeg(reader).unwrap_or_default()),
+			[b'G', b'I', b'F', ..] => Ok(Self::from_gif(reader).unwrap_or_default()),
 			_ => Ok(Self::default()),
 		}
 	}
@@ -345,9 +378,11 @@ impl PictureInformation {
 		let mut color_depth = u32::from(reader.read_u8()?);
 		let color_type = reader.read_u8()?;
 
+		// Samples per pixel, the bit depth is per sample
 		match color_type {
 			2 => color_depth *= 3,
-			4 | 6 => color_depth *= 4,
+			4 => color_depth *= 2,
+			6 => color_depth *= 4,
 			_ => {},
 		}
 
@@ -458,6 +493,91 @@ impl PictureInformation {
 
 		err!(NotAPicture)
 	}
+
+	/// Attempt to extract [`PictureInformation`] from a GIF
+	///
+	/// GIF images are always indexed, so `num_colors` will be the size of the global color table, or
+	/// the first image's local color table.
+	///
+	/// # Errors
+	///
+	/// * `reader` is not a GIF image
+	pub fn from_gif(mut data: &[u8]) -> Result<Self> {
+		const COLOR_TABLE_FLAG: u8 = 0x80;
+
+		let reader = &mut data;
+
+		let mut sig = [0; 6];
+		reader.read_exact(&mut sig)?;
+
+		if &sig != b"GIF87a" && &sig != b"GIF89a" {
+			err!(NotAPicture);
+		}
+
+		// Logical screen descriptor
+		let width = u32::from(reader.read_u16::<LittleEndian>()?);
+		let height = u32::from(reader.read_u16::<LittleEndian>()?);
+		let mut flags = reader.read_u8()?;
+
+		// Background color index (1), pixel aspect ratio (1)
+		reader.read_exact(&mut [0; 2])?;
+
+		// Without a global color table, every image will have its own
+		if flags & COLOR_TABLE_FLAG == 0 {
+			flags = Self::gif_local_color_table_flags(reader).unwrap_or_default();
+		}
+
+		let mut ret = Self {
+			width,
+			height,
+			color_depth: 0,
+			num_colors: 0,
+		};
+
+		if flags & COLOR_TABLE_FLAG != 0 {
+			// The color table has 2^(N+1) entries
+			let bits_per_pixel = u32::from(flags & 0x07) + 1;
+			ret.color_depth = bits_per_pixel;
+			ret.num_colors = 1 << bits_per_pixel;
+		}
+
+		Ok(ret)
+	}
+
+	// Skips any extension blocks to find the flags of the first image descriptor
+	fn gif_local_color_table_flags(reader: &mut &[u8]) -> Result<u8> {
+		const EXTENSION_INTRODUCER: u8 = 0x21;
+		const IMAGE_SEPARATOR: u8 = 0x2C;
+
+		loop {
+			match reader.read_u8()? {
+				EXTENSION_INTRODUCER => {
+					// Extension label
+					reader.read_u8()?;
+
+					// The extension's data sub-blocks, terminated by an empty block
+					loop {
+						let size = reader.read_u8()? as usize;
+						if size == 0 {
+							break;
+						}
+
+						if size > reader.len() {
+							err!(NotAPicture);
+						}
+
+						*reader = &reader[size..];
+					}
+				},
+				IMAGE_SEPARATOR => {
+					// Left (2), top (2), width (2), height (2)
+					reader.read_exact(&mut [0; 8])?;
+					return Ok(reader.read_u8()?);
+				},
+				_ => err!(NotAPicture),
+			}
+		}
+	}
 }
 
 /// Builder for a [`Picture`]
@@ -2,10 +2,16 @@
 
 use crate::aac::AacFile;
 use crate::ape::ApeFile;
-use crate::config::{ParseOptions, global_options};
+use crate::config::{ParseOptions, WriteOptions, global_options};
 use crate::error::Result;
-use crate::file::{AudioFile, BoundTaggedFile, FileType, FileTypeGuessResult, TaggedFile};
+use crate::file::tag_space::TagSpace;
+use crate::file::{
+	AudioFile, BoundTaggedFile, FileType, FileTypeGuessResult, TaggedFile, TaggedFileExt,
+};
 use crate::flac::FlacFile;
+use crate::id3::v2::read::parse_id3v2;
+use crate::id3::v2::{AttachedPictureFrame, Frame};
+use crate::id3::{FindId3v2Config, ID3FindResults, find_id3v2};
 use crate::iff::aiff::AiffFile;
 use crate::iff::wav::WavFile;
 use crate::macros::err;
@@ -16,7 +22,9 @@ use crate::musepack::MpcFile;
 use crate::ogg::opus::OpusFile;
 use crate::ogg::speex::SpeexFile;
 use crate::ogg::vorbis::VorbisFile;
+use crate::picture::{Picture, PictureType};
 use crate::resolve::custom_resolvers;
+use crate::tag::TagType;
 use crate::wavpack::WavPackFile;
 
 use crate::io::FileLike;
@@ -384,23 +392,53 @@ impl<R: Read + Seek> Probe<R> {
 
 				file_type_after_id3_block
 			},
-			// TODO: Check more than MPEG/AAC
+			// TODO: Check more than OGG and MPEG/AAC
 			FileTypeGuessResult::MaybePrecededByJunk => {
 				log::debug!(
 					"Probe: Possible junk bytes detected, searching up to {} bytes",
 					max_junk_bytes
 				);
 
-				let ret = self.check_mpeg_or_aac(max_junk_bytes);
+				let mut ret = self.check_ogg(max_junk_bytes)?;
+				if ret.is_none() {
+					self.inner.seek(SeekFrom::Start(starting_position))?;
+					ret = self.check_mpeg_or_aac(max_junk_bytes)?;
+				}
 
 				// before returning any result for a file type, seek back to the front
 				self.inner.seek(SeekFrom::Start(starting_position))?;
 
-				ret
+				Ok(ret)
 			},
 		}
 	}
 
+	/// Searches for an OGG Opus, Vorbis, or Speex stream, which may be preceded by junk bytes
+	fn check_ogg(&mut self, max_junk_bytes: usize) -> std::io::Result<Option<FileType>> {
+		const CAPTURE_PATTERN: &[u8] = b"OggS";
+
+		let mut content = Vec::new();
+		self.inner
+			.by_ref()
+			.take(max_junk_bytes as u64 + 36)
+			.read_to_end(&mut content)?;
+
+		let search_end = content.len().min(max_junk_bytes + CAPTURE_PATTERN.len());
+		let Some(page_start) = content[..search_end]
+			.windows(CAPTURE_PATTERN.len())
+			.position(|window| window == CAPTURE_PATTERN)
+		else {
+			return Ok(None);
+		};
+
+		match FileType::from_buffer_inner(&content[page_start..]) {
+			Some(FileTypeGuessResult::Determined(
+				file_ty @ (FileType::Opus | FileType::Vorbis | FileType::Speex),
+			)) => Ok(Some(file_ty)),
+			_ => Ok(None),
+		}
+	}
+
 	/// Searches for an MPEG/AAC frame sync, which may be preceded by junk bytes
 	fn check_mpeg_or_aac(&mut self, max_junk_bytes: usize) -> std::io::Result<Option<FileType>> {
 		{
@@ -470,17 +508,32 @@ impl<R: Read + Seek> Probe<R> {
 
 		let tagged_file = match self.f_ty {
 			Some(f_type) => match f_type {
-				FileType::Aac => AacFile::read_from(reader, options)?.into(),
+				FileType::Aac => {
+					let file = AacFile::read_from(reader, options)?;
+					with_tag_space(file.tag_space, file)
+				},
 				FileType::Aiff => AiffFile::read_from(reader, options)?.into(),
 				FileType::Ape => ApeFile::read_from(reader, options)?.into(),
 				FileType::Flac => FlacFile::read_from(reader, options)?.into(),
-				FileType::Mpeg => MpegFile::read_from(reader, options)?.into(),
-				FileType::Opus => OpusFile::read_from(reader, options)?.into(),
-				FileType::Vorbis => VorbisFile::read_from(reader, options)?.into(),
+				FileType::Mpeg => {
+					let file = MpegFile::read_from(reader, options)?;
+					with_tag_space(file.tag_space, file)
+				},
+				FileType::Opus => {
+					let file = OpusFile::read_from(reader, options)?;
+					with_tag_space(file.tag_space, file)
+				},
+				FileType::Vorbis => {
+					let file = VorbisFile::read_from(reader, options)?;
+					with_tag_space(file.tag_space, file)
+				},
 				FileType::Wav => WavFile::read_from(reader, options)?.into(),
 				FileType::Mp4 => Mp4File::read_from(reader, options)?.into(),
 				FileType::Mpc => MpcFile::read_from(reader, options)?.into(),
-				FileType::Speex => SpeexFile::read_from(reader, options)?.into(),
+				FileType::Speex => {
+					let file = SpeexFile::read_from(reader, options)?;
+					with_tag_space(file.tag_space, file)
+				},
 				FileType::WavPack => WavPackFile::read_from(reader, options)?.into(),
 				FileType::Custom(c) => {
 					if !unsafe { global_options().use_custom_resolvers } {
@@ -498,6 +551,13 @@ impl<R: Read + Seek> Probe<R> {
 	}
 }
 
+// The derived `TaggedFile` conversions don't carry the space taken up by the tags
+fn with_tag_space(tag_space: Option<TagSpace>, file: impl Into<TaggedFile>) -> TaggedFile {
+	let mut tagged_file = file.into();
+	tagged_file.tag_space = tag_space;
+	tagged_file
+}
+
 impl<F: FileLike> Probe<F> {
 	/// Attempts to extract a [`BoundTaggedFile`] from the reader
 	///
@@ -583,6 +643,160 @@ where
 	Probe::open(path)?.read()
 }
 
+/// Read a [`TaggedFile`] from a byte slice
+///
+/// NOTE: This will determine the [`FileType`] from the content
+///
+/// # Errors
+///
+/// See:
+///
+/// * [`Probe::guess_file_type`]
+/// * [`Probe::read`]
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::read_from_bytes;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// let bytes = std::fs::read(path)?;
+///
+/// let parsed_file = read_from_bytes(&bytes)?;
+/// # Ok(()) }
+/// ```
+pub fn read_from_bytes(bytes: &[u8]) -> Result<TaggedFile> {
+	Probe::new(Cursor::new(bytes)).guess_file_type()?.read()
+}
+
+/// Write the tags of a [`TaggedFile`] to a copy of `bytes`
+///
+/// This is the in-memory counterpart to [`AudioFile::save_to`]. `bytes` should hold the file that
+/// `tagged_file` was read from, and will be left untouched. The edited file is returned.
+///
+/// # Errors
+///
+/// See [`AudioFile::save_to`]
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::config::WriteOptions;
+/// use lofty::prelude::*;
+/// use lofty::{read_from_bytes, write_to_bytes};
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// let bytes = std::fs::read(path)?;
+///
+/// let mut tagged_file = read_from_bytes(&bytes)?;
+/// if let Some(tag) = tagged_file.primary_tag_mut() {
+/// 	tag.set_title(String::from("Foo title"));
+/// }
+///
+/// let edited = write_to_bytes(&bytes, &tagged_file, WriteOptions::default())?;
+///
+/// let tagged_file = read_from_bytes(&edited)?;
+/// # assert_eq!(tagged_file.primary_tag().unwrap().title().as_deref(), Some("Foo title"));
+/// # Ok(()) }
+/// ```
+pub fn write_to_bytes(
+	bytes: &[u8],
+	tagged_file: &TaggedFile,
+	write_options: WriteOptions,
+) -> Result<Vec<u8>> {
+	let mut file = Cursor::new(bytes.to_vec());
+	tagged_file.save_to(&mut file, write_options)?;
+
+	Ok(file.into_inner())
+}
+
+/// Read the front cover from a path, without fully parsing the file
+///
+/// This is a faster alternative to reading the entire file with [`read_from_path`] and searching
+/// for a [`PictureType::CoverFront`], intended for generating thumbnails. The audio properties are
+/// never read.
+///
+/// For formats that store an ID3v2 tag at the start of the file (e.g. [`FileType::Mpeg`]), this
+/// will return as soon as a front cover is found in that tag. For all other formats, only the tags
+/// are read. As with a full parse, the primary tag is checked first.
+///
+/// NOTE: This will determine the [`FileType`] from the content, falling back to the extension
+///
+/// # Errors
+///
+/// See:
+///
+/// * [`Probe::open`]
+/// * [`Probe::read`]
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::picture::PictureType;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// if let Some(front_cover) = lofty::read_cover_art(path)? {
+/// 	assert_eq!(front_cover.pic_type(), PictureType::CoverFront);
+/// }
+/// # Ok(()) }
+/// ```
+pub fn read_cover_art<P>(path: P) -> Result<Option<Picture>>
+where
+	P: AsRef<Path>,
+{
+	let parse_options = ParseOptions::new().read_properties(false);
+
+	let mut probe = Probe::open(path)?
+		.options(parse_options)
+		.guess_file_type()?;
+	let Some(file_type) = probe.file_type() else {
+		err!(UnknownFormat);
+	};
+
+	if file_type.primary_tag_type() == TagType::Id3v2 {
+		let start = probe.inner.stream_position()?;
+
+		if let ID3FindResults(Some(header), Some(content)) =
+			find_id3v2(&mut probe.inner, FindId3v2Config::READ_TAG)?
+		{
+			let id3v2 = parse_id3v2(&mut &*content, header, parse_options)?;
+			let front_cover = id3v2.frames.into_iter().find_map(|frame| match frame {
+				Frame::Picture(AttachedPictureFrame { picture, .. })
+					if picture.pic_type() == PictureType::CoverFront =>
+				{
+					Some(picture.into_owned())
+				},
+				_ => None,
+			});
+
+			if front_cover.is_some() {
+				return Ok(front_cover);
+			}
+		}
+
+		// No luck, fall back to reading all of the tags
+		probe.inner.seek(SeekFrom::Start(start))?;
+	}
+
+	let tagged_file = probe.read()?;
+
+	let primary_tag = tagged_file.primary_tag();
+	let front_cover = primary_tag
+		.into_iter()
+		.chain(
+			tagged_file
+				.tags()
+				.iter()
+				.filter(|tag| tag.tag_type() != file_type.primary_tag_type()),
+		)
+		.find_map(|tag| tag.get_picture_type(PictureType::CoverFront));
+
+	Ok(front_cover.cloned())
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::config::{GlobalOptions, ParseOptions};
@@ -797,4 +1011,62 @@ mod tests {
 			FileType::Wav,
 		);
 	}
+
+	fn cover_art_test(asset: &str, file_name: &str) {
+		use crate::config::WriteOptions;
+		use crate::file::{AudioFile, TaggedFileExt};
+		use crate::picture::{MimeType, Picture, PictureType};
+
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join(file_name);
+		std::fs::copy(asset, &path).unwrap();
+
+		// No pictures
+		assert_eq!(super::read_cover_art(&path).unwrap(), None);
+
+		let cover = std::fs::read("tests/files/assets/issue_37.jpg").unwrap();
+		let picture = |pic_type, data: &[u8]| {
+			Picture::unchecked(data.to_vec())
+				.pic_type(pic_type)
+				.mime_type(MimeType::Jpeg)
+				.build()
+		};
+
+		let mut tagged_file = crate::read_from_path(&path).unwrap();
+		let tag = tagged_file.primary_tag_mut().unwrap();
+		tag.push_picture(picture(PictureType::CoverBack, &cover[..100]));
+		tag.push_picture(picture(PictureType::CoverFront, &cover));
+		tagged_file
+			.save_to_path(&path, WriteOptions::default())
+			.unwrap();
+
+		let front_cover = super::read_cover_art(&path).unwrap().unwrap();
+		assert_eq!(front_cover.pic_type(), PictureType::CoverFront);
+		assert_eq!(front_cover.data(), cover.as_slice());
+
+		// Should be the same as a full parse
+		let tagged_file = crate::read_from_path(&path).unwrap();
+		assert_eq!(
+			tagged_file
+				.primary_tag()
+				.unwrap()
+				.get_picture_type(PictureType::CoverFront),
+			Some(&front_cover)
+		);
+	}
+
+	#[test_log::test]
+	fn read_cover_art_mpeg() {
+		cover_art_test("tests/files/assets/minimal/full_test.mp3", "test.mp3");
+	}
+
+	#[test_log::test]
+	fn read_cover_art_opus() {
+		cover_art_test("tests/files/assets/minimal/full_test.opus", "test.opus");
+	}
+
+	#[test_log::test]
+	fn read_cover_art_flac() {
+		cover_art_test("tests/files/assets/minimal/full_test.flac", "test.flac");
+	}
 }
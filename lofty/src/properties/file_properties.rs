@@ -12,6 +12,8 @@ pub struct FileProperties {
 	pub(crate) bit_depth: Option<u8>,
 	pub(crate) channels: Option<u8>,
 	pub(crate) channel_mask: Option<ChannelMask>,
+	pub(crate) encoder_delay: Option<u32>,
+	pub(crate) encoder_padding: Option<u32>,
 }
 
 impl Default for FileProperties {
@@ -24,6 +26,8 @@ impl Default for FileProperties {
 			bit_depth: None,
 			channels: None,
 			channel_mask: None,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -48,6 +52,8 @@ impl FileProperties {
 			bit_depth,
 			channels,
 			channel_mask,
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 
@@ -86,6 +92,31 @@ impl FileProperties {
 		self.channel_mask
 	}
 
+	/// The number of samples the encoder added to the start of the stream
+	///
+	/// Players should skip these samples for gapless playback. The source depends on the format:
+	///
+	/// * Opus: The identification header's pre-skip
+	/// * MPEG: The encoder delay from a LAME (or LAME-compatible) header
+	/// * MP4: The `iTunSMPB` freeform atom
+	///
+	/// This will be `None` if the format doesn't store it, or it wasn't found.
+	pub fn encoder_delay(&self) -> Option<u32> {
+		self.encoder_delay
+	}
+
+	/// The number of samples the encoder added to the end of the stream
+	///
+	/// Players should trim these samples for gapless playback. The source depends on the format:
+	///
+	/// * MPEG: The encoder padding from a LAME (or LAME-compatible) header
+	/// * MP4: The `iTunSMPB` freeform atom
+	///
+	/// This will be `None` if the format doesn't store it, or it wasn't found.
+	pub fn encoder_padding(&self) -> Option<u32> {
+		self.encoder_padding
+	}
+
 	/// Used for tests
 	#[doc(hidden)]
 	pub fn is_empty(&self) -> bool {
@@ -99,6 +130,8 @@ impl FileProperties {
 				bit_depth: None | Some(0),
 				channels: None | Some(0),
 				channel_mask: None,
+				encoder_delay: None,
+				encoder_padding: None,
 			}
 		)
 	}
@@ -6,9 +6,11 @@
 
 mod channel_mask;
 mod file_properties;
+mod total_duration;
 
 #[cfg(test)]
 mod tests;
 
 pub use channel_mask::ChannelMask;
 pub use file_properties::FileProperties;
+pub use total_duration::{TotalDuration, total_duration};
@@ -1,6 +1,6 @@
 use crate::aac::{AACProperties, AacFile};
 use crate::ape::{ApeFile, ApeProperties};
-use crate::config::ParseOptions;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::file::AudioFile;
 use crate::flac::{FlacFile, FlacProperties};
 use crate::iff::aiff::{AiffFile, AiffProperties};
@@ -14,10 +14,12 @@ use crate::musepack::{MpcFile, MpcProperties};
 use crate::ogg::{
 	OpusFile, OpusProperties, SpeexFile, SpeexProperties, VorbisFile, VorbisProperties,
 };
+use crate::probe::Probe;
 use crate::properties::ChannelMask;
 use crate::wavpack::{WavPackFile, WavPackProperties};
 
 use std::fs::File;
+use std::io::Cursor;
 use std::time::Duration;
 
 // These values are taken from FFmpeg's ffprobe
@@ -80,6 +82,9 @@ const MP1_PROPERTIES: MpegProperties = MpegProperties {
 	sample_rate: 32000,
 	channels: 2,
 	emphasis: None,
+	vbr: false,
+	encoder_delay: None,
+	encoder_padding: None,
 };
 
 const MP2_PROPERTIES: MpegProperties = MpegProperties {
@@ -95,6 +100,9 @@ const MP2_PROPERTIES: MpegProperties = MpegProperties {
 	sample_rate: 48000,
 	channels: 2,
 	emphasis: None,
+	vbr: false,
+	encoder_delay: None,
+	encoder_padding: None,
 };
 
 const MP3_PROPERTIES: MpegProperties = MpegProperties {
@@ -110,6 +118,9 @@ const MP3_PROPERTIES: MpegProperties = MpegProperties {
 	sample_rate: 48000,
 	channels: 2,
 	emphasis: None,
+	vbr: true,
+	encoder_delay: Some(576),
+	encoder_padding: Some(1150),
 };
 
 const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -122,6 +133,8 @@ const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: None,
 	channels: 2,
 	drm_protected: false,
+	encoder_delay: None,
+	encoder_padding: None,
 };
 
 const MP4_ALAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -134,6 +147,8 @@ const MP4_ALAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: Some(16),
 	channels: 2,
 	drm_protected: false,
+	encoder_delay: None,
+	encoder_padding: None,
 };
 
 const MP4_ALS_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -146,6 +161,8 @@ const MP4_ALS_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: None,
 	channels: 2,
 	drm_protected: false,
+	encoder_delay: None,
+	encoder_padding: None,
 };
 
 const MP4_FLAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -158,6 +175,8 @@ const MP4_FLAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: Some(16),
 	channels: 2,
 	drm_protected: false,
+	encoder_delay: None,
+	encoder_padding: None,
 };
 
 // Properties verified with libmpcdec 1.2.2
@@ -232,6 +251,8 @@ const OPUS_PROPERTIES: OpusProperties = OpusProperties {
 	channel_mask: ChannelMask::stereo(),
 	version: 1,
 	input_sample_rate: 48000,
+	output_gain: 0,
+	pre_skip: 312,
 };
 
 const SPEEX_PROPERTIES: SpeexProperties = SpeexProperties {
@@ -413,6 +434,89 @@ fn opus_properties() {
 	)
 }
 
+// Replaces the identification header (the entire first page) of the Opus test file
+fn opus_with_identification_header(identification_header: &[u8]) -> Vec<u8> {
+	let file = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	let mut reader = Cursor::new(&file);
+	let first_page = ogg_pager::Page::read(&mut reader).unwrap();
+
+	let mut pages = ogg_pager::paginate(
+		[identification_header],
+		first_page.header().stream_serial,
+		0,
+		ogg_pager::CONTAINS_FIRST_PAGE_OF_BITSTREAM,
+	)
+	.unwrap();
+	assert_eq!(pages.len(), 1);
+
+	pages[0].gen_crc();
+	let mut content = pages[0].as_bytes();
+	content.extend(&file[first_page.end as usize..]);
+	content
+}
+
+#[test_log::test]
+fn opus_inconsistent_channel_mapping() {
+	let mut identification_header = std::fs::read("tests/files/assets/minimal/full_test.opus")
+		.map(|file| {
+			let first_page = ogg_pager::Page::read(&mut Cursor::new(file)).unwrap();
+			first_page.take_content()
+		})
+		.unwrap();
+
+	// Switch to mapping family 1, declaring 2 channels but only a single uncoupled stream.
+	// The second channel then maps to a decoded channel that doesn't exist.
+	identification_header[18] = 1;
+	identification_header.extend([1, 0, 0, 1]);
+
+	let file = opus_with_identification_header(&identification_header);
+
+	let strict = OpusFile::read_from(
+		&mut Cursor::new(&file),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	);
+	assert!(strict.is_err());
+
+	let relaxed = OpusFile::read_from(
+		&mut Cursor::new(&file),
+		ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+	)
+	.unwrap();
+	assert_eq!(relaxed.properties().channels(), 2);
+	assert_eq!(relaxed.properties().duration(), OPUS_PROPERTIES.duration);
+}
+
+#[test_log::test]
+fn opus_truncated_channel_mapping_table() {
+	let mut identification_header = std::fs::read("tests/files/assets/minimal/full_test.opus")
+		.map(|file| {
+			let first_page = ogg_pager::Page::read(&mut Cursor::new(file)).unwrap();
+			first_page.take_content()
+		})
+		.unwrap();
+
+	// Switch to mapping family 1, but end the header before the channel mapping table
+	identification_header[18] = 1;
+	identification_header.truncate(19);
+
+	let file = opus_with_identification_header(&identification_header);
+
+	let strict = OpusFile::read_from(
+		&mut Cursor::new(&file),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	);
+	assert!(strict.is_err());
+
+	let relaxed = OpusFile::read_from(
+		&mut Cursor::new(&file),
+		ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+	)
+	.unwrap();
+	assert_eq!(relaxed.properties().channels(), 2);
+	assert_eq!(relaxed.properties().duration(), OPUS_PROPERTIES.duration);
+}
+
 #[test_log::test]
 fn speex_properties() {
 	assert_eq!(
@@ -421,6 +525,76 @@ fn speex_properties() {
 	)
 }
 
+#[test_log::test]
+fn speex_extra_headers() {
+	const PATH: &str = "tests/files/assets/minimal/full_test.spx";
+
+	let original = std::fs::read(PATH).unwrap();
+	let mut reader = Cursor::new(&original);
+
+	let identification_page = ogg_pager::Page::read(&mut reader).unwrap();
+	let stream_serial = identification_page.header().stream_serial;
+
+	// Declare a single extra header
+	let mut identification_header = identification_page.take_content();
+	identification_header[68..72].copy_from_slice(&1_u32.to_le_bytes());
+
+	let mut identification_pages = ogg_pager::paginate(
+		[identification_header.as_slice()],
+		stream_serial,
+		0,
+		ogg_pager::CONTAINS_FIRST_PAGE_OF_BITSTREAM,
+	)
+	.unwrap();
+	identification_pages[0].gen_crc();
+
+	let mut file = identification_pages[0].as_bytes();
+
+	// The comment header ends on the first page with an absolute granule position of 0
+	let comment_header_start = reader.position() as usize;
+	let mut sequence_number = 1;
+	loop {
+		let page = ogg_pager::Page::read(&mut reader).unwrap();
+		sequence_number += 1;
+
+		if page.header().abgp == 0 {
+			break;
+		}
+	}
+	file.extend(&original[comment_header_start..reader.position() as usize]);
+
+	let mut extra_header =
+		ogg_pager::paginate([&b"Some extra header"[..]], stream_serial, 0, 0).unwrap();
+	extra_header[0].header_mut().sequence_number = sequence_number;
+	extra_header[0].gen_crc();
+	file.extend(extra_header[0].as_bytes());
+
+	// Shift the remaining pages to make room for the extra header
+	while let Ok(mut page) = ogg_pager::Page::read(&mut reader) {
+		page.header_mut().sequence_number += 1;
+		page.gen_crc();
+		file.extend(page.as_bytes());
+	}
+
+	let expected =
+		SpeexFile::read_from(&mut File::open(PATH).unwrap(), ParseOptions::new()).unwrap();
+	let speex_file = SpeexFile::read_from(
+		&mut Cursor::new(&file),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+
+	assert_eq!(speex_file.vorbis_comments(), expected.vorbis_comments());
+	assert_eq!(
+		speex_file.properties().duration(),
+		SPEEX_PROPERTIES.duration
+	);
+	assert_eq!(
+		speex_file.properties().audio_bitrate(),
+		SPEEX_PROPERTIES.audio_bitrate
+	);
+}
+
 #[test_log::test]
 fn vorbis_properties() {
 	assert_eq!(
@@ -444,3 +618,32 @@ fn wavpack_properties() {
 		WAVPACK_PROPERTIES
 	)
 }
+
+#[test_log::test]
+fn total_duration() {
+	let read = |path: &str, read_properties: bool| {
+		Probe::open(path)
+			.unwrap()
+			.options(ParseOptions::new().read_properties(read_properties))
+			.read()
+			.unwrap()
+	};
+
+	let vorbis = read("tests/files/assets/minimal/full_test.ogg", true);
+	let wav = read("tests/files/assets/minimal/wav_format_pcm.wav", true);
+	// Without properties, the duration is unknown
+	let unknown = read("tests/files/assets/minimal/full_test.opus", false);
+
+	let total = super::total_duration(&[&vorbis, &unknown, &wav]);
+	assert_eq!(
+		total.duration(),
+		VORBIS_PROPERTIES.duration + WAV_PROPERTIES.duration
+	);
+	assert_eq!(total.skipped(), 1);
+
+	let total = super::total_duration(&[&unknown]);
+	assert_eq!(total.duration(), Duration::ZERO);
+	assert_eq!(total.skipped(), 1);
+
+	assert_eq!(super::total_duration(&[]), super::TotalDuration::default());
+}
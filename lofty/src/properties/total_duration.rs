@@ -0,0 +1,66 @@
+use crate::file::{AudioFile, TaggedFile, TaggedFileExt};
+
+use std::time::Duration;
+
+/// The combined duration of multiple files
+///
+/// See [`total_duration`].
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TotalDuration {
+	pub(crate) duration: Duration,
+	pub(crate) skipped: usize,
+}
+
+impl TotalDuration {
+	/// The sum of all known durations
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// The number of files skipped due to an unknown duration
+	pub fn skipped(&self) -> usize {
+		self.skipped
+	}
+}
+
+/// Sum the durations of multiple files, such as the tracks of a playlist
+///
+/// A file's duration is unknown if it is [`Duration::ZERO`], which is the case when its properties
+/// weren't read (see [`ParseOptions::read_properties`]) or couldn't be determined. These files are
+/// skipped, and counted in [`TotalDuration::skipped`].
+///
+/// [`ParseOptions::read_properties`]: crate::config::ParseOptions::read_properties
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn main() -> lofty::error::Result<()> {
+/// let first = lofty::read_from_path("first.mp3")?;
+/// let second = lofty::read_from_path("second.flac")?;
+///
+/// let total = lofty::total_duration(&[&first, &second]);
+/// println!(
+/// 	"Total duration: {}s ({} files skipped)",
+/// 	total.duration().as_secs(),
+/// 	total.skipped()
+/// );
+/// # Ok(()) }
+/// ```
+pub fn total_duration(files: &[&TaggedFile]) -> TotalDuration {
+	let mut total = TotalDuration::default();
+	for file in files {
+		let duration = file.properties().duration();
+		if duration.is_zero() {
+			log::debug!(
+				"Skipping {:?} file with an unknown duration",
+				file.file_type()
+			);
+			total.skipped += 1;
+			continue;
+		}
+
+		total.duration += duration;
+	}
+
+	total
+}
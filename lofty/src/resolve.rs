@@ -62,6 +62,7 @@ pub(crate) trait ObjectSafeFileResolver: Send + Sync {
 	fn extension(&self) -> Option<&'static str>;
 	fn primary_tag_type(&self) -> TagType;
 	fn tag_support(&self, tag_type: TagType) -> TagSupport;
+	fn supported_tag_types(&self) -> &'static [TagType];
 	fn guess(&self, buf: &[u8]) -> Option<FileType>;
 
 	// A mask for the `AudioFile::read_from` impl
@@ -73,7 +74,12 @@ pub(crate) trait ObjectSafeFileResolver: Send + Sync {
 }
 
 // A fake `FileResolver` implementer, so we don't need to construct the type in `register_custom_resolver`
-pub(crate) struct GhostlyResolver<T: 'static>(PhantomData<T>);
+pub(crate) struct GhostlyResolver<T: 'static> {
+	// Computed from `FileResolver::tag_support` on registration
+	supported_tag_types: &'static [TagType],
+	_resolver: PhantomData<T>,
+}
+
 impl<T: FileResolver> ObjectSafeFileResolver for GhostlyResolver<T> {
 	fn extension(&self) -> Option<&'static str> {
 		T::extension()
@@ -87,6 +93,10 @@ impl<T: FileResolver> ObjectSafeFileResolver for GhostlyResolver<T> {
 		T::tag_support(tag_type)
 	}
 
+	fn supported_tag_types(&self) -> &'static [TagType] {
+		self.supported_tag_types
+	}
+
 	fn guess(&self, buf: &[u8]) -> Option<FileType> {
 		T::guess(buf)
 	}
@@ -121,7 +131,15 @@ pub fn register_custom_resolver<T: FileResolver + 'static>(name: &'static str) {
 		name
 	);
 
-	let ghost = GhostlyResolver::<T>(PhantomData);
+	let supported_tag_types = TagType::ALL
+		.into_iter()
+		.filter(|tag_type| T::tag_support(*tag_type).is_readable())
+		.collect::<Vec<_>>();
+
+	let ghost = GhostlyResolver::<T> {
+		supported_tag_types: Vec::leak(supported_tag_types),
+		_resolver: PhantomData,
+	};
 	let b: Box<dyn ObjectSafeFileResolver> = Box::new(ghost);
 
 	res.insert(name, Box::leak::<'static>(b));
@@ -206,6 +224,11 @@ mod tests {
 		let read_content = crate::read_from(&mut File::open(path).unwrap()).unwrap();
 		assert_eq!(read_content.file_type(), FileType::Custom("MyFile"));
 
+		assert_eq!(
+			FileType::Custom("MyFile").supported_tag_types(),
+			&[TagType::Id3v2]
+		);
+
 		assert!(
 			panic::catch_unwind(|| {
 				register_custom_resolver::<MyFile>("MyFile");
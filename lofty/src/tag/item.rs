@@ -420,6 +420,14 @@ gen_map!(
 	"ACOUSTID_FINGERPRINT"                    => AcoustIdFingerprint,
 	"CATALOGNUMBER"                           => CatalogNumber,
 	"COMPILATION"                             => FlagCompilation,
+	// There's no standard for podcast fields, these follow Mp3tag's mapping
+	// <https://docs.mp3tag.de/mapping/>
+	"PODCAST"                                 => FlagPodcast,
+	"PODCASTDESC"                             => PodcastDescription,
+	"PODCASTCATEGORY"                         => PodcastSeriesCategory,
+	"PODCASTURL"                              => PodcastUrl,
+	"PODCASTID"                               => PodcastGlobalUniqueId,
+	"PODCASTKEYWORDS"                         => PodcastKeywords,
 	"MEDIA"                                   => OriginalMediaType,
 	"ENCODEDBY" | "ENCODED-BY" | "ENCODED_BY" => EncodedBy,
 	"ENCODER"                                 => EncoderSoftware,
@@ -755,13 +763,18 @@ gen_item_keys!(
 		/// Only read and written if the tag format supports a field for decimal BPM values
 		/// that are not restricted to integer values.
 		///
-		/// Not supported by ID3v2 that restricts BPM values to integers in `TBPM`.
+		/// Not supported by ID3v2 that restricts BPM values to integers in `TBPM`. When converting
+		/// to a format that only has an integer BPM field, the value is rounded and used in place of
+		/// a missing [`ItemKey::IntegerBpm`].
 		Bpm,
 		/// Non-fractional BPM value with integer precision
 		///
 		/// Only read and written if the tag format has a field for integer BPM values,
 		/// e.g. ID3v2 ([`TBPM` frame](https://github.com/id3/ID3v2.4/blob/516075e38ff648a6390e48aff490abed987d3199/id3v2.4.0-frames.txt#L376))
 		/// and MP4 (`tmpo` integer atom).
+		///
+		/// When converting to a format that only has a decimal BPM field (e.g. Vorbis comments), the
+		/// value is used in place of a missing [`ItemKey::Bpm`].
 		IntegerBpm,
 
 		// Legal
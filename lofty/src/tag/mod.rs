@@ -4,6 +4,7 @@ mod accessor;
 pub(crate) mod companion_tag;
 pub(crate) mod item;
 pub mod items;
+mod semantic_eq;
 mod split_merge_tag;
 mod tag_ext;
 mod tag_type;
@@ -15,7 +16,9 @@ use crate::macros::err;
 use crate::picture::{Picture, PictureType};
 use crate::probe::Probe;
 use crate::tag::items::Timestamp;
+use crate::util::flag_item;
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::text::{parse_number, trim_number_padding};
 
 use std::borrow::Cow;
 use std::io::Write;
@@ -118,7 +121,10 @@ pub struct Tag {
 
 #[must_use]
 pub(crate) fn try_parse_timestamp(input: &str) -> Option<Timestamp> {
-	let Ok(timestamp) = Timestamp::parse(&mut input.as_bytes(), ParsingMode::Relaxed) else {
+	let Ok(timestamp) = Timestamp::parse(
+		&mut trim_number_padding(input).as_bytes(),
+		ParsingMode::Relaxed,
+	) else {
 		log::warn!("Timestamp exists in file, but cannot be parsed.");
 		return None;
 	};
@@ -186,11 +192,7 @@ impl Accessor for Tag {
 	fn date(&self) -> Option<Timestamp> {
 		self.get_string(ItemKey::RecordingDate)
 			.or_else(|| self.get_string(ItemKey::Year))
-			.and_then(|d| {
-				Timestamp::parse(&mut d.as_bytes(), ParsingMode::Relaxed)
-					.ok()
-					.flatten()
-			})
+			.and_then(try_parse_timestamp)
 	}
 
 	fn set_date(&mut self, value: Timestamp) {
@@ -290,6 +292,45 @@ impl Tag {
 		self.items.iter()
 	}
 
+	/// Returns every [`ItemKey`] present in the tag
+	///
+	/// Each key is only returned once, in the order it first appears. Items with no generic
+	/// mapping are not part of a `Tag`, see [`Tag::has_format_specific_items`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Accessor, ItemKey, ItemValue, Tag, TagItem, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.set_title(String::from("Foo title"));
+	/// tag.set_artist(String::from("Foo artist"));
+	/// tag.push(TagItem::new(
+	/// 	ItemKey::TrackArtist,
+	/// 	ItemValue::Text(String::from("Bar artist")),
+	/// ));
+	///
+	/// for key in tag.keys() {
+	/// 	println!("{key:?}: {:?}", tag.get_string(key));
+	/// }
+	///
+	/// assert_eq!(
+	/// 	tag.keys().collect::<Vec<_>>(),
+	/// 	[ItemKey::TrackTitle, ItemKey::TrackArtist]
+	/// );
+	/// ```
+	pub fn keys(&self) -> impl Iterator<Item = ItemKey> + Clone {
+		self.items
+			.iter()
+			.enumerate()
+			.filter(|(idx, item)| {
+				!self.items[..*idx]
+					.iter()
+					.any(|previous| previous.item_key == item.item_key)
+			})
+			.map(|(_, item)| item.item_key)
+	}
+
 	/// Returns a reference to a [`TagItem`] matching an [`ItemKey`]
 	pub fn get(&self, item_key: ItemKey) -> Option<&TagItem> {
 		self.items.iter().find(|i| i.item_key == item_key)
@@ -306,7 +347,7 @@ impl Tag {
 
 	fn get_u32_from_string(&self, key: ItemKey) -> Option<u32> {
 		let i = self.get_string(key)?;
-		i.parse::<u32>().ok()
+		parse_number(i)
 	}
 
 	/// Gets a byte slice from an [`ItemKey`]
@@ -326,6 +367,51 @@ impl Tag {
 		None
 	}
 
+	/// Whether the track is part of a compilation
+	///
+	/// This reads the [`ItemKey::FlagCompilation`] item (`TCMP` in ID3v2, `cpil` in MP4, and
+	/// `COMPILATION` in Vorbis comments and APE). A missing or invalid flag is treated as `false`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::VorbisComments);
+	/// assert!(!tag.is_compilation());
+	///
+	/// tag.insert_text(ItemKey::FlagCompilation, String::from("1"));
+	/// assert!(tag.is_compilation());
+	/// ```
+	pub fn is_compilation(&self) -> bool {
+		self.get_string(ItemKey::FlagCompilation)
+			.and_then(|flag| flag_item(flag.trim()))
+			.unwrap_or(false)
+	}
+
+	/// Sets the compilation flag
+	///
+	/// The flag is stored as `"1"` or `"0"`, which is converted to a boolean when writing formats
+	/// that support it (such as `cpil` in MP4).
+	///
+	/// NOTE: This will do nothing if the [`TagType`] has no mapping for [`ItemKey::FlagCompilation`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Mp4Ilst);
+	/// tag.set_compilation(true);
+	/// assert!(tag.is_compilation());
+	///
+	/// tag.set_compilation(false);
+	/// assert!(!tag.is_compilation());
+	/// ```
+	pub fn set_compilation(&mut self, compilation: bool) {
+		let _ = self.insert_text(ItemKey::FlagCompilation, u8::from(compilation).to_string());
+	}
+
 	/// Insert a [`TagItem`], replacing any existing one of the same [`ItemKey`]
 	///
 	/// NOTE: This **will** verify an [`ItemKey`] mapping exists for the target [`TagType`]
@@ -513,6 +599,8 @@ impl Tag {
 	}
 
 	/// Returns the stored [`Picture`]s as a slice
+	///
+	/// These are in the order they were read from the file.
 	pub fn pictures(&self) -> &[Picture] {
 		&self.pictures
 	}
@@ -823,4 +911,54 @@ mod tests {
 		assert!(try_parse_timestamp("19").is_none());
 		assert!(try_parse_timestamp("1").is_none());
 	}
+
+	#[test_log::test]
+	fn compilation_flag_across_formats() {
+		use crate::ape::ApeTag;
+		use crate::id3::v2::{FrameId, Id3v2Tag};
+		use crate::mp4::{AtomData, AtomIdent, Ilst};
+		use crate::ogg::VorbisComments;
+
+		use std::borrow::Cow;
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_compilation(true);
+
+		let id3v2 = Id3v2Tag::from(tag.clone());
+		assert_eq!(
+			id3v2.get_text(&FrameId::Valid(Cow::Borrowed("TCMP"))),
+			Some("1")
+		);
+		assert!(Tag::from(id3v2).is_compilation());
+
+		// Copy the tag to each format
+		tag.re_map(TagType::Mp4Ilst);
+		let ilst = Ilst::from(tag.clone());
+		assert_eq!(
+			ilst.get(&AtomIdent::Fourcc(*b"cpil"))
+				.and_then(|atom| atom.data().next()),
+			Some(&AtomData::Bool(true))
+		);
+		assert!(Tag::from(ilst).is_compilation());
+
+		tag.re_map(TagType::VorbisComments);
+		let vorbis_comments = VorbisComments::from(tag.clone());
+		assert_eq!(vorbis_comments.get("COMPILATION"), Some("1"));
+		assert!(Tag::from(vorbis_comments).is_compilation());
+
+		tag.re_map(TagType::Ape);
+		let ape = ApeTag::from(tag.clone());
+		assert!(Tag::from(ape).is_compilation());
+
+		// `false` is written explicitly, rather than removing the flag
+		tag.re_map(TagType::Mp4Ilst);
+		tag.set_compilation(false);
+		let ilst = Ilst::from(tag);
+		assert_eq!(
+			ilst.get(&AtomIdent::Fourcc(*b"cpil"))
+				.and_then(|atom| atom.data().next()),
+			Some(&AtomData::Bool(false))
+		);
+		assert!(!Tag::from(ilst).is_compilation());
+	}
 }
@@ -0,0 +1,248 @@
+use super::{ItemKey, ItemValue, Tag};
+use crate::picture::{Picture, PictureType};
+use crate::util::text::parse_number;
+
+#[derive(PartialEq)]
+enum NormalizedValue<'a> {
+	Number(u32),
+	Text(String),
+	Binary(&'a [u8]),
+}
+
+#[derive(PartialEq)]
+struct NormalizedPicture<'a> {
+	pic_type: PictureType,
+	mime_type: Option<String>,
+	description: Option<String>,
+	data: &'a [u8],
+}
+
+impl Tag {
+	/// Check if two tags hold the same information, ignoring formatting differences
+	///
+	/// Unlike comparing the items directly, this is meant to answer whether two files "have the same tags",
+	/// regardless of how they were written.
+	///
+	/// The following normalization is applied before comparing:
+	///
+	/// * The [`TagType`](crate::tag::TagType) is ignored
+	/// * Items and pictures are compared regardless of their order, and duplicates are ignored
+	/// * Empty items are ignored
+	/// * Leading and trailing whitespace is trimmed, and any other runs of whitespace are treated
+	///   as a single space
+	/// * Text that parses as a number is compared numerically (`"03"` is equal to `"3"`)
+	/// * A number pair in [`ItemKey::TrackNumber`] or [`ItemKey::DiscNumber`] is treated as its
+	///   separate number and total (`"3/12"` is equal to a track number of `"3"` with a track total of `"12"`)
+	/// * [`ItemValue::Text`] and [`ItemValue::Locator`] are considered the same
+	/// * Item languages and descriptions are ignored
+	/// * Picture MIME types are compared case-insensitively, and picture descriptions are subject
+	///   to the same whitespace rules as text. The picture data itself must be identical.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Accessor, ItemKey, Tag, TagType};
+	///
+	/// let mut id3v2 = Tag::new(TagType::Id3v2);
+	/// id3v2.set_title(String::from("Foo  title "));
+	/// id3v2.insert_text(ItemKey::TrackNumber, String::from("3/12"));
+	///
+	/// let mut vorbis_comments = Tag::new(TagType::VorbisComments);
+	/// vorbis_comments.set_title(String::from("Foo title"));
+	/// vorbis_comments.set_track(3);
+	/// vorbis_comments.set_track_total(12);
+	///
+	/// assert!(id3v2.semantic_eq(&vorbis_comments));
+	///
+	/// vorbis_comments.set_track(4);
+	/// assert!(!id3v2.semantic_eq(&vorbis_comments));
+	/// ```
+	pub fn semantic_eq(&self, other: &Tag) -> bool {
+		same_set(&normalized_items(self), &normalized_items(other))
+			&& same_set(&normalized_pictures(self), &normalized_pictures(other))
+	}
+}
+
+fn normalized_items(tag: &Tag) -> Vec<(ItemKey, NormalizedValue<'_>)> {
+	let mut items = Vec::new();
+	for item in &tag.items {
+		let key = item.key();
+		let text = match item.value() {
+			ItemValue::Text(text) | ItemValue::Locator(text) => text,
+			ItemValue::Binary(binary) => {
+				if !binary.is_empty() {
+					items.push((key, NormalizedValue::Binary(binary)));
+				}
+				continue;
+			},
+		};
+
+		let total_key = match key {
+			ItemKey::TrackNumber => Some(ItemKey::TrackTotal),
+			ItemKey::DiscNumber => Some(ItemKey::DiscTotal),
+			_ => None,
+		};
+
+		if let (Some(total_key), Some((number, total))) = (total_key, text.split_once('/')) {
+			items.extend(normalize_text(number).map(|number| (key, number)));
+			items.extend(normalize_text(total).map(|total| (total_key, total)));
+			continue;
+		}
+
+		items.extend(normalize_text(text).map(|text| (key, text)));
+	}
+
+	items
+}
+
+fn normalized_pictures(tag: &Tag) -> Vec<NormalizedPicture<'_>> {
+	tag.pictures.iter().map(normalize_picture).collect()
+}
+
+fn normalize_picture(picture: &Picture) -> NormalizedPicture<'_> {
+	NormalizedPicture {
+		pic_type: picture.pic_type(),
+		mime_type: picture
+			.mime_type()
+			.map(|mime_type| mime_type.as_str().to_ascii_lowercase()),
+		description: picture.description().and_then(normalize_whitespace),
+		data: picture.data(),
+	}
+}
+
+fn normalize_text(text: &str) -> Option<NormalizedValue<'static>> {
+	if let Some(number) = parse_number(text) {
+		return Some(NormalizedValue::Number(number));
+	}
+
+	normalize_whitespace(text).map(NormalizedValue::Text)
+}
+
+fn normalize_whitespace(text: &str) -> Option<String> {
+	let normalized = text
+		.split(|c: char| c.is_whitespace() || c == '\0')
+		.filter(|word| !word.is_empty())
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	if normalized.is_empty() {
+		return None;
+	}
+
+	Some(normalized)
+}
+
+// Order-insensitive comparison, ignoring duplicates
+fn same_set<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+	a.iter().all(|item| b.contains(item)) && b.iter().all(|item| a.contains(item))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::prelude::*;
+	use crate::tag::{ItemValue, Tag, TagItem, TagType};
+
+	fn tag_with(items: &[(ItemKey, &str)]) -> Tag {
+		let mut tag = Tag::new(TagType::Id3v2);
+		for (key, value) in items {
+			tag.push_unchecked(TagItem::new(*key, ItemValue::Text((*value).to_string())));
+		}
+
+		tag
+	}
+
+	#[test_log::test]
+	fn formatting_differences() {
+		let pairs = [
+			(
+				tag_with(&[(ItemKey::TrackTitle, " Foo   title\0")]),
+				tag_with(&[(ItemKey::TrackTitle, "Foo title")]),
+			),
+			(
+				tag_with(&[(ItemKey::TrackNumber, "03")]),
+				tag_with(&[(ItemKey::TrackNumber, "3")]),
+			),
+			(
+				tag_with(&[(ItemKey::TrackNumber, "3/12")]),
+				tag_with(&[(ItemKey::TrackNumber, "3"), (ItemKey::TrackTotal, "12")]),
+			),
+			(
+				tag_with(&[(ItemKey::DiscNumber, " 1 / 2 ")]),
+				tag_with(&[(ItemKey::DiscTotal, "2"), (ItemKey::DiscNumber, "1")]),
+			),
+			(
+				tag_with(&[(ItemKey::TrackArtist, "Foo"), (ItemKey::Comment, "")]),
+				tag_with(&[(ItemKey::TrackArtist, "Foo"), (ItemKey::TrackArtist, "Foo")]),
+			),
+		];
+
+		for (a, b) in pairs {
+			assert!(a.semantic_eq(&b), "{:?} != {:?}", a.items, b.items);
+			assert!(b.semantic_eq(&a), "{:?} != {:?}", b.items, a.items);
+		}
+	}
+
+	#[test_log::test]
+	fn different_values() {
+		let pairs = [
+			(
+				tag_with(&[(ItemKey::TrackTitle, "Foo title")]),
+				tag_with(&[(ItemKey::TrackTitle, "Foo Title")]),
+			),
+			(
+				tag_with(&[(ItemKey::TrackNumber, "3/12")]),
+				tag_with(&[(ItemKey::TrackNumber, "3")]),
+			),
+			(
+				tag_with(&[(ItemKey::TrackNumber, "3/12")]),
+				tag_with(&[(ItemKey::TrackNumber, "3"), (ItemKey::TrackTotal, "11")]),
+			),
+			(
+				tag_with(&[(ItemKey::TrackArtist, "Foo")]),
+				tag_with(&[(ItemKey::AlbumArtist, "Foo")]),
+			),
+		];
+
+		for (a, b) in pairs {
+			assert!(!a.semantic_eq(&b), "{:?} == {:?}", a.items, b.items);
+			assert!(!b.semantic_eq(&a), "{:?} == {:?}", b.items, a.items);
+		}
+	}
+
+	#[test_log::test]
+	fn pictures() {
+		let picture = |mime_type: MimeType, description: &'static str, data: Vec<u8>| {
+			Picture::unchecked(data)
+				.pic_type(PictureType::CoverFront)
+				.mime_type(mime_type)
+				.description(description)
+				.build()
+		};
+
+		let mut a = Tag::new(TagType::Id3v2);
+		a.push_picture(picture(
+			MimeType::Unknown(String::from("IMAGE/WEBP")),
+			"Cover ",
+			vec![1, 2, 3],
+		));
+
+		let mut b = Tag::new(TagType::Ape);
+		b.push_picture(picture(
+			MimeType::Unknown(String::from("image/webp")),
+			"Cover",
+			vec![1, 2, 3],
+		));
+
+		assert!(a.semantic_eq(&b));
+
+		b.remove_picture(0);
+		b.push_picture(picture(
+			MimeType::Unknown(String::from("image/webp")),
+			"Cover",
+			vec![1, 2, 4],
+		));
+
+		assert!(!a.semantic_eq(&b));
+	}
+}
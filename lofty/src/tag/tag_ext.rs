@@ -84,13 +84,30 @@ pub trait TagExt: Accessor + Into<Tag> + Sized + private::Sealed {
 		path: P,
 		write_options: WriteOptions,
 	) -> std::result::Result<(), Self::Err> {
-		self.save_to(
-			&mut std::fs::OpenOptions::new()
-				.read(true)
-				.write(true)
-				.open(path)?,
-			write_options,
-		)
+		crate::util::save::save_to_path(path.as_ref(), write_options, |file| {
+			self.save_to(file, write_options)
+		})
+	}
+
+	/// Save the tag to a path, going through a temporary file in `temp_dir`
+	///
+	/// See [`AudioFile::save_to_path_with_temp_dir`](crate::file::AudioFile::save_to_path_with_temp_dir)
+	///
+	/// # Errors
+	///
+	/// * Path doesn't exist
+	/// * A file can't be created in `temp_dir`
+	/// * The temporary file can't be renamed over `path`
+	/// * See [`TagExt::save_to`]
+	fn save_to_path_with_temp_dir<P: AsRef<Path>, T: AsRef<Path>>(
+		&self,
+		path: P,
+		temp_dir: T,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err> {
+		crate::util::save::save_to_path_with_temp_dir(path.as_ref(), temp_dir.as_ref(), |file| {
+			self.save_to(file, write_options)
+		})
 	}
 
 	/// Save the tag to a [`FileLike`]
@@ -113,8 +113,20 @@ pub enum TagType {
 }
 
 impl TagType {
+	pub(crate) const ALL: [TagType; 7] = [
+		TagType::Ape,
+		TagType::Id3v1,
+		TagType::Id3v2,
+		TagType::Mp4Ilst,
+		TagType::VorbisComments,
+		TagType::RiffInfo,
+		TagType::AiffText,
+	];
+
 	/// Remove a tag from a [`Path`]
 	///
+	/// NOTE: This always modifies the file in place, as with [`WriteStrategy::InPlace`](crate::config::WriteStrategy::InPlace).
+	///
 	/// # Errors
 	///
 	/// See [`TagType::remove_from`]
@@ -1,6 +1,8 @@
 pub(crate) mod alloc;
 pub mod io;
 pub(crate) mod math;
+pub(crate) mod musical;
+pub(crate) mod save;
 pub(crate) mod text;
 
 pub(crate) fn flag_item(item: &str) -> Option<bool> {
@@ -0,0 +1,120 @@
+/// Parses a BPM value, which must be a positive number
+pub(crate) fn parse_bpm(value: &str) -> Option<f64> {
+	let bpm = value.trim().parse::<f64>().ok()?;
+	if !bpm.is_finite() || bpm <= 0.0 {
+		return None;
+	}
+
+	Some(bpm)
+}
+
+/// Parses a BPM value, rounded to the nearest integer
+pub(crate) fn parse_integer_bpm(value: &str) -> Option<u16> {
+	let bpm = parse_bpm(value)?.round();
+	if !(1.0..=f64::from(u16::MAX)).contains(&bpm) {
+		return None;
+	}
+
+	Some(bpm as u16)
+}
+
+/// Checks if a string is a valid musical key
+///
+/// This accepts the notation of the ID3v2 `TKEY` frame, where the key is made up of a root note
+/// ("A" to "G"), an optional accessory ("b" or "#"), and an optional "m" for minor keys. An off key
+/// is represented by "o".
+///
+/// As other software is far less strict, the following are accepted as well:
+///
+/// * Lowercase root notes, "♭" and "♯" accessories, and the "min", "maj", "minor", and "major"
+///   suffixes, so "Dbm", "d♭ min", and "Eb major" are all valid.
+/// * The Camelot ("8A") and Open Key ("1m") notations.
+pub(crate) fn is_valid_initial_key(value: &str) -> bool {
+	let value = value.trim();
+	value == "o" || is_valid_note_key(value) || is_valid_wheel_key(value)
+}
+
+fn is_valid_note_key(value: &str) -> bool {
+	let mut chars = value.chars();
+	let Some('A'..='G' | 'a'..='g') = chars.next() else {
+		return false;
+	};
+
+	let mut rest = chars.as_str();
+	if let Some(remaining) = rest.strip_prefix(['b', '#', '♭', '♯']) {
+		rest = remaining;
+	}
+
+	matches!(
+		rest.trim_start().to_ascii_lowercase().as_str(),
+		"" | "m" | "min" | "minor" | "maj" | "major"
+	)
+}
+
+// The Camelot and Open Key notations, which number the keys 1 to 12 on the circle of fifths
+fn is_valid_wheel_key(value: &str) -> bool {
+	let Some((number, mode)) = value.split_at_checked(value.len().saturating_sub(1)) else {
+		return false;
+	};
+
+	number.bytes().all(|b| b.is_ascii_digit())
+		&& matches!(number.parse::<u8>(), Ok(1..=12))
+		&& matches!(mode, "A" | "B" | "a" | "b" | "m" | "d")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{is_valid_initial_key, parse_bpm, parse_integer_bpm};
+
+	#[test_log::test]
+	fn bpm() {
+		assert_eq!(parse_bpm("120"), Some(120.0));
+		assert_eq!(parse_bpm(" 98.5 "), Some(98.5));
+		assert_eq!(parse_integer_bpm("98.5"), Some(99));
+
+		for invalid in ["", "0", "-120", "fast", "NaN", "inf"] {
+			assert_eq!(parse_bpm(invalid), None, "{invalid:?}");
+		}
+
+		assert_eq!(parse_integer_bpm("0.2"), None);
+		assert_eq!(parse_integer_bpm("70000"), None);
+	}
+
+	#[test_log::test]
+	fn initial_key() {
+		for valid in [
+			"C",
+			"Dbm",
+			"F#",
+			"A#m",
+			"Cmaj",
+			"Amin",
+			"o",
+			"c",
+			"C minor",
+			"E♭ major",
+			"f♯m",
+			"8A",
+			"12b",
+			"1m",
+			"10d",
+		] {
+			assert!(is_valid_initial_key(valid), "{valid:?}");
+		}
+
+		for invalid in [
+			"",
+			"H",
+			"Cb#",
+			"C minorish",
+			"0A",
+			"13B",
+			"8C",
+			"+8A",
+			"oo",
+			"fast",
+		] {
+			assert!(!is_valid_initial_key(invalid), "{invalid:?}");
+		}
+	}
+}
@@ -0,0 +1,290 @@
+//! Path-based saving, see [`WriteStrategy`]
+
+use crate::config::{WriteOptions, WriteStrategy};
+
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Seek};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// The number of attempts to make at finding an unused temporary file name
+const MAX_TEMP_FILE_ATTEMPTS: usize = 64;
+
+/// Opens `path` according to the [`WriteStrategy`] and passes the file to `save`
+///
+/// With [`WriteStrategy::AtomicRename`], `save` receives a copy of the original file, which
+/// will only replace the original if `save` succeeds.
+pub(crate) fn save_to_path<E, S>(path: &Path, write_options: WriteOptions, save: S) -> Result<(), E>
+where
+	E: From<std::io::Error>,
+	S: FnOnce(&mut File) -> Result<(), E>,
+{
+	match write_options.write_strategy {
+		WriteStrategy::InPlace => save(&mut OpenOptions::new().read(true).write(true).open(path)?),
+		WriteStrategy::AtomicRename => save_atomic(path, None, save),
+	}
+}
+
+/// Same as [`save_to_path`] with [`WriteStrategy::AtomicRename`], creating the temporary file in
+/// `temp_dir`
+pub(crate) fn save_to_path_with_temp_dir<E, S>(
+	path: &Path,
+	temp_dir: &Path,
+	save: S,
+) -> Result<(), E>
+where
+	E: From<std::io::Error>,
+	S: FnOnce(&mut File) -> Result<(), E>,
+{
+	save_atomic(path, Some(temp_dir), save)
+}
+
+fn save_atomic<E, S>(path: &Path, temp_dir: Option<&Path>, save: S) -> Result<(), E>
+where
+	E: From<std::io::Error>,
+	S: FnOnce(&mut File) -> Result<(), E>,
+{
+	let mut original = File::open(path)?;
+	let (temp_path, mut temp_file) = create_temp_file(path, temp_dir)?;
+
+	let ret = copy_and_save(&mut original, &mut temp_file, save);
+
+	// The temporary file needs to be closed prior to renaming on some platforms
+	drop(temp_file);
+	drop(original);
+
+	if let Err(e) = ret {
+		if let Err(e) = std::fs::remove_file(&temp_path) {
+			log::warn!(
+				"Failed to remove temporary file `{}`: {e}",
+				temp_path.display()
+			);
+		}
+
+		return Err(e);
+	}
+
+	// Renames across file systems aren't possible, but anything else (such as copying) would
+	// give up atomicity. The finished file is left behind, so nothing is lost.
+	if let Err(e) = std::fs::rename(&temp_path, path) {
+		log::warn!(
+			"Unable to rename temporary file `{}` over the original ({e}), leaving it in place",
+			temp_path.display()
+		);
+		return Err(e.into());
+	}
+
+	Ok(())
+}
+
+fn copy_and_save<E, S>(original: &mut File, temp_file: &mut File, save: S) -> Result<(), E>
+where
+	E: From<std::io::Error>,
+	S: FnOnce(&mut File) -> Result<(), E>,
+{
+	let metadata = original.metadata()?;
+
+	std::io::copy(original, temp_file)?;
+	temp_file.rewind()?;
+
+	save(temp_file)?;
+
+	temp_file.set_permissions(metadata.permissions())?;
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::MetadataExt;
+
+		// This will fail if we aren't privileged enough to transfer ownership, which is fine.
+		if let Err(e) =
+			std::os::unix::fs::fchown(&*temp_file, Some(metadata.uid()), Some(metadata.gid()))
+		{
+			log::debug!("Unable to preserve file ownership: {e}");
+		}
+	}
+
+	temp_file.sync_all()?;
+	Ok(())
+}
+
+// Creates a new, uniquely named file in `temp_dir`, or alongside `path` if not provided
+fn create_temp_file(path: &Path, temp_dir: Option<&Path>) -> std::io::Result<(PathBuf, File)> {
+	static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+	let parent = match temp_dir.or_else(|| path.parent()) {
+		Some(parent) if !parent.as_os_str().is_empty() => parent,
+		_ => Path::new("."),
+	};
+
+	let file_name = path
+		.file_name()
+		.ok_or_else(|| std::io::Error::new(ErrorKind::InvalidInput, "path has no file name"))?;
+
+	for _ in 0..MAX_TEMP_FILE_ATTEMPTS {
+		let mut temp_name = std::ffi::OsString::from(".");
+		temp_name.push(file_name);
+		temp_name.push(format!(
+			".{}-{}.lofty-tmp",
+			std::process::id(),
+			TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+		));
+
+		let temp_path = parent.join(temp_name);
+		match OpenOptions::new()
+			.read(true)
+			.write(true)
+			.create_new(true)
+			.open(&temp_path)
+		{
+			Ok(file) => return Ok((temp_path, file)),
+			Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+			Err(e) => return Err(e),
+		}
+	}
+
+	Err(std::io::Error::new(
+		ErrorKind::AlreadyExists,
+		"unable to find an unused temporary file name",
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::{ParseOptions, WriteOptions, WriteStrategy};
+	use crate::error::{ErrorKind, LoftyError};
+	use crate::file::AudioFile;
+	use crate::mpeg::MpegFile;
+	use crate::tag::Accessor;
+
+	use std::io::Write;
+	use std::path::Path;
+
+	const TEST_ASSET: &str = "tests/files/assets/minimal/full_test.mp3";
+
+	fn dir_entries(dir: &Path) -> Vec<std::ffi::OsString> {
+		std::fs::read_dir(dir)
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name())
+			.collect()
+	}
+
+	#[test_log::test]
+	fn atomic_rename_save() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("test.mp3");
+		std::fs::copy(TEST_ASSET, &path).unwrap();
+
+		let mut file = MpegFile::read_from(
+			&mut std::fs::File::open(&path).unwrap(),
+			ParseOptions::new(),
+		)
+		.unwrap();
+		file.id3v2_mut()
+			.unwrap()
+			.set_artist(String::from("Bar artist"));
+
+		file.save_to_path(
+			&path,
+			WriteOptions::new().write_strategy(WriteStrategy::AtomicRename),
+		)
+		.unwrap();
+
+		// Only the original file should remain
+		assert_eq!(
+			dir_entries(dir.path()),
+			vec![std::ffi::OsString::from("test.mp3")]
+		);
+
+		let file = MpegFile::read_from(
+			&mut std::fs::File::open(&path).unwrap(),
+			ParseOptions::new(),
+		)
+		.unwrap();
+		assert_eq!(
+			file.id3v2().unwrap().artist().as_deref(),
+			Some("Bar artist")
+		);
+	}
+
+	#[test_log::test]
+	fn atomic_rename_custom_temp_dir() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("test.mp3");
+		std::fs::copy(TEST_ASSET, &path).unwrap();
+
+		let temp_dir = tempfile::tempdir().unwrap();
+
+		let ret: crate::error::Result<()> =
+			super::save_to_path_with_temp_dir(&path, temp_dir.path(), |file| {
+				// The copy being written to should live in the temporary directory
+				assert_eq!(dir_entries(temp_dir.path()).len(), 1);
+
+				file.write_all(b"foo")?;
+				Ok(())
+			});
+		ret.unwrap();
+
+		assert!(dir_entries(temp_dir.path()).is_empty());
+		assert_eq!(
+			dir_entries(dir.path()),
+			vec![std::ffi::OsString::from("test.mp3")]
+		);
+		assert!(std::fs::read(&path).unwrap().starts_with(b"foo"));
+	}
+
+	#[test_log::test]
+	fn atomic_rename_failure_keeps_temp_file() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("test.mp3");
+		std::fs::copy(TEST_ASSET, &path).unwrap();
+
+		let temp_dir = tempfile::tempdir().unwrap();
+
+		let ret: crate::error::Result<()> =
+			super::save_to_path_with_temp_dir(&path, temp_dir.path(), |file| {
+				file.write_all(b"foo")?;
+
+				// Make the rename fail
+				std::fs::remove_dir_all(dir.path())?;
+				Ok(())
+			});
+		assert!(ret.is_err());
+
+		// The finished file is left for the caller to recover
+		let temp_files = dir_entries(temp_dir.path());
+		assert_eq!(temp_files.len(), 1);
+		assert!(
+			std::fs::read(temp_dir.path().join(&temp_files[0]))
+				.unwrap()
+				.starts_with(b"foo")
+		);
+	}
+
+	#[test_log::test]
+	fn atomic_rename_failure_leaves_original() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("test.mp3");
+		std::fs::copy(TEST_ASSET, &path).unwrap();
+
+		let ret: crate::error::Result<()> = super::save_to_path(
+			&path,
+			WriteOptions::new().write_strategy(WriteStrategy::AtomicRename),
+			|file| {
+				// Simulate a failure partway through writing
+				file.write_all(b"garbage")?;
+				Err(LoftyError::new(ErrorKind::TooMuchData))
+			},
+		);
+		assert!(ret.is_err());
+
+		// The original is untouched, and the temporary file was cleaned up
+		assert_eq!(
+			dir_entries(dir.path()),
+			vec![std::ffi::OsString::from("test.mp3")]
+		);
+		assert_eq!(
+			std::fs::read(&path).unwrap(),
+			std::fs::read(TEST_ASSET).unwrap()
+		);
+	}
+}
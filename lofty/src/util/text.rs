@@ -330,6 +330,17 @@ pub(crate) fn trim_end_nulls_str(text: &str) -> &str {
 	text.trim_end_matches('\0')
 }
 
+/// Strip any whitespace, null terminators, and BOMs surrounding a numeric value
+///
+/// Some taggers leave these behind (e.g. `"\u{FEFF}3\0"`), which would otherwise fail to parse.
+pub(crate) fn trim_number_padding(text: &str) -> &str {
+	text.trim_matches(|c: char| c.is_whitespace() || matches!(c, '\0' | '\u{FEFF}' | '\u{FFFE}'))
+}
+
+pub(crate) fn parse_number<T: std::str::FromStr>(text: &str) -> Option<T> {
+	trim_number_padding(text).parse().ok()
+}
+
 fn utf16_encode(
 	text: &str,
 	endianness: fn(u16) -> [u8; 2],
@@ -455,4 +466,16 @@ mod tests {
 
 		assert_eq!(utf8_encode.as_slice(), TEST_STRING.as_bytes());
 	}
+
+	#[test_log::test]
+	fn padded_numbers() {
+		for padded in [" 3", "3 ", "3\0", "\u{FEFF}3", "\u{FEFF} 3\0\n"] {
+			assert_eq!(super::trim_number_padding(padded), "3", "{padded:?}");
+			assert_eq!(super::parse_number::<u32>(padded), Some(3), "{padded:?}");
+		}
+
+		for invalid in ["three", "\u{FEFF}", "\0", "3 three"] {
+			assert_eq!(super::parse_number::<u32>(invalid), None, "{invalid:?}");
+		}
+	}
 }
@@ -37,6 +37,8 @@ impl From<WavPackProperties> for FileProperties {
 			} else {
 				Some(input.channel_mask)
 			},
+			encoder_delay: None,
+			encoder_padding: None,
 		}
 	}
 }
@@ -123,3 +123,8 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::util::no_tag_test("tests/files/assets/minimal/full_test.aac", None);
 }
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.aac");
+}
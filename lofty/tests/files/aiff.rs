@@ -1,10 +1,10 @@
-use lofty::config::ParseOptions;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
 use lofty::prelude::*;
 use lofty::probe::Probe;
 use lofty::tag::TagType;
 
-use std::io::Seek;
+use std::io::{Seek, Write};
 
 #[test_log::test]
 fn read() {
@@ -98,3 +98,90 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::util::no_tag_test("tests/files/assets/minimal/full_test.aiff", None);
 }
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.aiff");
+}
+
+#[test_log::test]
+fn instrument_chunk_survives_tag_edit() {
+	use lofty::iff::aiff::{AiffFile, InstrumentChunk, InstrumentLoop};
+
+	let mut contents = std::fs::read("tests/files/assets/minimal/full_test.aiff").unwrap();
+
+	contents.extend(b"INST");
+	contents.extend(20_u32.to_be_bytes());
+	contents.extend([60, (-10_i8) as u8, 0, 127, 1, 127]);
+	contents.extend((-3_i16).to_be_bytes());
+	for value in [1_u16, 1, 2, 0, 0, 0] {
+		contents.extend(value.to_be_bytes());
+	}
+
+	// The loop's markers, with names "a" and "end"
+	contents.extend(b"MARK");
+	contents.extend(20_u32.to_be_bytes());
+	contents.extend(2_u16.to_be_bytes());
+	contents.extend(1_u16.to_be_bytes());
+	contents.extend(100_u32.to_be_bytes());
+	contents.extend([1, b'a']);
+	contents.extend(2_u16.to_be_bytes());
+	contents.extend(2000_u32.to_be_bytes());
+	contents.extend([3, b'e', b'n', b'd']);
+
+	let form_size = contents.len() as u32 - 8;
+	contents[4..8].copy_from_slice(&form_size.to_be_bytes());
+
+	let mut file = tempfile::tempfile().unwrap();
+	file.write_all(&contents).unwrap();
+
+	let expected = InstrumentChunk {
+		base_note: 60,
+		detune: -10,
+		low_note: 0,
+		high_note: 127,
+		low_velocity: 1,
+		high_velocity: 127,
+		gain: -3,
+		sustain_loop: InstrumentLoop {
+			play_mode: 1,
+			begin_marker_id: 1,
+			end_marker_id: 2,
+			begin_position: Some(100),
+			end_position: Some(2000),
+		},
+		release_loop: InstrumentLoop::default(),
+	};
+
+	file.rewind().unwrap();
+	let aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(aiff_file.instrument_chunk(), Some(&expected));
+	assert_eq!(aiff_file.markers().len(), 2);
+	assert_eq!(aiff_file.markers()[1].name, "end");
+
+	// Only change the tags
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	tagged_file
+		.tag_mut(TagType::Id3v2)
+		.unwrap()
+		.set_title(String::from("Foo title"));
+
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(aiff_file.instrument_chunk(), Some(&expected));
+	assert_eq!(
+		aiff_file.id3v2().unwrap().title().as_deref(),
+		Some("Foo title")
+	);
+}
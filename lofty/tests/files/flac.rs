@@ -1,12 +1,13 @@
 use crate::util::temp_file;
 
 use std::fs::File;
-use std::io::Seek;
+use std::io::{Read, Seek};
 
 use lofty::config::{ParseOptions, ParsingMode, WriteOptions};
 use lofty::flac::FlacFile;
-use lofty::ogg::VorbisComments;
+use lofty::ogg::{OggPictureStorage, VorbisComments};
 use lofty::prelude::*;
+use lofty::probe::Probe;
 
 #[test_log::test]
 fn multiple_vorbis_comments() {
@@ -66,3 +67,184 @@ fn retain_vendor_string() {
 	// The vendor string should be retained
 	assert_eq!(f.vorbis_comments().unwrap().vendor(), "Lavf58.76.100");
 }
+
+#[test_log::test]
+fn application_block_roundtrip() {
+	const APPLICATION_ID: u32 = u32::from_be_bytes(*b"lfty");
+
+	let mut file = temp_file("tests/files/assets/minimal/full_test.flac");
+
+	let mut f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	file.rewind().unwrap();
+	assert_eq!(f.application_blocks().count(), 0);
+
+	f.insert_application_block(APPLICATION_ID, b"Some application data".to_vec());
+	f.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	let f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	file.rewind().unwrap();
+	assert_eq!(
+		f.application_blocks().collect::<Vec<_>>(),
+		vec![(APPLICATION_ID, &b"Some application data"[..])]
+	);
+
+	// Writing just the tag should leave the block alone
+	let mut tag = f.vorbis_comments().unwrap().clone();
+	tag.set_artist(String::from("Bar artist"));
+	tag.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	let mut f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	file.rewind().unwrap();
+	assert_eq!(
+		f.vorbis_comments().unwrap().artist().as_deref(),
+		Some("Bar artist")
+	);
+	assert_eq!(f.application_blocks().count(), 1);
+
+	f.remove_application_blocks(APPLICATION_ID);
+	f.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	let f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(f.application_blocks().count(), 0);
+	assert_eq!(
+		f.vorbis_comments().unwrap().artist().as_deref(),
+		Some("Bar artist")
+	);
+}
+
+#[test_log::test]
+fn application_block_without_comments() {
+	const APPLICATION_ID: u32 = u32::from_be_bytes(*b"lfty");
+
+	let mut file = temp_file("tests/files/assets/minimal/full_test.flac");
+
+	let mut f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	let _ = f.remove_vorbis_comments();
+	f.insert_application_block(APPLICATION_ID, b"Some application data".to_vec());
+	f.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	// Reading without tags shouldn't cause the blocks to be lost on write
+	let f = FlacFile::read_from(&mut file, ParseOptions::new().read_tags(false)).unwrap();
+	file.rewind().unwrap();
+	assert_eq!(f.application_blocks().count(), 0);
+
+	f.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	let mut f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	file.rewind().unwrap();
+	assert!(f.vorbis_comments().is_none());
+	assert_eq!(f.application_blocks().count(), 1);
+
+	// Removing the only block, with nothing else to write, should still reach the file
+	f.remove_application_blocks(APPLICATION_ID);
+	f.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	let f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(f.application_blocks().count(), 0);
+}
+
+#[test_log::test]
+fn application_block_insert_without_reading_tags() {
+	const APPLICATION_ID: u32 = u32::from_be_bytes(*b"lfty");
+
+	let mut file = temp_file("tests/files/assets/minimal/full_test.flac");
+
+	let original = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	file.rewind().unwrap();
+	assert!(original.vorbis_comments().is_some());
+
+	let mut f = FlacFile::read_from(&mut file, ParseOptions::new().read_tags(false)).unwrap();
+	file.rewind().unwrap();
+
+	f.insert_application_block(APPLICATION_ID, b"Some application data".to_vec());
+	f.save_to(&mut file, WriteOptions::new()).unwrap();
+	file.rewind().unwrap();
+
+	// The block should be added alongside the existing comments and pictures
+	let f = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(
+		f.application_blocks().collect::<Vec<_>>(),
+		vec![(APPLICATION_ID, &b"Some application data"[..])]
+	);
+	assert_eq!(f.vorbis_comments(), original.vorbis_comments());
+	assert_eq!(f.pictures(), original.pictures());
+}
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.flac");
+}
+
+#[test_log::test]
+fn write_requires_rewrite() {
+	let read = |file: &mut File| {
+		file.rewind().unwrap();
+		Probe::new(file)
+			.options(ParseOptions::new().read_properties(false))
+			.guess_file_type()
+			.unwrap()
+			.read()
+			.unwrap()
+	};
+
+	let mut file = temp_file("tests/files/assets/minimal/full_test.flac");
+	let original_len = file.metadata().unwrap().len();
+
+	let mut original = Vec::new();
+	file.read_to_end(&mut original).unwrap();
+
+	// The file has a large PADDING block to grow into, so nothing after it has to move
+	let mut tagged_file = read(&mut file);
+	tagged_file
+		.primary_tag_mut()
+		.unwrap()
+		.set_title("a".repeat(1000));
+	assert!(!tagged_file.write_requires_rewrite());
+
+	file.rewind().unwrap();
+	tagged_file.save_to(&mut file, WriteOptions::new()).unwrap();
+	assert_eq!(file.metadata().unwrap().len(), original_len);
+
+	let mut written = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut written).unwrap();
+
+	// STREAMINFO, the Vorbis comments, and the PADDING block come before the audio data
+	let audio_start = 8263;
+	assert_eq!(written[audio_start..], original[audio_start..]);
+
+	let mut tagged_file = read(&mut file);
+	assert_eq!(
+		tagged_file.primary_tag().unwrap().title().as_deref(),
+		Some(&*"a".repeat(1000))
+	);
+
+	// Too large for the padding, the audio data has to be moved
+	tagged_file
+		.primary_tag_mut()
+		.unwrap()
+		.set_title("a".repeat(10_000));
+	assert!(tagged_file.write_requires_rewrite());
+
+	file.rewind().unwrap();
+	tagged_file.save_to(&mut file, WriteOptions::new()).unwrap();
+	assert!(file.metadata().unwrap().len() > original_len);
+
+	let mut tagged_file = read(&mut file);
+	assert_eq!(
+		tagged_file.primary_tag().unwrap().title().as_deref(),
+		Some(&*"a".repeat(10_000))
+	);
+
+	// Nothing is written
+	tagged_file.clear();
+	assert!(!tagged_file.write_requires_rewrite());
+}
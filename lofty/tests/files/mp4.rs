@@ -1,10 +1,14 @@
-use lofty::config::ParseOptions;
+use crate::util::temp_file;
+use lofty::config::{ParseOptions, ParsingMode, WriteOptions};
+use lofty::error::ErrorKind;
 use lofty::file::FileType;
+use lofty::mp4::{Atom, AtomData, AtomIdent, Mp4File};
 use lofty::prelude::*;
 use lofty::probe::Probe;
-use lofty::tag::TagType;
+use lofty::tag::{Tag, TagType};
 
-use std::io::Seek;
+use std::borrow::Cow;
+use std::io::{Cursor, Read, Seek};
 
 #[test_log::test]
 fn read() {
@@ -73,3 +77,96 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::util::no_tag_test("tests/files/assets/minimal/m4a_codec_aac.m4a", None);
 }
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/m4a_codec_aac.m4a");
+}
+
+#[test_log::test]
+fn read_itunsmpb() {
+	let mut file = temp_file("tests/files/assets/minimal/m4a_codec_aac.m4a");
+	let mut mp4_file = Mp4File::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(mp4_file.properties().encoder_delay(), None);
+	assert_eq!(mp4_file.properties().encoder_padding(), None);
+
+	mp4_file.ilst_mut().unwrap().insert(Atom::new(
+		AtomIdent::Freeform {
+			mean: Cow::Borrowed("com.apple.iTunes"),
+			name: Cow::Borrowed("iTunSMPB"),
+		},
+		AtomData::UTF8(String::from(
+			" 00000000 00000840 000001CA 00000000003F31F6 00000000 00000000 00000000 00000000",
+		)),
+	));
+
+	file.rewind().unwrap();
+	mp4_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = lofty::read_from(&mut file).unwrap();
+	assert_eq!(tagged_file.properties().encoder_delay(), Some(0x840));
+	assert_eq!(tagged_file.properties().encoder_padding(), Some(0x1CA));
+}
+
+// Marks the only audio sample entry as FairPlay encrypted
+fn encrypted_m4a() -> Vec<u8> {
+	let mut content = std::fs::read("tests/files/assets/minimal/m4a_codec_aac.m4a").unwrap();
+	let sample_entry_pos = content
+		.windows(4)
+		.position(|window| window == b"mp4a")
+		.unwrap();
+	content[sample_entry_pos..sample_entry_pos + 4].copy_from_slice(b"drms");
+
+	content
+}
+
+#[test_log::test]
+fn read_encrypted() {
+	let content = encrypted_m4a();
+
+	let mp4_file = Mp4File::read_from(&mut Cursor::new(&content), ParseOptions::new()).unwrap();
+	assert!(mp4_file.properties().is_drm_protected());
+
+	// The metadata isn't encrypted, and should still be available
+	let ilst = mp4_file.ilst().unwrap();
+	assert_eq!(ilst.artist().as_deref(), Some("Foo artist"));
+
+	let err = Mp4File::read_from(
+		&mut Cursor::new(&content),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap_err();
+	assert!(matches!(err.kind(), ErrorKind::EncryptedStream));
+}
+
+#[test_log::test]
+fn bpm_is_16_bit() {
+	let mut file = temp_file("tests/files/assets/minimal/m4a_codec_aac.m4a");
+
+	let mut tag = Tag::new(TagType::Mp4Ilst);
+	tag.insert_text(ItemKey::IntegerBpm, String::from("128"));
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.rewind().unwrap();
+	let mut content = Vec::new();
+	file.read_to_end(&mut content).unwrap();
+
+	// tmpo, then a data atom with the type `BE signed integer` (21), holding 2 bytes
+	let tmpo_pos = content
+		.windows(4)
+		.position(|window| window == b"tmpo")
+		.unwrap();
+	assert_eq!(
+		&content[tmpo_pos + 4..tmpo_pos + 22],
+		&[
+			0, 0, 0, 18, b'd', b'a', b't', b'a', 0, 0, 0, 21, 0, 0, 0, 0, 0, 128
+		]
+	);
+
+	let mp4_file = Mp4File::read_from(&mut Cursor::new(&content), ParseOptions::new()).unwrap();
+	let tag: Tag = mp4_file.ilst().unwrap().clone().into();
+	assert_eq!(tag.get_string(ItemKey::IntegerBpm), Some("128"));
+}
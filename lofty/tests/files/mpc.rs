@@ -84,6 +84,11 @@ macro_rules! generate_tests {
 			fn [<read_no_tags_ $stream_version>]() {
 				crate::util::no_tag_test($path, None);
 			}
+
+			#[test_log::test]
+			fn [<roundtrip_stable_ $stream_version>]() {
+				crate::util::assert_roundtrip_stable($path);
+			}
 		}
 	};
 }
@@ -1,14 +1,15 @@
 use crate::util::temp_file;
+use lofty::TextEncoding;
 use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::{BoundTaggedFile, FileType};
-use lofty::id3::v2::{Frame, FrameId, Id3v2Tag, KeyValueFrame};
+use lofty::id3::v2::{Frame, FrameId, GeneralEncapsulatedObject, Id3v2Tag, KeyValueFrame};
 use lofty::mpeg::MpegFile;
 use lofty::prelude::*;
 use lofty::probe::Probe;
-use lofty::tag::{Tag, TagType};
+use lofty::tag::{ItemValue, Tag, TagItem, TagType};
 
 use std::borrow::Cow;
-use std::io::Seek;
+use std::io::{Read, Seek};
 
 #[test_log::test]
 fn read() {
@@ -264,6 +265,49 @@ fn test_bound_tagged_into_inner() {
 	assert_eq!(tag.disk(), Some(123));
 }
 
+#[test_log::test]
+fn get_or_insert_tag_on_untagged_file() {
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+	for tag_type in [TagType::Id3v2, TagType::Id3v1, TagType::Ape] {
+		tag_type.remove_from(&mut file).unwrap();
+		file.rewind().unwrap();
+	}
+
+	let mut tagged_file = BoundTaggedFile::read_from(file, ParseOptions::default()).unwrap();
+	assert!(tagged_file.tags().is_empty());
+
+	// The file type doesn't support this tag
+	assert!(
+		tagged_file
+			.get_or_insert_tag(TagType::VorbisComments)
+			.is_none()
+	);
+
+	let tag = tagged_file.get_or_insert_tag(TagType::Id3v2).unwrap();
+	assert!(tag.is_empty());
+	tag.set_title(String::from("Foo title"));
+
+	// The existing tag should be returned
+	assert_eq!(
+		tagged_file
+			.get_or_insert_tag(TagType::Id3v2)
+			.unwrap()
+			.title()
+			.as_deref(),
+		Some("Foo title")
+	);
+
+	tagged_file.save(WriteOptions::default()).unwrap();
+
+	let mut file = tagged_file.into_inner();
+	file.rewind().unwrap();
+	let tagged_file = BoundTaggedFile::read_from(file, ParseOptions::default()).unwrap();
+
+	assert_eq!(tagged_file.tags().len(), 1);
+	let tag = tagged_file.tag(TagType::Id3v2).unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+}
+
 #[test_log::test]
 fn save_total_of_track_and_disk_to_id3v2() {
 	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
@@ -366,6 +410,153 @@ fn remove_ape() {
 	crate::util::remove_tag_test("tests/files/assets/minimal/full_test.mp3", TagType::Ape);
 }
 
+#[test_log::test]
+fn convert_id3v1_to_id3v2() {
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let _ = tagged_file.remove(TagType::Id3v2);
+	let id3v1 = tagged_file.tag_mut(TagType::Id3v1).unwrap();
+	id3v1.set_genre(String::from("Rock"));
+	let id3v1 = id3v1.clone();
+
+	let id3v2 = tagged_file
+		.convert_tag(TagType::Id3v1, TagType::Id3v2, true)
+		.unwrap();
+	assert_eq!(id3v2.tag_type(), TagType::Id3v2);
+	assert_eq!(id3v2.artist().as_deref(), Some("Bar artist"));
+	assert_eq!(id3v2.title(), id3v1.title());
+	assert_eq!(id3v2.album(), id3v1.album());
+	assert_eq!(id3v2.genre().as_deref(), Some("Rock"));
+
+	assert!(!tagged_file.contains_tag_type(TagType::Id3v1));
+
+	// Converting a tag the file no longer has does nothing
+	assert!(
+		tagged_file
+			.convert_tag(TagType::Id3v1, TagType::Id3v2, false)
+			.is_none()
+	);
+
+	// MP3 files can't contain Vorbis Comments
+	assert!(
+		tagged_file
+			.convert_tag(TagType::Id3v2, TagType::VorbisComments, false)
+			.is_none()
+	);
+	assert!(tagged_file.contains_tag_type(TagType::Id3v2));
+
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::util::verify_artist(&tagged_file, TagType::Id3v2, "Bar artist", 1);
+	let id3v2 = tagged_file.tag(TagType::Id3v2).unwrap();
+	assert_eq!(id3v2.genre().as_deref(), Some("Rock"));
+}
+
+#[test_log::test]
+fn convert_id3v2_to_id3v1() {
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let id3v2 = tagged_file.tag_mut(TagType::Id3v2).unwrap();
+	// 29 bytes, followed by a 2 byte character that would be split at 30 bytes
+	id3v2.set_title(format!("{}é", "a".repeat(29)));
+	id3v2.set_comment("b".repeat(40));
+	id3v2.set_genre(String::from("Not an ID3v1 genre"));
+	id3v2.set_track(300);
+
+	let id3v1 = tagged_file
+		.convert_tag(TagType::Id3v2, TagType::Id3v1, false)
+		.unwrap();
+	assert_eq!(id3v1.title().as_deref(), Some("a".repeat(29).as_str()));
+	assert_eq!(id3v1.artist().as_deref(), Some("Foo artist"));
+	assert_eq!(id3v1.comment().as_deref(), Some("b".repeat(28).as_str()));
+	assert!(id3v1.genre().is_none());
+	assert!(id3v1.track().is_none());
+
+	// The source tag is untouched
+	let id3v2 = tagged_file.tag(TagType::Id3v2).unwrap();
+	assert_eq!(id3v2.comment().as_deref(), Some("b".repeat(40).as_str()));
+}
+
+#[test_log::test]
+fn id3v2_keys() {
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let id3v2 = tagged_file.tag_mut(TagType::Id3v2).unwrap();
+	assert_eq!(id3v2.keys().collect::<Vec<_>>(), [ItemKey::TrackArtist]);
+
+	id3v2.push(TagItem::new(
+		ItemKey::TrackArtist,
+		ItemValue::Text(String::from("Bar artist")),
+	));
+	id3v2.set_title(String::from("Foo title"));
+	id3v2.set_album(String::from("Foo album"));
+	id3v2.set_genre(String::from("Rock"));
+	id3v2.set_comment(String::from("Foo comment"));
+	id3v2.set_track(1);
+
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let id3v2 = tagged_file.tag(TagType::Id3v2).unwrap();
+
+	// The two artists only appear once
+	let mut keys = id3v2.keys().collect::<Vec<_>>();
+	assert_eq!(keys.len(), 6);
+
+	for key in [
+		ItemKey::TrackArtist,
+		ItemKey::TrackTitle,
+		ItemKey::AlbumTitle,
+		ItemKey::Genre,
+		ItemKey::Comment,
+		ItemKey::TrackNumber,
+	] {
+		assert!(keys.contains(&key), "{key:?} is missing");
+		keys.retain(|k| *k != key);
+	}
+
+	assert!(keys.is_empty());
+}
+
 #[test_log::test]
 fn read_and_write_tpil_frame() {
 	let key_value_pairs = vec![
@@ -423,3 +614,200 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::util::no_tag_test("tests/files/assets/minimal/full_test.mp3", None);
 }
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.mp3");
+}
+
+#[test_log::test]
+fn read_encoder_delay_and_padding() {
+	let file = lofty::read_from_path("tests/files/assets/minimal/full_test.mp3").unwrap();
+
+	// Read from the LAME header, which FFmpeg writes as well
+	let properties = file.properties();
+	assert_eq!(properties.encoder_delay(), Some(576));
+	assert_eq!(properties.encoder_padding(), Some(1150));
+
+	// No Xing header, so no LAME header
+	let file = lofty::read_from_path("tests/files/assets/minimal/full_test.mp2").unwrap();
+	assert_eq!(file.properties().encoder_delay(), None);
+	assert_eq!(file.properties().encoder_padding(), None);
+}
+
+#[test_log::test]
+fn encapsulated_object_survives_generic_write() {
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+
+	let mut mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let object = GeneralEncapsulatedObject::new(
+		TextEncoding::Latin1,
+		Some(String::from("application/octet-stream")),
+		None,
+		Some(String::from("Serato Markers2")),
+		vec![0, 1, 2, 3, 0xFF],
+	);
+	let _ = mpeg_file
+		.id3v2_mut()
+		.unwrap()
+		.insert_encapsulated_object(object.clone());
+
+	file.rewind().unwrap();
+	mpeg_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// Modify the tag through the generic `Tag` API, which doesn't know about GEOB frames
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	{
+		let tag = tagged_file.tag_mut(TagType::Id3v2).unwrap();
+		tag.set_artist(String::from("Bar artist"));
+	}
+
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let id3v2 = mpeg_file.id3v2().unwrap();
+	assert_eq!(id3v2.artist().as_deref(), Some("Bar artist"));
+
+	let read_object = id3v2.encapsulated_object("Serato Markers2").unwrap();
+	assert_eq!(read_object.mime_type, object.mime_type);
+	assert_eq!(read_object.file_name, None);
+	assert_eq!(read_object.data, object.data);
+}
+
+#[test_log::test]
+fn id3v2_extended_header_and_footer() {
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+	let original = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	file.rewind().unwrap();
+	let mut mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let id3v2 = mpeg_file.id3v2_mut().unwrap();
+	let mut flags = *id3v2.flags();
+	flags.crc = true;
+	flags.footer = true;
+	id3v2.set_flags(flags);
+
+	file.rewind().unwrap();
+	mpeg_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The extended header and footer shouldn't be mistaken for frames or audio
+	file.rewind().unwrap();
+	let mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let id3v2 = mpeg_file.id3v2().unwrap();
+	assert!(id3v2.flags().crc);
+	assert!(id3v2.flags().footer);
+	assert_eq!(id3v2.len(), original.id3v2().unwrap().len());
+	assert_eq!(id3v2.title(), original.id3v2().unwrap().title());
+	assert_eq!(mpeg_file.properties(), original.properties());
+
+	// Both should be stripped along with the rest of the tag
+	file.rewind().unwrap();
+	TagType::Id3v2.remove_from(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mut start = [0; 2];
+	file.read_exact(&mut start).unwrap();
+	assert_eq!(start, [0xFF, 0xFB]);
+
+	file.rewind().unwrap();
+	let mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(mpeg_file.id3v2().is_none());
+	assert_eq!(mpeg_file.properties(), original.properties());
+}
+
+#[test_log::test]
+fn write_requires_rewrite() {
+	let read = |file: &mut std::fs::File| {
+		file.rewind().unwrap();
+		Probe::new(file)
+			.options(ParseOptions::new().read_properties(false))
+			.guess_file_type()
+			.unwrap()
+			.read()
+			.unwrap()
+	};
+
+	let mut file = temp_file("tests/files/assets/minimal/full_test.mp3");
+
+	// The ID3v2 tag has no padding, so it can't grow
+	let mut tagged_file = read(&mut file);
+	tagged_file
+		.tag_mut(TagType::Id3v2)
+		.unwrap()
+		.set_title("a".repeat(100));
+	assert!(tagged_file.write_requires_rewrite());
+
+	// Tags after the audio data can grow freely
+	let mut tagged_file = read(&mut file);
+	let _ = tagged_file.remove(TagType::Id3v2);
+	tagged_file
+		.tag_mut(TagType::Ape)
+		.unwrap()
+		.set_title("a".repeat(100));
+	assert!(!tagged_file.write_requires_rewrite());
+
+	// Grow the tag, so it's written with some padding
+	let mut tagged_file = read(&mut file);
+	tagged_file
+		.tag_mut(TagType::Id3v2)
+		.unwrap()
+		.set_title(String::from("Foo title"));
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::new().preferred_padding(1024))
+		.unwrap();
+	let padded_len = file.metadata().unwrap().len();
+
+	// The new tag fits in the padding, so it's written over the old one
+	let mut tagged_file = read(&mut file);
+	tagged_file
+		.tag_mut(TagType::Id3v2)
+		.unwrap()
+		.set_title("a".repeat(500));
+	assert!(!tagged_file.write_requires_rewrite());
+
+	file.rewind().unwrap();
+	tagged_file.save_to(&mut file, WriteOptions::new()).unwrap();
+	assert_eq!(file.metadata().unwrap().len(), padded_len);
+
+	let mut tagged_file = read(&mut file);
+	assert_eq!(
+		tagged_file.tag(TagType::Id3v2).unwrap().title().as_deref(),
+		Some(&*"a".repeat(500))
+	);
+
+	// Too large for the padding, the audio data has to be moved
+	tagged_file
+		.tag_mut(TagType::Id3v2)
+		.unwrap()
+		.set_title("a".repeat(2000));
+	assert!(tagged_file.write_requires_rewrite());
+
+	file.rewind().unwrap();
+	tagged_file.save_to(&mut file, WriteOptions::new()).unwrap();
+	assert!(file.metadata().unwrap().len() > padded_len);
+
+	let mut tagged_file = read(&mut file);
+	assert_eq!(
+		tagged_file.tag(TagType::Id3v2).unwrap().title().as_deref(),
+		Some(&*"a".repeat(2000))
+	);
+
+	// Empty tags are removed from the file
+	tagged_file.tag_mut(TagType::Id3v2).unwrap().clear();
+	assert!(tagged_file.write_requires_rewrite());
+}
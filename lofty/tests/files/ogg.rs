@@ -1,11 +1,20 @@
 use crate::util::temp_file;
-use lofty::config::{ParseOptions, WriteOptions};
+use lofty::config::{ParseOptions, ParsingMode, WriteOptions};
+use lofty::error::ErrorKind;
 use lofty::file::FileType;
+use lofty::ogg::{
+	Chapter, OggBuilder, OggPictureStorage, OpusFile, VorbisComments, VorbisFile,
+	rewrite_comment_header, update_comment_header_in_place,
+};
+use lofty::picture::{MimeType, Picture, PictureInformation, PictureType};
 use lofty::prelude::*;
 use lofty::probe::Probe;
-use lofty::tag::TagType;
+use lofty::tag::{Tag, TagType};
 
-use std::io::Seek;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use ogg_pager::{Packets, Page};
 
 // The tests for OGG Opus/Vorbis/Speex are nearly identical
 // We have the vendor string and a title stored in the tag
@@ -83,6 +92,789 @@ fn speex_remove() {
 	)
 }
 
+#[test_log::test]
+fn opus_roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.opus");
+}
+
+#[test_log::test]
+fn vorbis_roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.ogg");
+}
+
+#[test_log::test]
+fn speex_roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.spx");
+}
+
+#[test_log::test]
+fn vorbis_insert_unsupported_tag() {
+	let mut tagged_file =
+		lofty::read_from_path("tests/files/assets/minimal/full_test.ogg").unwrap();
+
+	assert!(tagged_file.insert_tag(Tag::new(TagType::Ape)).is_none());
+	assert!(!tagged_file.contains_tag_type(TagType::Ape));
+
+	assert!(
+		tagged_file
+			.insert_tag(Tag::new(TagType::VorbisComments))
+			.is_some()
+	);
+	assert!(tagged_file.contains_tag_type(TagType::VorbisComments));
+}
+
+#[test_log::test]
+fn opus_encoder_delay() {
+	let file = lofty::read_from_path("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	// Opus only stores the pre-skip
+	assert_eq!(file.properties().encoder_delay(), Some(312));
+	assert_eq!(file.properties().encoder_padding(), None);
+}
+
+#[test_log::test]
+fn opus_extract_packets() {
+	let mut file = std::fs::File::open("tests/files/assets/minimal/full_test.opus").unwrap();
+	let packets = OpusFile::extract_packets(&mut file).unwrap();
+	assert_eq!(packets.len(), 72);
+}
+
+#[test_log::test]
+fn opus_extract_packets_across_pages() {
+	let mut identification_header = b"OpusHead".to_vec();
+	identification_header.extend([1, 2]);
+	identification_header.extend(312_u16.to_le_bytes());
+	identification_header.extend(48000_u32.to_le_bytes());
+	identification_header.extend([0, 0, 0]);
+
+	// The second packet is large enough to span multiple pages, and the third needs a
+	// terminating 0 lacing value
+	let audio_packets = vec![vec![1; 100], vec![2; 20_000], vec![3; 510], vec![4; 1]];
+
+	let file = OggBuilder::new(FileType::Opus)
+		.id_header(identification_header)
+		.audio_packets(audio_packets.clone())
+		.samples_per_packet(960)
+		.build()
+		.unwrap();
+
+	let packets = OpusFile::extract_packets(&mut Cursor::new(file)).unwrap();
+	assert_eq!(packets, audio_packets);
+}
+
+#[test_log::test]
+fn opus_gapless_info() {
+	// 80ms of pre-skip
+	let mut identification_header = b"OpusHead".to_vec();
+	identification_header.extend([1, 2]);
+	identification_header.extend(3840_u16.to_le_bytes());
+	identification_header.extend(48000_u32.to_le_bytes());
+	identification_header.extend([0, 0, 0]);
+
+	// Five 20ms CELT packets, the third large enough to span pages with a granule position of -1
+	let mut audio_packets = vec![vec![0xF8; 100]; 5];
+	audio_packets[2] = vec![0xF8; 70_000];
+
+	let file = OggBuilder::new(FileType::Opus)
+		.id_header(identification_header)
+		.audio_packets(audio_packets)
+		.samples_per_packet(960)
+		.build()
+		.unwrap();
+
+	// Only 360 samples of the final packet are real audio
+	let mut reader = Cursor::new(&file);
+	let mut pages = Vec::new();
+	while (reader.position() as usize) < file.len() {
+		pages.push(Page::read(&mut reader).unwrap());
+	}
+
+	assert!(pages.iter().any(|page| page.header().abgp == u64::MAX));
+
+	let last_page = pages.last_mut().unwrap();
+	assert_eq!(last_page.header().abgp, 4800);
+	last_page.header_mut().abgp = 4200;
+	last_page.gen_crc();
+
+	let file = pages
+		.iter()
+		.flat_map(|page| page.as_bytes())
+		.collect::<Vec<u8>>();
+
+	let gapless_info = OpusFile::gapless_info(&mut Cursor::new(&file)).unwrap();
+	assert_eq!(gapless_info.pre_skip(), 3840);
+	assert_eq!(gapless_info.total_samples(), 360);
+	assert_eq!(gapless_info.trailing_padding(), 600);
+}
+
+#[test_log::test]
+fn opus_gapless_info_start_offset() {
+	let mut identification_header = b"OpusHead".to_vec();
+	identification_header.extend([1, 2]);
+	identification_header.extend(3840_u16.to_le_bytes());
+	identification_header.extend(48000_u32.to_le_bytes());
+	identification_header.extend([0, 0, 0]);
+
+	let file = OggBuilder::new(FileType::Opus)
+		.id_header(identification_header)
+		.audio_packets(vec![vec![0xF8; 100]; 5])
+		.samples_per_packet(960)
+		.build()
+		.unwrap();
+
+	// Shift the audio pages, as if the stream was cut from the middle of another
+	let mut reader = Cursor::new(&file);
+	let mut pages = Vec::new();
+	while (reader.position() as usize) < file.len() {
+		pages.push(Page::read(&mut reader).unwrap());
+	}
+
+	for page in pages.iter_mut().filter(|page| page.header().abgp != 0) {
+		page.header_mut().abgp += 48000;
+		page.gen_crc();
+	}
+
+	let last_page = pages.last_mut().unwrap();
+	assert_eq!(last_page.header().abgp, 52800);
+	last_page.header_mut().abgp = 52200;
+	last_page.gen_crc();
+
+	let file = pages
+		.iter()
+		.flat_map(|page| page.as_bytes())
+		.collect::<Vec<u8>>();
+
+	let gapless_info = OpusFile::gapless_info(&mut Cursor::new(&file)).unwrap();
+	assert_eq!(gapless_info.pre_skip(), 3840);
+	assert_eq!(gapless_info.total_samples(), 360);
+	assert_eq!(gapless_info.trailing_padding(), 600);
+}
+
+#[test_log::test]
+fn opus_rewrite_comment_header() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	let mut comments = VorbisComments::new();
+	comments.set_title(String::from("Bar title"));
+
+	let mut out = Vec::new();
+	let audio_rewritten =
+		rewrite_comment_header(&mut Cursor::new(&original), &mut out, &comments, None).unwrap();
+	assert!(!audio_rewritten);
+
+	// The audio pages should be copied byte for byte
+	let header_end = |bytes: &[u8]| {
+		let mut reader = Cursor::new(bytes);
+		Packets::read_count(&mut reader, 2).unwrap();
+		reader.position() as usize
+	};
+	assert_eq!(&out[header_end(&out)..], &original[header_end(&original)..]);
+
+	let file = OpusFile::read_from(&mut Cursor::new(&out), ParseOptions::new()).unwrap();
+	assert_eq!(file.vorbis_comments().title().as_deref(), Some("Bar title"));
+	// The vendor is retained
+	assert_eq!(file.vorbis_comments().vendor(), "Lavf58.76.100");
+}
+
+#[test_log::test]
+fn opus_rewrite_comment_header_grows() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	// Large enough to push the comment header onto multiple pages
+	let mut comments = VorbisComments::new();
+	comments.set_comment("a".repeat(100_000));
+
+	let mut out = Vec::new();
+	let audio_rewritten = rewrite_comment_header(
+		&mut Cursor::new(&original),
+		&mut out,
+		&comments,
+		Some("Lofty"),
+	)
+	.unwrap();
+	assert!(audio_rewritten);
+
+	// Every page after the comment header has to be renumbered
+	let mut reader = Cursor::new(&out);
+	let mut sequence_number = 0;
+	while (reader.position() as usize) < out.len() {
+		let page = Page::read(&mut reader).unwrap();
+		assert_eq!(page.header().sequence_number, sequence_number);
+		sequence_number += 1;
+	}
+
+	assert_eq!(
+		OpusFile::extract_packets(&mut Cursor::new(&out)).unwrap(),
+		OpusFile::extract_packets(&mut Cursor::new(&original)).unwrap()
+	);
+
+	let file = OpusFile::read_from(&mut Cursor::new(&out), ParseOptions::new()).unwrap();
+	assert_eq!(file.vorbis_comments().vendor(), "Lofty");
+	assert_eq!(file.vorbis_comments().comment().unwrap().len(), 100_000);
+}
+
+#[test_log::test]
+fn opus_update_comment_header_in_place() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	// Shorter than the original comments, the rest will be padding
+	let mut comments = VorbisComments::new();
+	comments.set_title(String::from("Bar"));
+
+	let mut file = Cursor::new(original.clone());
+	let modified = update_comment_header_in_place(&mut file, &comments, None).unwrap();
+
+	// Only the comment header page should be touched
+	let mut reader = Cursor::new(&original);
+	let _identification_page = Page::read(&mut reader).unwrap();
+	let comment_page = Page::read(&mut reader).unwrap();
+	assert_eq!(modified, comment_page.header().start..comment_page.end);
+
+	let updated = file.into_inner();
+	let (start, end) = (modified.start as usize, modified.end as usize);
+	assert_eq!(updated.len(), original.len());
+	assert_eq!(updated[..start], original[..start]);
+	assert_eq!(updated[end..], original[end..]);
+	assert_ne!(updated[start..end], original[start..end]);
+
+	let file = OpusFile::read_from(
+		&mut Cursor::new(&updated),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+	assert_eq!(file.vorbis_comments().title().as_deref(), Some("Bar"));
+	assert_eq!(file.vorbis_comments().vendor(), "Lavf58.76.100");
+	assert!(file.vorbis_comments().padding_len() > 0);
+}
+
+#[test_log::test]
+fn opus_update_comment_header_in_place_too_large() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	let mut comments = VorbisComments::new();
+	comments.set_comment("a".repeat(100_000));
+
+	let mut file = Cursor::new(original.clone());
+	assert!(update_comment_header_in_place(&mut file, &comments, None).is_err());
+
+	// Nothing is written if the comments don't fit
+	assert_eq!(file.into_inner(), original);
+}
+
+#[test_log::test]
+fn vorbis_update_comment_header_in_place() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+
+	// The comment header shares its page with the setup header
+	let mut reader = Cursor::new(&original);
+	let _identification_page = Page::read(&mut reader).unwrap();
+	let header_page = Page::read(&mut reader).unwrap();
+	let comment_header_len = usize::from(header_page.header().segments()[0]);
+	assert!(comment_header_len < header_page.content().len());
+
+	let mut comments = VorbisComments::new();
+	comments.set_title(String::from("Bar"));
+
+	let mut file = Cursor::new(original.clone());
+	let modified = update_comment_header_in_place(&mut file, &comments, None).unwrap();
+	assert_eq!(modified, header_page.header().start..header_page.end);
+
+	let updated = file.into_inner();
+	let (start, end) = (modified.start as usize, modified.end as usize);
+	assert_eq!(updated.len(), original.len());
+	assert_eq!(updated[..start], original[..start]);
+	assert_eq!(updated[end..], original[end..]);
+
+	// The page layout and the setup header are left untouched
+	let mut reader = Cursor::new(&updated);
+	let _identification_page = Page::read(&mut reader).unwrap();
+	let updated_page = Page::read(&mut reader).unwrap();
+	assert_eq!(
+		updated_page.header().segments(),
+		header_page.header().segments()
+	);
+	assert_eq!(
+		updated_page.content()[comment_header_len..],
+		header_page.content()[comment_header_len..]
+	);
+
+	let file = VorbisFile::read_from(
+		&mut Cursor::new(&updated),
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+	assert_eq!(file.vorbis_comments().title().as_deref(), Some("Bar"));
+	assert_eq!(file.vorbis_comments().vendor(), "Lavf58.76.100");
+
+	let expected = VorbisFile::read_from(&mut Cursor::new(&original), ParseOptions::new()).unwrap();
+	assert_eq!(file.properties(), expected.properties());
+}
+
+#[test_log::test]
+fn opus_write_requires_rewrite() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+	let mut tagged_file = crate::util::read("tests/files/assets/minimal/full_test.opus");
+
+	// The comment header is on a page of its own, so smaller comments can be written in place
+	let tag = tagged_file.primary_tag_mut().unwrap();
+	tag.clear();
+	tag.set_title(String::from("Bar"));
+	assert!(!tagged_file.write_requires_rewrite());
+
+	tagged_file.save(WriteOptions::new()).unwrap();
+
+	// The space is still known after saving
+	assert!(!tagged_file.write_requires_rewrite());
+
+	let mut file = tagged_file.into_inner();
+	let mut written = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut written).unwrap();
+
+	// Only the comment header page is touched
+	let mut reader = Cursor::new(&original);
+	let _identification_page = Page::read(&mut reader).unwrap();
+	let comment_page = Page::read(&mut reader).unwrap();
+	let (start, end) = (
+		comment_page.header().start as usize,
+		comment_page.end as usize,
+	);
+	assert_eq!(written.len(), original.len());
+	assert_eq!(written[..start], original[..start]);
+	assert_eq!(written[end..], original[end..]);
+
+	// Too large for the original comment header, the stream has to be rewritten
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(file)
+		.options(ParseOptions::new())
+		.guess_file_type()
+		.unwrap()
+		.read_bound()
+		.unwrap();
+	assert_eq!(
+		tagged_file.primary_tag().unwrap().title().as_deref(),
+		Some("Bar")
+	);
+
+	tagged_file
+		.primary_tag_mut()
+		.unwrap()
+		.set_comment("a".repeat(100_000));
+	assert!(tagged_file.write_requires_rewrite());
+
+	tagged_file.save(WriteOptions::new()).unwrap();
+
+	let mut file = tagged_file.into_inner();
+	assert!(file.metadata().unwrap().len() > original.len() as u64);
+
+	// The audio packets are carried over
+	file.rewind().unwrap();
+	assert_eq!(
+		OpusFile::extract_packets(&mut file).unwrap(),
+		OpusFile::extract_packets(&mut Cursor::new(&original)).unwrap()
+	);
+
+	file.rewind().unwrap();
+	let file = OpusFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(file.vorbis_comments().title().as_deref(), Some("Bar"));
+	assert_eq!(file.vorbis_comments().comment().unwrap().len(), 100_000);
+}
+
+#[test_log::test]
+fn opus_chapters_roundtrip() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+
+	let chapters = vec![
+		Chapter::new(Duration::ZERO, Some(String::from("Intro"))),
+		Chapter::new(Duration::from_millis(500), None),
+		Chapter::new(
+			Duration::from_millis(3_723_250),
+			Some(String::from("Outro")),
+		),
+	];
+
+	let mut comments = VorbisComments::new();
+	comments.set_title(String::from("Foo title"));
+	comments.set_chapters(chapters.clone());
+
+	let mut out = Vec::new();
+	rewrite_comment_header(&mut Cursor::new(&original), &mut out, &comments, None).unwrap();
+
+	let file = OpusFile::read_from(&mut Cursor::new(&out), ParseOptions::new()).unwrap();
+	let comments = file.vorbis_comments();
+	assert_eq!(comments.chapters(), chapters);
+	assert_eq!(comments.get("CHAPTER003"), Some("01:02:03.250"));
+	assert_eq!(comments.get("CHAPTER003NAME"), Some("Outro"));
+	assert_eq!(comments.title().as_deref(), Some("Foo title"));
+}
+
+#[test_log::test]
+fn opus_insert_unsupported_picture_type() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap();
+	let picture_data = std::fs::read("tests/picture/assets/png_640x628.png").unwrap();
+
+	let picture = Picture::unchecked(picture_data)
+		.pic_type(PictureType::Undefined(42))
+		.mime_type(MimeType::Png)
+		.build();
+
+	let mut comments = VorbisComments::new();
+
+	// Rejected outright in strict mode
+	let err = comments
+		.insert_picture_checked(picture.clone(), None, ParsingMode::Strict)
+		.unwrap_err();
+	assert!(matches!(err.kind(), ErrorKind::UnsupportedPicture));
+	assert!(comments.pictures().is_empty());
+
+	// Downgraded to `Other` otherwise
+	comments
+		.insert_picture_checked(picture, None, ParsingMode::Relaxed)
+		.unwrap();
+
+	let mut out = Vec::new();
+	rewrite_comment_header(&mut Cursor::new(&original), &mut out, &comments, None).unwrap();
+
+	let file = OpusFile::read_from(&mut Cursor::new(&out), ParseOptions::new()).unwrap();
+	let pictures = file.vorbis_comments().pictures();
+	assert_eq!(pictures.len(), 1);
+
+	let (picture, information) = &pictures[0];
+	assert_eq!(picture.pic_type(), PictureType::Other);
+	assert_eq!(information.width, 640);
+	assert_eq!(information.height, 628);
+}
+
+#[test_log::test]
+fn vorbis_page_granules() {
+	let path = "tests/files/assets/minimal/full_test.ogg";
+
+	let granules = VorbisFile::page_granules(&mut std::fs::File::open(path).unwrap()).unwrap();
+	assert_eq!(granules.len(), 4);
+	assert!(granules.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+	let vorbis_file =
+		VorbisFile::read_from(&mut std::fs::File::open(path).unwrap(), ParseOptions::new())
+			.unwrap();
+	let properties = vorbis_file.properties();
+
+	// The duration is rounded to the nearest millisecond
+	let (_, last_granule) = *granules.last().unwrap();
+	let expected_samples =
+		properties.duration().as_millis() as i64 * i64::from(properties.sample_rate()) / 1000;
+	assert!((last_granule - expected_samples).abs() <= i64::from(properties.sample_rate()) / 1000);
+}
+
+#[test_log::test]
+fn vorbis_fragmentation_stats() {
+	let mut identification_header = b"\x01vorbis".to_vec();
+	identification_header.extend(0_u32.to_le_bytes()); // Version
+	identification_header.push(2); // Channel count
+	identification_header.extend(44100_u32.to_le_bytes()); // Sample rate
+	identification_header.extend([0; 12]); // Bitrates
+	identification_header.push(0xB8); // Block sizes
+	identification_header.push(1); // Framing bit
+
+	// The second packet is large enough to span 3 pages
+	let file = OggBuilder::new(FileType::Vorbis)
+		.id_header(identification_header)
+		.setup_header(b"\x05vorbis".to_vec())
+		.audio_packets(vec![vec![1; 100], vec![2; 20_000], vec![3; 1]])
+		.samples_per_packet(1024)
+		.build()
+		.unwrap();
+
+	let stats = VorbisFile::fragmentation_stats(&mut Cursor::new(&file)).unwrap();
+
+	// 3 header packets, each on their own page, followed by 3 audio packets on 5 pages
+	assert_eq!(stats.page_count(), 8);
+	assert_eq!(stats.packet_count(), 6);
+	assert_eq!(stats.spanning_packet_count(), 1);
+
+	let packets = Packets::read(&mut Cursor::new(&file)).unwrap();
+	let total_packet_size = packets.iter().map(<[u8]>::len).sum::<usize>();
+	assert!((stats.average_packet_size() - total_packet_size as f64 / 6.0).abs() < f64::EPSILON);
+
+	// A file where every packet fits on one page
+	let stats = VorbisFile::fragmentation_stats(
+		&mut std::fs::File::open("tests/files/assets/minimal/full_test.ogg").unwrap(),
+	)
+	.unwrap();
+	assert_eq!(stats.spanning_packet_count(), 0);
+}
+
+#[test_log::test]
+fn vorbis_setup_header_spanning_pages() {
+	let mut identification_header = b"\x01vorbis".to_vec();
+	identification_header.extend(0_u32.to_le_bytes()); // Version
+	identification_header.push(2); // Channel count
+	identification_header.extend(44100_u32.to_le_bytes()); // Sample rate
+	identification_header.extend([0; 12]); // Bitrates
+	identification_header.push(0xB8); // Block sizes
+	identification_header.push(1); // Framing bit
+
+	let mut setup_header = b"\x05vorbis".to_vec();
+	setup_header.extend((0..20_000).map(|i| i as u8));
+
+	let file = OggBuilder::new(FileType::Vorbis)
+		.id_header(identification_header)
+		.setup_header(setup_header.clone())
+		.audio_packets(vec![vec![1; 100]])
+		.samples_per_packet(1024)
+		.build()
+		.unwrap();
+
+	// The identification and comment headers are on their own pages
+	let mut reader = Cursor::new(&file);
+	let _ = Page::read(&mut reader).unwrap();
+	let _ = Page::read(&mut reader).unwrap();
+	let setup_page = Page::read(&mut reader).unwrap();
+	assert!(setup_page.content().len() < setup_header.len());
+
+	let read_setup_header = VorbisFile::setup_header(&mut Cursor::new(&file)).unwrap();
+	assert_eq!(read_setup_header, setup_header);
+}
+
+#[test_log::test]
+fn vorbis_validate_all_crcs() {
+	let path = "tests/files/assets/minimal/full_test.ogg";
+	let mut file = std::fs::read(path).unwrap();
+	assert!(
+		VorbisFile::validate_all_crcs(&mut Cursor::new(&file), false)
+			.unwrap()
+			.is_empty()
+	);
+
+	// Corrupt a byte of the content of the first and third pages
+	let mut reader = Cursor::new(&file);
+	let first_page = Page::read(&mut reader).unwrap();
+	let first_page_end = reader.stream_position().unwrap() as usize;
+	let _ = Page::read(&mut reader).unwrap();
+	let third_page_start = reader.stream_position().unwrap();
+	let third_page = Page::read(&mut reader).unwrap();
+	let third_page_end = reader.stream_position().unwrap() as usize;
+
+	assert!(first_page.content().len() > 7);
+	file[first_page_end - 1] ^= 0xFF;
+	file[third_page_end - third_page.content().len()] ^= 0xFF;
+
+	// Stops at the first bad page
+	assert_eq!(
+		VorbisFile::validate_all_crcs(&mut Cursor::new(&file), false).unwrap(),
+		[0]
+	);
+
+	// Every page is checked when reporting all mismatches
+	assert_eq!(
+		VorbisFile::validate_all_crcs(&mut Cursor::new(&file), true).unwrap(),
+		[0, third_page_start]
+	);
+}
+
+#[test_log::test]
+fn vorbis_bounded_last_page_search() {
+	// Counts the bytes read through it
+	struct CountingReader<R> {
+		inner: R,
+		bytes_read: u64,
+	}
+
+	impl<R: Read> Read for CountingReader<R> {
+		fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+			let read = self.inner.read(buf)?;
+			self.bytes_read += read as u64;
+			Ok(read)
+		}
+	}
+
+	impl<R: Seek> Seek for CountingReader<R> {
+		fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+			self.inner.seek(pos)
+		}
+	}
+
+	// A valid stream, followed by 64MB of zeros
+	let mut file = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+	let stream_len = file.len() as u64;
+	file.resize(file.len() + 64 * 1024 * 1024, 0);
+
+	let mut reader = CountingReader {
+		inner: Cursor::new(&file),
+		bytes_read: 0,
+	};
+
+	let expected = VorbisFile::read_from(
+		&mut Cursor::new(&file[..stream_len as usize]),
+		ParseOptions::new(),
+	)
+	.unwrap();
+	assert!(!expected.properties().duration().is_zero());
+
+	// The search gives up without reading the entire file, and falls back to walking the pages
+	let vorbis_file = VorbisFile::read_from(&mut reader, ParseOptions::new()).unwrap();
+	assert_eq!(
+		vorbis_file.properties().duration(),
+		expected.properties().duration()
+	);
+	assert!(reader.bytes_read < 2 * ParseOptions::DEFAULT_MAX_LAST_PAGE_SEARCH);
+
+	// Searching far enough will find the last page
+	let parse_options = ParseOptions::new().max_last_page_search(u64::MAX);
+	let vorbis_file = VorbisFile::read_from(&mut Cursor::new(&file), parse_options).unwrap();
+	assert_eq!(
+		vorbis_file.properties().duration(),
+		expected.properties().duration()
+	);
+}
+
+#[test_log::test]
+fn vorbis_leading_junk() {
+	let path = "tests/files/assets/minimal/full_test.ogg";
+	let original = std::fs::read(path).unwrap();
+
+	let mut file = vec![0xAA; 2048];
+	file.extend_from_slice(&original);
+
+	// The junk is beyond the default limit
+	assert!(VorbisFile::read_from(&mut Cursor::new(&file), ParseOptions::new()).is_err());
+
+	let parse_options = ParseOptions::new().max_junk_bytes(4096);
+	let vorbis_file = VorbisFile::read_from(&mut Cursor::new(&file), parse_options).unwrap();
+	assert_eq!(vorbis_file.stream_offset(), 2048);
+
+	let expected = VorbisFile::read_from(&mut Cursor::new(&original), ParseOptions::new()).unwrap();
+	assert_eq!(expected.stream_offset(), 0);
+	assert_eq!(vorbis_file.vorbis_comments(), expected.vorbis_comments());
+	assert_eq!(
+		vorbis_file.properties().duration(),
+		expected.properties().duration()
+	);
+}
+
+#[test_log::test]
+fn vorbis_leading_junk_write() {
+	let original = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+
+	// Within the default limit, so the junk is skipped when writing as well
+	let mut file = vec![0xAA; 512];
+	file.extend_from_slice(&original);
+
+	let mut file = Cursor::new(file);
+	let mut vorbis_file = VorbisFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(vorbis_file.stream_offset(), 512);
+
+	vorbis_file
+		.vorbis_comments_mut()
+		.set_title(String::from("Bar title"));
+
+	file.rewind().unwrap();
+	vorbis_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The junk is preserved as is
+	assert_eq!(&file.get_ref()[..512], &[0xAA; 512][..]);
+
+	file.rewind().unwrap();
+	let vorbis_file = VorbisFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(vorbis_file.stream_offset(), 512);
+	assert_eq!(
+		vorbis_file.vorbis_comments().title().as_deref(),
+		Some("Bar title")
+	);
+
+	let expected = VorbisFile::read_from(&mut Cursor::new(&original), ParseOptions::new()).unwrap();
+	assert_eq!(
+		vorbis_file.properties().duration(),
+		expected.properties().duration()
+	);
+}
+
+#[test_log::test]
+fn vorbis_comment_header_spanning_pages() {
+	let mut identification_header = b"\x01vorbis".to_vec();
+	identification_header.extend(0_u32.to_le_bytes()); // Version
+	identification_header.push(2); // Channel count
+	identification_header.extend(44100_u32.to_le_bytes()); // Sample rate
+	identification_header.extend([0; 12]); // Bitrates
+	identification_header.push(0xB8); // Block sizes
+	identification_header.push(1); // Framing bit
+
+	// ~4MB once base64 encoded, which spans hundreds of pages
+	let picture_data = (0..3 * 1024 * 1024)
+		.map(|i| (i % 251) as u8)
+		.collect::<Vec<_>>();
+	let picture = Picture::unchecked(picture_data.clone())
+		.pic_type(PictureType::CoverFront)
+		.mime_type(MimeType::Png)
+		.description("Large cover")
+		.build();
+
+	let mut comments = VorbisComments::default();
+	comments.set_title(String::from("Foo title"));
+	comments
+		.insert_picture(picture, Some(PictureInformation::default()))
+		.unwrap();
+	comments.set_artist(String::from("Bar artist"));
+
+	let file = OggBuilder::new(FileType::Vorbis)
+		.id_header(identification_header)
+		.comments(comments)
+		.setup_header(b"\x05vorbis".to_vec())
+		.audio_packets(vec![vec![1; 100]; 5])
+		.samples_per_packet(1024)
+		.build()
+		.unwrap();
+
+	let stats = VorbisFile::fragmentation_stats(&mut Cursor::new(&file)).unwrap();
+	assert!(stats.page_count() > 100);
+	assert_eq!(stats.spanning_packet_count(), 1);
+
+	let vorbis_file = VorbisFile::read_from(&mut Cursor::new(&file), ParseOptions::new()).unwrap();
+	let comments = vorbis_file.vorbis_comments();
+	assert_eq!(comments.title().as_deref(), Some("Foo title"));
+	assert_eq!(comments.artist().as_deref(), Some("Bar artist"));
+
+	let pictures = comments.pictures();
+	assert_eq!(pictures.len(), 1);
+
+	let (picture, _) = &pictures[0];
+	assert_eq!(picture.pic_type(), PictureType::CoverFront);
+	assert_eq!(picture.description(), Some("Large cover"));
+	assert_eq!(picture.data(), picture_data.as_slice());
+
+	// The setup header and audio packets follow the comment header
+	let packets = Packets::read(&mut Cursor::new(&file)).unwrap();
+	assert_eq!(packets.len(), 8);
+	assert_eq!(packets.iter().nth(2), Some(b"\x05vorbis".as_slice()));
+}
+
+#[test_log::test]
+fn vorbis_bytes_roundtrip() {
+	let bytes = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+
+	let mut tagged_file = lofty::read_from_bytes(&bytes).unwrap();
+	assert_eq!(tagged_file.file_type(), FileType::Vorbis);
+	crate::util::verify_artist(&tagged_file, TagType::VorbisComments, "Foo artist", 2);
+
+	let tag = tagged_file.tag_mut(TagType::VorbisComments).unwrap();
+	tag.set_artist(String::from("Bar artist"));
+	tag.set_title(String::from("Foo title"));
+
+	let edited = lofty::write_to_bytes(&bytes, &tagged_file, WriteOptions::default()).unwrap();
+	assert_ne!(edited, bytes);
+
+	let tagged_file = lofty::read_from_bytes(&edited).unwrap();
+	crate::util::verify_artist(&tagged_file, TagType::VorbisComments, "Bar artist", 3);
+
+	let tag = tagged_file.tag(TagType::VorbisComments).unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+
+	// Writing the same tags again shouldn't change anything
+	let rewritten = lofty::write_to_bytes(&edited, &tagged_file, WriteOptions::default()).unwrap();
+	assert_eq!(rewritten, edited);
+}
+
 fn read(path: &str, file_type: FileType) {
 	let file = Probe::open(path)
 		.unwrap()
@@ -153,3 +153,15 @@ pub fn remove_tag_test(path: impl AsRef<Path>, tag_type: TagType) {
 		.unwrap();
 	assert!(tagged_file.tag(tag_type).is_none());
 }
+
+/// Verify that the file at `path` is unchanged by a no-op write
+///
+/// See [`lofty::file::verify_roundtrip`]
+pub fn assert_roundtrip_stable(path: impl AsRef<Path>) {
+	let differences =
+		lofty::file::verify_roundtrip(path, ParseOptions::new(), WriteOptions::default()).unwrap();
+	assert!(
+		differences.is_empty(),
+		"Round trip differences: {differences:?}"
+	);
+}
@@ -1,10 +1,11 @@
-use lofty::config::ParseOptions;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
 use lofty::prelude::*;
 use lofty::probe::Probe;
-use lofty::tag::TagType;
+use lofty::tag::{Tag, TagType};
 
-use std::io::Seek;
+use std::io::{Read, Seek, Write};
+use std::time::Duration;
 
 #[test_log::test]
 fn read() {
@@ -113,3 +114,214 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::util::no_tag_test("tests/files/assets/minimal/wav_format_pcm.wav", None);
 }
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/wav_format_pcm.wav");
+}
+
+// 48kHz, 16-bit stereo PCM, with the real sizes stored in the `ds64` chunk
+fn rf64_header(riff_size: u64, data_size: u64) -> Vec<u8> {
+	let mut header = Vec::new();
+	header.extend(b"RF64");
+	header.extend(u32::MAX.to_le_bytes());
+	header.extend(b"WAVE");
+
+	header.extend(b"ds64");
+	header.extend(28_u32.to_le_bytes());
+	header.extend(riff_size.to_le_bytes());
+	header.extend(data_size.to_le_bytes());
+	header.extend((data_size / 4).to_le_bytes());
+	// Chunk size table
+	header.extend(0_u32.to_le_bytes());
+
+	header.extend(b"fmt ");
+	header.extend(16_u32.to_le_bytes());
+	header.extend(1_u16.to_le_bytes());
+	header.extend(2_u16.to_le_bytes());
+	header.extend(48000_u32.to_le_bytes());
+	header.extend(192_000_u32.to_le_bytes());
+	header.extend(4_u16.to_le_bytes());
+	header.extend(16_u16.to_le_bytes());
+
+	header.extend(b"data");
+	header.extend(u32::MAX.to_le_bytes());
+
+	header
+}
+
+fn rf64_file(data_size: u64) -> std::fs::File {
+	// The RIFF size doesn't include the "RF64" or size fields
+	let header_len = rf64_header(0, 0).len() as u64;
+	let header = rf64_header(header_len + data_size - 8, data_size);
+
+	let mut file = tempfile::tempfile().unwrap();
+	file.write_all(&header).unwrap();
+	// Sparse, so this doesn't actually take up `data_size` bytes
+	file.set_len(header_len + data_size).unwrap();
+	file.rewind().unwrap();
+
+	file
+}
+
+#[test_log::test]
+fn read_rf64_over_4gb() {
+	// 30000 seconds of audio, ~5.7GB
+	let data_size = 5_760_000_000;
+	let mut file = rf64_file(data_size);
+
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Wav);
+
+	let properties = tagged_file.properties();
+	assert_eq!(properties.duration(), Duration::from_secs(30000));
+	assert_eq!(properties.sample_rate(), Some(48000));
+	assert_eq!(properties.channels(), Some(2));
+	assert_eq!(properties.bit_depth(), Some(16));
+	assert_eq!(properties.audio_bitrate(), Some(1536));
+}
+
+#[test_log::test]
+fn write_rf64() {
+	let data_size = 48000;
+	let mut file = rf64_file(data_size);
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	for tag_type in [TagType::RiffInfo, TagType::Id3v2] {
+		let mut tag = Tag::new(tag_type);
+		tag.set_artist(String::from("Foo artist"));
+		tagged_file.insert_tag(tag);
+	}
+
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	let mut contents = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut contents).unwrap();
+
+	// The 32-bit sizes stay at their placeholder value
+	assert_eq!(&contents[..4], b"RF64");
+	assert_eq!(contents[4..8], u32::MAX.to_le_bytes());
+
+	// And the `ds64` chunk holds the new RIFF size, with the data size untouched
+	let riff_size = u64::from_le_bytes(contents[20..28].try_into().unwrap());
+	let ds64_data_size = u64::from_le_bytes(contents[28..36].try_into().unwrap());
+	assert_eq!(riff_size, contents.len() as u64 - 8);
+	assert_eq!(ds64_data_size, data_size);
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::util::verify_artist(&tagged_file, TagType::RiffInfo, "Foo artist", 1);
+	crate::util::verify_artist(&tagged_file, TagType::Id3v2, "Foo artist", 1);
+	assert_eq!(
+		tagged_file.properties().duration(),
+		Duration::from_millis(250)
+	);
+}
+
+#[test_log::test]
+fn sampler_chunks_survive_tag_edit() {
+	use lofty::iff::wav::{InstrumentChunk, SampleChunk, SampleLoop, WavFile};
+
+	let mut contents = std::fs::read("tests/files/assets/minimal/wav_format_pcm.wav").unwrap();
+
+	contents.extend(b"smpl");
+	contents.extend(60_u32.to_le_bytes());
+	for value in [0_u32, 0, 22675, 60, 0, 0, 0, 1, 0] {
+		contents.extend(value.to_le_bytes());
+	}
+
+	// Loop
+	for value in [1_u32, 0, 100, 200, 0, 0] {
+		contents.extend(value.to_le_bytes());
+	}
+
+	contents.extend(b"inst");
+	contents.extend(7_u32.to_le_bytes());
+	contents.extend([60, (-10_i8) as u8, 3, 0, 127, 1, 127, 0]);
+
+	let riff_size = contents.len() as u32 - 8;
+	contents[4..8].copy_from_slice(&riff_size.to_le_bytes());
+
+	let mut file = tempfile::tempfile().unwrap();
+	file.write_all(&contents).unwrap();
+
+	let expected_sample_chunk = SampleChunk {
+		sample_period: 22675,
+		midi_unity_note: 60,
+		loops: vec![SampleLoop {
+			cue_point_id: 1,
+			loop_type: 0,
+			start: 100,
+			end: 200,
+			fraction: 0,
+			play_count: 0,
+		}],
+		..SampleChunk::default()
+	};
+	let expected_instrument_chunk = InstrumentChunk {
+		unshifted_note: 60,
+		fine_tune: -10,
+		gain: 3,
+		low_note: 0,
+		high_note: 127,
+		low_velocity: 1,
+		high_velocity: 127,
+	};
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(wav_file.sample_chunk(), Some(&expected_sample_chunk));
+	assert_eq!(
+		wav_file.instrument_chunk(),
+		Some(&expected_instrument_chunk)
+	);
+
+	// Only change the tags
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	tagged_file
+		.tag_mut(TagType::Id3v2)
+		.unwrap()
+		.set_title(String::from("Foo title"));
+
+	file.rewind().unwrap();
+	tagged_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(wav_file.sample_chunk(), Some(&expected_sample_chunk));
+	assert_eq!(
+		wav_file.instrument_chunk(),
+		Some(&expected_instrument_chunk)
+	);
+	assert_eq!(
+		wav_file.id3v2().unwrap().title().as_deref(),
+		Some("Foo title")
+	);
+}
@@ -136,3 +136,8 @@ fn write_ape_disc_key() {
 	assert_eq!(ape_tag.disk(), Some(3));
 	assert_eq!(ape_tag.disk_total(), Some(5));
 }
+
+#[test_log::test]
+fn roundtrip_stable() {
+	crate::util::assert_roundtrip_stable("tests/files/assets/minimal/full_test.wv");
+}
@@ -0,0 +1,131 @@
+use lofty::ape::{ApeFile, ApeTag};
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::file::FileType;
+use lofty::picture::{MimeType, Picture, PictureType};
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::{Tag, TagType};
+
+use std::io::Cursor;
+
+fn picture(pic_type: PictureType, path: &str) -> Picture {
+	let mut picture = Picture::from_reader(&mut std::fs::File::open(path).unwrap()).unwrap();
+	picture.set_pic_type(pic_type);
+	picture
+}
+
+#[test_log::test]
+fn gif_dropped_when_only_jpeg_allowed() {
+	let files = [
+		("tests/files/assets/minimal/full_test.mp3", FileType::Mpeg),
+		("tests/files/assets/minimal/full_test.flac", FileType::Flac),
+		("tests/files/assets/minimal/full_test.ogg", FileType::Vorbis),
+		(
+			"tests/files/assets/minimal/m4a_codec_aac.m4a",
+			FileType::Mp4,
+		),
+	];
+
+	let write_options = WriteOptions::new().allowed_picture_mimes(Some(&[MimeType::Jpeg]));
+
+	for (path, file_type) in files {
+		let mut tag = Tag::new(file_type.primary_tag_type());
+		tag.push_picture(picture(
+			PictureType::CoverFront,
+			"tests/picture/assets/jpeg_640x628.jpg",
+		));
+		tag.push_picture(picture(
+			PictureType::CoverBack,
+			"tests/picture/assets/gif_640x628.gif",
+		));
+
+		let mut file = Cursor::new(std::fs::read(path).unwrap());
+		tag.save_to(&mut file, write_options).unwrap();
+
+		file.set_position(0);
+		let tagged_file = Probe::new(&mut file)
+			.guess_file_type()
+			.unwrap()
+			.read()
+			.unwrap();
+
+		let pictures = tagged_file.primary_tag().unwrap().pictures();
+		assert_eq!(pictures.len(), 1, "{file_type:?}");
+		assert_eq!(pictures[0].mime_type(), Some(&MimeType::Jpeg));
+	}
+
+	// APE stores pictures as binary items
+	let mut tag = Tag::new(TagType::Ape);
+	tag.push_picture(picture(
+		PictureType::CoverFront,
+		"tests/picture/assets/jpeg_640x628.jpg",
+	));
+	tag.push_picture(picture(
+		PictureType::CoverBack,
+		"tests/picture/assets/gif_640x628.gif",
+	));
+
+	let tag = ApeTag::from(tag);
+	assert!(tag.get("Cover Art (Back)").is_some());
+
+	let mut file = Cursor::new(std::fs::read("tests/files/assets/minimal/full_test.ape").unwrap());
+	tag.save_to(&mut file, write_options).unwrap();
+
+	file.set_position(0);
+	let ape_file = ApeFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let ape_tag = ape_file.ape().unwrap();
+	assert!(ape_tag.get("Cover Art (Front)").is_some());
+	assert!(ape_tag.get("Cover Art (Back)").is_none());
+}
+
+#[test_log::test]
+fn all_pictures_written_by_default() {
+	let mut tag = Tag::new(FileType::Mpeg.primary_tag_type());
+	tag.push_picture(picture(
+		PictureType::CoverFront,
+		"tests/picture/assets/jpeg_640x628.jpg",
+	));
+	tag.push_picture(picture(
+		PictureType::CoverBack,
+		"tests/picture/assets/gif_640x628.gif",
+	));
+
+	let mut file = Cursor::new(std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap());
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.set_position(0);
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	assert_eq!(tagged_file.primary_tag().unwrap().pictures().len(), 2);
+}
+
+#[test_log::test]
+fn mp4_ilst_removed_when_all_atoms_dropped() {
+	let mut tag = Tag::new(TagType::Mp4Ilst);
+	tag.push_picture(picture(
+		PictureType::CoverFront,
+		"tests/picture/assets/gif_640x628.gif",
+	));
+
+	let mut file =
+		Cursor::new(std::fs::read("tests/files/assets/minimal/m4a_codec_aac.m4a").unwrap());
+	tag.save_to(
+		&mut file,
+		WriteOptions::new().allowed_picture_mimes(Some(&[MimeType::Jpeg])),
+	)
+	.unwrap();
+
+	// No empty `ilst` should be left behind
+	assert!(!file.get_ref().windows(4).any(|window| window == b"ilst"));
+
+	file.set_position(0);
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	assert!(tagged_file.tag(TagType::Mp4Ilst).is_none());
+}
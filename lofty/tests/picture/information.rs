@@ -1,4 +1,4 @@
-use lofty::picture::PictureInformation;
+use lofty::picture::{Picture, PictureInformation};
 
 use std::fs::File;
 use std::io::Read;
@@ -40,6 +40,24 @@ fn read_png_plte() {
 	assert_eq!(information.num_colors, 118);
 }
 
+#[test_log::test]
+fn read_png_truecolor() {
+	// 24-bit RGB PNG image, without a PLTE chunk
+	let mut f = File::open("tests/picture/assets/png_640x628_rgb.png").unwrap();
+
+	let mut buf = Vec::new();
+	f.read_to_end(&mut buf).unwrap();
+
+	let information = PictureInformation::from_png(&buf).unwrap();
+
+	assert_eq!(information.width, 640);
+	assert_eq!(information.height, 628);
+	assert_eq!(information.color_depth, 24);
+
+	// Always 0 for truecolor images
+	assert_eq!(information.num_colors, 0);
+}
+
 #[test_log::test]
 fn read_jpeg() {
 	let mut f = File::open("tests/picture/assets/jpeg_640x628.jpg").unwrap();
@@ -56,3 +74,31 @@ fn read_jpeg() {
 	// Always 0, not applicable for JPEG
 	assert_eq!(information.num_colors, 0);
 }
+
+#[test_log::test]
+fn read_gif() {
+	let mut f = File::open("tests/picture/assets/gif_640x628.gif").unwrap();
+
+	let mut buf = Vec::new();
+	f.read_to_end(&mut buf).unwrap();
+
+	let information = PictureInformation::from_gif(&buf).unwrap();
+
+	assert_eq!(information.width, 640);
+	assert_eq!(information.height, 628);
+	assert_eq!(information.color_depth, 8);
+
+	// Size of the global color table
+	assert_eq!(information.num_colors, 256);
+}
+
+#[test_log::test]
+fn read_gif_from_picture() {
+	let buf = std::fs::read("tests/picture/assets/gif_640x628.gif").unwrap();
+	let picture = Picture::unchecked(buf).build();
+
+	assert_eq!(
+		PictureInformation::from_picture(&picture).unwrap(),
+		PictureInformation::from_gif(picture.data()).unwrap()
+	);
+}
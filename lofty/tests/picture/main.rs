@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 
+mod allowed_mimes;
 mod format_parsers;
 mod from_reader;
 mod information;
@@ -21,6 +21,7 @@ fn test_audio_properties_xing_header_cbr() {
 	assert_eq!(f.properties().audio_bitrate(), 64);
 	assert_eq!(f.properties().channels(), 1);
 	assert_eq!(f.properties().sample_rate(), 44100);
+	assert!(!f.properties().is_vbr());
 	// TODO?
 	// CPPUNIT_ASSERT_EQUAL(MPEG::XingHeader::Xing, f.audioProperties()->xingHeader()->type());
 }
@@ -34,6 +35,7 @@ fn test_audio_properties_xing_header_vbr() {
 	assert_eq!(f.properties().audio_bitrate(), 70);
 	assert_eq!(f.properties().channels(), 1);
 	assert_eq!(f.properties().sample_rate(), 44100);
+	assert!(f.properties().is_vbr());
 	// TODO?
 	// CPPUNIT_ASSERT_EQUAL(MPEG::XingHeader::Xing, f.audioProperties()->xingHeader()->type());
 }
@@ -47,6 +49,7 @@ fn test_audio_properties_vbri_header() {
 	assert_eq!(f.properties().audio_bitrate(), 233);
 	assert_eq!(f.properties().channels(), 2);
 	assert_eq!(f.properties().sample_rate(), 44100);
+	assert!(f.properties().is_vbr());
 	// TODO?
 	// CPPUNIT_ASSERT_EQUAL(MPEG::XingHeader::VBRI, f.audioProperties()->xingHeader()->type());
 }
@@ -60,6 +63,7 @@ fn test_audio_properties_no_vbr_headers() {
 	assert_eq!(f.properties().audio_bitrate(), 64);
 	assert_eq!(f.properties().channels(), 1);
 	assert_eq!(f.properties().sample_rate(), 44100);
+	assert!(!f.properties().is_vbr());
 
 	// NOTE: This test also checks the last frame of the file. That information is not saved
 	//       in Lofty, and it doesn't seem too useful to expose.
@@ -2,6 +2,8 @@
 
 use lofty::TextEncoding;
 use lofty::id3::v2::{CommentFrame, Frame, FrameId, Id3v2Tag, UnsynchronizedTextFrame};
+use lofty::mp4::{AtomData, AtomIdent, Ilst};
+use lofty::ogg::VorbisComments;
 use lofty::tag::{ItemKey, Tag, TagType};
 
 use std::borrow::Cow;
@@ -34,3 +36,60 @@ fn tag_to_id3v2_lang_frame() {
 		)))
 	);
 }
+
+#[test_log::test]
+fn bpm_and_initial_key_roundtrip() {
+	let mut vorbis_comments = VorbisComments::default();
+	vorbis_comments.insert(String::from("BPM"), String::from("120.5"));
+	vorbis_comments.insert(String::from("INITIALKEY"), String::from("F#m"));
+
+	// ID3v2 can only store integer BPM values
+	let tag: Tag = vorbis_comments.into();
+	let id3v2: Id3v2Tag = tag.into();
+	assert_eq!(
+		id3v2.get_text(&FrameId::Valid(Cow::Borrowed("TBPM"))),
+		Some("121")
+	);
+	assert_eq!(
+		id3v2.get_text(&FrameId::Valid(Cow::Borrowed("TKEY"))),
+		Some("F#m")
+	);
+
+	let tag: Tag = id3v2.into();
+	assert_eq!(tag.get_string(ItemKey::IntegerBpm), Some("121"));
+	assert_eq!(tag.get_string(ItemKey::InitialKey), Some("F#m"));
+
+	let ilst: Ilst = tag.into();
+	assert_eq!(
+		ilst.get(&AtomIdent::Fourcc(*b"tmpo"))
+			.and_then(|atom| atom.data().next()),
+		Some(&AtomData::SignedInteger(121))
+	);
+	assert_eq!(
+		ilst.get(&AtomIdent::Freeform {
+			mean: Cow::Borrowed("com.apple.iTunes"),
+			name: Cow::Borrowed("initialkey"),
+		})
+		.and_then(|atom| atom.data().next()),
+		Some(&AtomData::UTF8(String::from("F#m")))
+	);
+
+	// The integer BPM takes the place of the missing decimal BPM
+	let tag: Tag = ilst.into();
+	let vorbis_comments: VorbisComments = tag.into();
+	assert_eq!(vorbis_comments.get("BPM"), Some("121"));
+	assert_eq!(vorbis_comments.get("INITIALKEY"), Some("F#m"));
+}
+
+#[test_log::test]
+fn decimal_bpm_preferred_in_vorbis_comments() {
+	let mut tag = Tag::new(TagType::Mp4Ilst);
+	tag.insert_text(ItemKey::IntegerBpm, String::from("120"));
+	tag.insert_text(ItemKey::Bpm, String::from("120.5"));
+
+	let vorbis_comments: VorbisComments = tag.into();
+	assert_eq!(
+		vorbis_comments.get_all("BPM").collect::<Vec<_>>(),
+		["120.5"]
+	);
+}
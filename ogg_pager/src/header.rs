@@ -105,6 +105,15 @@ impl PageHeader {
 		self.segments.iter().map(|&b| usize::from(b)).sum::<usize>()
 	}
 
+	/// Returns the page's segment table
+	///
+	/// Each entry is a lacing value, the size of a segment of the page content. A lacing value
+	/// less than 255 marks the end of a packet, while a page ending with a lacing value of 255
+	/// has a packet continuing onto the next page.
+	pub fn segments(&self) -> &[u8] {
+		&self.segments
+	}
+
 	/// Returns the page's header type flag
 	pub fn header_type_flag(&self) -> u8 {
 		self.header_type_flag
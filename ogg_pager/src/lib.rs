@@ -118,7 +118,7 @@ impl Page {
 
 #[cfg(test)]
 mod tests {
-	use crate::{Page, PageHeader, paginate};
+	use crate::{Packets, Page, PageHeader, paginate};
 	use std::io::Cursor;
 
 	pub fn segment_table(length: usize) -> Vec<u8> {
@@ -285,4 +285,55 @@ mod tests {
 
 		assert_eq!(*page.header.segments.last().unwrap(), 0);
 	}
+
+	#[test]
+	fn reconstruct_packets_from_segments() {
+		let packets = [vec![1; 100], vec![2; 10_000], vec![3; 510]];
+
+		let pages = paginate(packets.iter().map(Vec::as_slice), 1234, 0, 0).unwrap();
+		assert!(pages.len() > 1);
+
+		let mut reconstructed = Vec::new();
+		let mut current_packet = Vec::new();
+		for page in &pages {
+			let mut content = page.content();
+			for &lacing_value in page.header().segments() {
+				let (segment, rest) = content.split_at(usize::from(lacing_value));
+				current_packet.extend_from_slice(segment);
+				content = rest;
+
+				if lacing_value < 255 {
+					reconstructed.push(std::mem::take(&mut current_packet));
+				}
+			}
+
+			assert!(content.is_empty());
+		}
+
+		assert!(current_packet.is_empty());
+		assert_eq!(reconstructed, packets);
+	}
+
+	#[test]
+	fn packets_get_and_set() {
+		let packets = [vec![1; 100], vec![2; 10_000], vec![3; 510]];
+
+		let pages = paginate(packets.iter().map(Vec::as_slice), 1234, 0, 0).unwrap();
+
+		let mut bytes = Vec::new();
+		for page in pages {
+			bytes.extend(page.as_bytes());
+		}
+
+		let mut read_packets = Packets::read(&mut Cursor::new(bytes)).unwrap();
+		for (i, packet) in packets.iter().enumerate() {
+			assert_eq!(read_packets.get(i), Some(packet.as_slice()));
+		}
+		assert_eq!(read_packets.get(3), None);
+
+		assert!(read_packets.set(1, [4; 5]));
+		assert_eq!(read_packets.get(0), Some(packets[0].as_slice()));
+		assert_eq!(read_packets.get(1), Some([4; 5].as_slice()));
+		assert_eq!(read_packets.get(2), Some(packets[2].as_slice()));
+	}
 }
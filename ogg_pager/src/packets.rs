@@ -227,22 +227,10 @@ impl Packets {
 	/// # Ok(()) }
 	/// ```
 	pub fn get(&self, idx: usize) -> Option<&[u8]> {
-		if idx >= self.content.len() {
-			return None;
-		}
+		let packet_size = *self.packet_sizes.get(idx)? as usize;
 
-		let start_pos = match idx {
-			// Packet 0 starts at pos 0
-			0 => 0,
-			// Anything else we have to get the size of the previous packet
-			other => self.packet_sizes[other - 1] as usize,
-		};
-
-		if let Some(packet_size) = self.packet_sizes.get(idx) {
-			return Some(&self.content[start_pos..start_pos + *packet_size as usize]);
-		}
-
-		None
+		let start_pos = self.packet_start(idx);
+		Some(&self.content[start_pos..start_pos + packet_size])
 	}
 
 	/// Sets the packet content, if it exists
@@ -282,12 +270,7 @@ impl Packets {
 			return false;
 		}
 
-		let start_pos = match idx {
-			// Packet 0 starts at pos 0
-			0 => 0,
-			// Anything else we have to get the size of the previous packet
-			other => self.packet_sizes[other - 1] as usize,
-		};
+		let start_pos = self.packet_start(idx);
 
 		let content = content.into();
 		let content_size = content.len();
@@ -300,6 +283,11 @@ impl Packets {
 		true
 	}
 
+	// The packets are stored back to back, so a packet starts after all of the previous ones
+	fn packet_start(&self, idx: usize) -> usize {
+		self.packet_sizes[..idx].iter().sum::<u64>() as usize
+	}
+
 	/// Returns an iterator over the packets
 	///
 	/// # Examples